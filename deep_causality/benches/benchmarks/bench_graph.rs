@@ -55,6 +55,28 @@ fn medium_linear_graph_benchmark(criterion: &mut Criterion) {
 
     let index = data.len() / 2;
 
+    criterion.bench_function(
+        "medium_linear_graph_reason_all_causes_with_policy",
+        |bencher| {
+            bencher.iter(|| {
+                g.reason_all_causes_with_policy(&data, None, ReasoningPolicy::FailFast)
+                    .unwrap()
+            })
+        },
+    );
+
+    let mut arena = EvalArena::with_capacity(g.size());
+
+    criterion.bench_function(
+        "medium_linear_graph_reason_all_causes_with_arena",
+        |bencher| {
+            bencher.iter(|| {
+                g.reason_all_causes_with_arena(&data, None, ReasoningPolicy::FailFast, &mut arena)
+                    .unwrap()
+            })
+        },
+    );
+
     criterion.bench_function(
         "medium_linear_graph_reason_subgraph_from_cause",
         |bencher| bencher.iter(|| g.reason_subgraph_from_cause(index, &data, None).unwrap()),
@@ -43,7 +43,7 @@ fn main() {
     println!("{}", expl);
 }
 
-fn get_test_causaloid<'l>(id: IdentificationValue) -> BaseCausaloid<'l> {
+fn get_test_causaloid(id: IdentificationValue) -> SimpleCausaloid {
     let description = "tests whether data exceeds threshold of 0.55";
     fn causal_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
         if obs.is_sign_negative() {
@@ -61,7 +61,7 @@ fn get_test_causaloid<'l>(id: IdentificationValue) -> BaseCausaloid<'l> {
     Causaloid::new(id, causal_fn, description)
 }
 
-fn get_multi_cause_graph<'l>() -> BaseCausalGraph<'l> {
+fn get_multi_cause_graph() -> SimpleCausalGraph {
     // Builds a multi cause graph:
     //  root
     //  / \
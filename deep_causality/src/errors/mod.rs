@@ -8,6 +8,7 @@ mod causal_graph_index_error;
 mod causality_error;
 mod causality_graph_error;
 mod context_index_error;
+mod reasoning_error;
 mod update_error;
 
 pub use action_error::*;
@@ -17,4 +18,5 @@ pub use causal_graph_index_error::*;
 pub use causality_error::*;
 pub use causality_graph_error::*;
 pub use context_index_error::*;
+pub use reasoning_error::*;
 pub use update_error::*;
@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::errors::{
+    ActionError, AdjustmentError, BuildError, CausalGraphIndexError, CausalityError,
+    CausalityGraphError, ContextIndexError, UpdateError,
+};
+use crate::prelude::IdentificationValue;
+
+/// Failure category of a ReasoningError.
+///
+/// Each kind maps to a stable numeric error code so that callers
+/// can branch on, log, or export the failure category without parsing messages.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[repr(u16)]
+pub enum ReasoningErrorKind {
+    /// The input data were invalid, i.e. NaN, infinite, or out of range.
+    InvalidData = 100,
+    /// A causal function failed during evaluation.
+    Evaluation = 200,
+    /// The causal graph is empty, lacks a root, or has no path.
+    GraphStructure = 300,
+    /// A node or edge index does not exist.
+    Index = 400,
+    /// A context lookup failed.
+    Context = 500,
+    /// An action of the causal state machine failed to fire.
+    Action = 600,
+    /// An update of a node or state failed.
+    Update = 700,
+    /// An adjustment of a node failed.
+    Adjustment = 800,
+    /// A type could not be built.
+    Build = 900,
+    /// Failure of unknown origin, i.e. converted from a plain CausalityError.
+    Unknown = 999,
+}
+
+impl ReasoningErrorKind {
+    /// Returns the stable numeric code of this kind.
+    pub fn code(&self) -> u16 {
+        *self as u16
+    }
+
+    /// Returns true if an error of this kind may succeed when retried
+    /// without changing the input i.e. a failed action.
+    pub fn is_retriable_by_default(&self) -> bool {
+        matches!(
+            self,
+            ReasoningErrorKind::Action | ReasoningErrorKind::Update
+        )
+    }
+}
+
+impl fmt::Display for ReasoningErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Structured error for causal reasoning.
+///
+/// In addition to the message, a ReasoningError carries:
+/// * kind: the failure category with a stable error code
+/// * causaloid_id: the id of the failing causaloid, if known
+/// * source: the underlying error, forming a source chain
+/// * retriable: whether the operation may be retried
+///
+/// ReasoningError converts from all error types of this crate into the kind of their origin:
+/// CausalityError into Unknown, CausalityGraphError into GraphStructure,
+/// CausalGraphIndexError into Index, ContextIndexError into Context, ActionError into Action,
+/// UpdateError into Update, AdjustmentError into Adjustment, and BuildError into Build.
+///
+/// ReasoningError converts into CausalityError and CausalityGraphError, keeping kind, code,
+/// and causaloid id in the message, so existing code returning these errors continues to work.
+#[derive(Debug)]
+pub struct ReasoningError {
+    kind: ReasoningErrorKind,
+    message: String,
+    causaloid_id: Option<IdentificationValue>,
+    retriable: bool,
+    source: Option<Box<dyn Error + Send + Sync + 'static>>,
+}
+
+impl ReasoningError {
+    /// Creates a new ReasoningError. The retriable flag defaults to the one of the kind.
+    pub fn new(kind: ReasoningErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            causaloid_id: None,
+            retriable: kind.is_retriable_by_default(),
+            source: None,
+        }
+    }
+
+    /// Sets the id of the causaloid that failed.
+    pub fn with_causaloid_id(mut self, causaloid_id: IdentificationValue) -> Self {
+        self.causaloid_id = Some(causaloid_id);
+        self
+    }
+
+    /// Overrides the retriable flag.
+    pub fn with_retriable(mut self, retriable: bool) -> Self {
+        self.retriable = retriable;
+        self
+    }
+
+    /// Sets the underlying error that caused this error.
+    pub fn with_source<E>(mut self, source: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        self.source = Some(Box::new(source));
+        self
+    }
+}
+
+impl ReasoningError {
+    pub fn kind(&self) -> ReasoningErrorKind {
+        self.kind
+    }
+    pub fn code(&self) -> u16 {
+        self.kind.code()
+    }
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+    pub fn causaloid_id(&self) -> Option<IdentificationValue> {
+        self.causaloid_id
+    }
+    pub fn is_retriable(&self) -> bool {
+        self.retriable
+    }
+
+    /// Returns the messages of this error followed by all errors in its source chain.
+    pub fn chain(&self) -> Vec<String> {
+        let mut chain = vec![self.to_string()];
+
+        let mut current = self.source();
+        while let Some(err) = current {
+            chain.push(err.to_string());
+            current = err.source();
+        }
+
+        chain
+    }
+}
+
+impl Error for ReasoningError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|e| e.as_ref() as &(dyn Error + 'static))
+    }
+}
+
+impl fmt::Display for ReasoningError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.causaloid_id {
+            Some(id) => write!(
+                f,
+                "ReasoningError[E{}] {}: {} (causaloid: {})",
+                self.code(),
+                self.kind,
+                self.message,
+                id
+            ),
+            None => write!(
+                f,
+                "ReasoningError[E{}] {}: {}",
+                self.code(),
+                self.kind,
+                self.message
+            ),
+        }
+    }
+}
+
+// Backward compatible conversions into the legacy error types.
+impl From<ReasoningError> for CausalityError {
+    fn from(err: ReasoningError) -> Self {
+        CausalityError(err.to_string())
+    }
+}
+
+// Keeps kind, code, and causaloid id in the message of a CausalityGraphError.
+impl From<ReasoningError> for CausalityGraphError {
+    fn from(err: ReasoningError) -> Self {
+        CausalityGraphError(err.to_string())
+    }
+}
+
+impl From<CausalityError> for ReasoningError {
+    fn from(err: CausalityError) -> Self {
+        ReasoningError::new(ReasoningErrorKind::Unknown, err.0)
+    }
+}
+
+impl From<CausalityGraphError> for ReasoningError {
+    fn from(err: CausalityGraphError) -> Self {
+        ReasoningError::new(ReasoningErrorKind::GraphStructure, err.0)
+    }
+}
+
+impl From<CausalGraphIndexError> for ReasoningError {
+    fn from(err: CausalGraphIndexError) -> Self {
+        ReasoningError::new(ReasoningErrorKind::Index, err.0)
+    }
+}
+
+impl From<ContextIndexError> for ReasoningError {
+    fn from(err: ContextIndexError) -> Self {
+        ReasoningError::new(ReasoningErrorKind::Context, err.0)
+    }
+}
+
+impl From<ActionError> for ReasoningError {
+    fn from(err: ActionError) -> Self {
+        ReasoningError::new(ReasoningErrorKind::Action, err.0)
+    }
+}
+
+impl From<UpdateError> for ReasoningError {
+    fn from(err: UpdateError) -> Self {
+        ReasoningError::new(ReasoningErrorKind::Update, err.0)
+    }
+}
+
+impl From<AdjustmentError> for ReasoningError {
+    fn from(err: AdjustmentError) -> Self {
+        ReasoningError::new(ReasoningErrorKind::Adjustment, err.0)
+    }
+}
+
+impl From<BuildError> for ReasoningError {
+    fn from(err: BuildError) -> Self {
+        ReasoningError::new(ReasoningErrorKind::Build, err.0)
+    }
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::VecDeque;
+
+use crate::prelude::{Adjustable, BatchAdjustable, Identifiable};
+
+impl<T, A> BatchAdjustable<T, A> for [A]
+where
+    T: Copy + Default,
+    A: Adjustable<T> + Identifiable + Clone,
+{
+    fn get_all_items_mut(&mut self) -> Vec<&mut A> {
+        self.iter_mut().collect()
+    }
+}
+
+impl<T, A> BatchAdjustable<T, A> for Vec<A>
+where
+    T: Copy + Default,
+    A: Adjustable<T> + Identifiable + Clone,
+{
+    fn get_all_items_mut(&mut self) -> Vec<&mut A> {
+        self.iter_mut().collect()
+    }
+}
+
+impl<T, A> BatchAdjustable<T, A> for VecDeque<A>
+where
+    T: Copy + Default,
+    A: Adjustable<T> + Identifiable + Clone,
+{
+    fn get_all_items_mut(&mut self) -> Vec<&mut A> {
+        self.iter_mut().collect()
+    }
+}
@@ -6,7 +6,7 @@ use std::hash::Hash;
 
 use deep_causality_macros::{make_get_all_items, make_get_all_map_items, make_is_empty, make_len};
 
-use crate::prelude::{Inferable, InferableReasoning};
+use crate::prelude::{BayesianInferable, Inferable, InferableReasoning};
 
 impl<T> InferableReasoning<T> for [T]
 where
@@ -54,3 +54,23 @@ where
     make_is_empty!();
     make_get_all_items!();
 }
+
+impl<T> BayesianInferable<T> for [T] where T: Inferable {}
+
+impl<K, V> BayesianInferable<V> for HashMap<K, V>
+where
+    K: Eq + Hash,
+    V: Inferable,
+{
+}
+
+impl<K, V> BayesianInferable<V> for BTreeMap<K, V>
+where
+    K: Eq + Hash,
+    V: Inferable,
+{
+}
+
+impl<T> BayesianInferable<T> for Vec<T> where T: Inferable {}
+
+impl<T> BayesianInferable<T> for VecDeque<T> where T: Inferable {}
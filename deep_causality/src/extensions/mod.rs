@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
+pub mod adjustable;
 pub mod assumable;
 pub mod causable;
 pub mod inferable;
@@ -14,17 +14,25 @@ pub use crate::errors::*;
 //
 // Adjustable protocol
 pub use crate::protocols::adjustable::Adjustable;
+pub use crate::protocols::adjustable::BatchAdjustable;
+pub use crate::protocols::adjustable::OnlineAdjustable;
 // Assumeable protocols
 pub use crate::protocols::assumable::Assumable;
 pub use crate::protocols::assumable::AssumableReasoning;
+// Async causable protocol
+pub use crate::protocols::async_causable::AsyncCausable;
 // Causable protocols
 pub use crate::protocols::causable::Causable;
 pub use crate::protocols::causable::CausableReasoning;
 pub use crate::protocols::causable_graph::*;
 // Causable Graph protocols
 pub use crate::protocols::causable_graph::graph::CausableGraph;
+pub use crate::protocols::causable_graph::graph_attribution::*;
+pub use crate::protocols::causable_graph::graph_diagnosing::CausableGraphDiagnosing;
 pub use crate::protocols::causable_graph::graph_explaining::CausableGraphExplaining;
+pub use crate::protocols::causable_graph::graph_incremental::CausableGraphIncremental;
 pub use crate::protocols::causable_graph::graph_reasoning::CausableGraphReasoning;
+pub use crate::protocols::causable_graph::graph_simulating::CausableGraphSimulating;
 // contextuable protocols
 pub use crate::protocols::contextuable::Contextuable;
 pub use crate::protocols::contextuable::Datable;
@@ -34,11 +42,14 @@ pub use crate::protocols::contextuable::Temporable;
 // contextuable Graph protocol
 pub use crate::protocols::contextuable_graph::ContextuableGraph;
 pub use crate::protocols::contextuable_graph::ExtendableContextuableGraph;
+// Fingerprintable protocol
+pub use crate::protocols::fingerprintable::Fingerprintable;
 // Identifiable protocol
 pub use crate::protocols::identifiable::Identifiable;
 // Indexable protocol
 pub use crate::protocols::indexable::Indexable;
 // Inferable protocol
+pub use crate::protocols::inferable::BayesianInferable;
 pub use crate::protocols::inferable::Inferable;
 pub use crate::protocols::inferable::InferableReasoning;
 // Observable protocols
@@ -50,9 +61,13 @@ pub use crate::protocols::observable::ObservableReasoning;
 // Alias types
 pub use crate::types::alias_types::*;
 // Context types
+pub use crate::types::context_types::calendar_date::*;
+pub use crate::types::context_types::context_delta::ContextDelta;
 pub use crate::types::context_types::context_graph;
 // Context graph types
 pub use crate::types::context_types::context_graph::Context;
+pub use crate::types::context_types::context_set::ContextSet;
+pub use crate::types::context_types::context_shard_map::*;
 pub use crate::types::context_types::contextoid::*;
 // Graph types
 pub use crate::types::context_types::contextoid::contextoid_type::*;
@@ -67,21 +82,61 @@ pub use crate::types::context_types::node_types_adjustable::adjustable_data::*;
 pub use crate::types::context_types::node_types_adjustable::adjustable_space::*;
 pub use crate::types::context_types::node_types_adjustable::adjustable_space_time::*;
 pub use crate::types::context_types::node_types_adjustable::adjustable_time::*;
+pub use crate::types::context_types::node_types_adjustable::adjustment_undo::AdjustmentUndo;
 pub use crate::types::context_types::relation_kind::*;
+pub use crate::types::context_types::resample_method::ResampleMethod;
+pub use crate::types::context_types::shared_context::*;
 pub use crate::types::context_types::time_scale::TimeScale;
+pub use crate::types::context_types::time_series_context::TimeSeriesContext;
 pub use crate::types::csm_types::CSM;
 // CSM types
-pub use crate::types::csm_types::csm_action::CausalAction;
+pub use crate::types::csm_types::csm_action::{ActionStep, CausalAction};
+pub use crate::types::csm_types::csm_action_policy::*;
+pub use crate::types::csm_types::csm_arbitration::*;
+pub use crate::types::csm_types::csm_record::*;
 pub use crate::types::csm_types::csm_state::CausalState;
+pub use crate::types::csm_types::csm_state_debounce::StateDebounce;
+pub use crate::types::csm_types::csm_trigger::CSMTrigger;
+pub use crate::types::csm_types::csm_watchdog::Watchdog;
+pub use crate::types::csm_types::event_scheduler::*;
 // Model types
+pub use crate::types::model_types::model_card::*;
+pub use crate::types::model_types::model_provenance::ModelProvenance;
+pub use crate::types::model_types::model_version::ModelVersion;
 pub use crate::types::model_types::Model;
 // Reasoning types
 pub use crate::types::reasoning_types::assumption::Assumption;
+pub use crate::types::reasoning_types::assumption_monitor::*;
+pub use crate::types::reasoning_types::attribution::Attribution;
+pub use crate::types::reasoning_types::beta_distribution::BetaDistribution;
+pub use crate::types::reasoning_types::causal_executor::*;
 pub use crate::types::reasoning_types::causaloid::Causaloid;
 pub use crate::types::reasoning_types::causaloid_graph::CausaloidGraph;
+pub use crate::types::reasoning_types::conformal_calibrator::*;
+pub use crate::types::reasoning_types::dyn_causaloid::DynCausaloid;
+pub use crate::types::reasoning_types::eval_arena::EvalArena;
+pub use crate::types::reasoning_types::evaluation_cache::EvaluationCache;
+pub use crate::types::reasoning_types::evaluation_plan::*;
+pub use crate::types::reasoning_types::graph_import::*;
+pub use crate::types::reasoning_types::graph_validation_report::GraphValidationReport;
 pub use crate::types::reasoning_types::inference::Inference;
+pub use crate::types::reasoning_types::inference_posteriors::InferencePosteriors;
+pub use crate::types::reasoning_types::intervention_schedule::*;
+pub use crate::types::reasoning_types::intervention_sweep::*;
 pub use crate::types::reasoning_types::observation::Observation;
+pub use crate::types::reasoning_types::observation_statistics::ObservationStatistics;
+pub use crate::types::reasoning_types::observation_store::ObservationStore;
+pub use crate::types::reasoning_types::online_learner::OnlineLearner;
+pub use crate::types::reasoning_types::page_hinkley::PageHinkley;
+pub use crate::types::reasoning_types::parameter_change::ParameterChange;
+pub use crate::types::reasoning_types::reasoning_policy::ReasoningPolicy;
+pub use crate::types::reasoning_types::reasoning_report::*;
+pub use crate::types::reasoning_types::root_cause::*;
 //
 // Utils
 //
+pub use crate::utils::dot_utils::*;
+pub use crate::utils::fingerprint_utils::*;
+pub use crate::utils::json_utils::*;
+pub use crate::utils::resample_utils::*;
 pub use crate::utils::time_utils::*;
@@ -4,6 +4,10 @@
 use dcl_data_structures::prelude::ArrayGrid;
 
 use crate::errors::{AdjustmentError, UpdateError};
+use crate::prelude::{
+    AdjustmentUndo, Identifiable, IdentificationValue, NumericalValue, ParameterChange,
+};
+use crate::types::reasoning_types::online_learner;
 
 pub trait Adjustable<T>
 where
@@ -31,3 +35,161 @@ where
         Ok(())
     }
 }
+
+/// The BatchAdjustable trait provides transactional adjustments over a collection
+/// of adjustable nodes.
+///
+/// A batch adjustment is all-or-nothing: either every node of the batch is adjusted or,
+/// if any adjustment fails, no node is changed. A successful batch adjustment returns
+/// an undo token that restores all adjusted nodes, for example, to revert a clock skew
+/// correction applied to all time nodes of a context.
+///
+/// Nodes are addressed by their id. If multiple nodes share an id, the first one is adjusted.
+pub trait BatchAdjustable<T, A>
+where
+    T: Copy + Default,
+    A: Adjustable<T> + Identifiable + Clone,
+{
+    fn get_all_items_mut(&mut self) -> Vec<&mut A>;
+
+    /// Adjusts all nodes given by id with their adjustment data.
+    ///
+    /// All adjustments are first applied to copies of the nodes and only written back
+    /// if all of them succeed.
+    ///
+    /// Returns an undo token for the batch or an AdjustmentError if a node does not exist,
+    /// a node is listed more than once, or an adjustment fails.
+    fn adjust_all<const W: usize, const H: usize, const D: usize, const C: usize>(
+        &mut self,
+        adjustments: &[(IdentificationValue, &ArrayGrid<T, W, H, D, C>)],
+    ) -> Result<AdjustmentUndo<A>, AdjustmentError> {
+        let mut items = self.get_all_items_mut();
+
+        let mut positions = Vec::with_capacity(adjustments.len());
+        for (i, (id, _)) in adjustments.iter().enumerate() {
+            if adjustments[..i].iter().any(|(other, _)| other == id) {
+                return Err(AdjustmentError(format!(
+                    "Node {} is listed more than once in the batch",
+                    id
+                )));
+            }
+
+            match items.iter().position(|item| item.id() == *id) {
+                Some(position) => positions.push(position),
+                None => {
+                    return Err(AdjustmentError(format!(
+                        "Node {} does not exist and cannot be adjusted",
+                        id
+                    )))
+                }
+            }
+        }
+
+        // Adjust copies first so that a failed adjustment leaves all nodes unchanged.
+        let mut adjusted = Vec::with_capacity(adjustments.len());
+        for ((id, array_grid), position) in adjustments.iter().zip(positions.iter()) {
+            let mut node = items[*position].clone();
+            if let Err(e) = node.adjust(array_grid) {
+                return Err(AdjustmentError(format!(
+                    "Adjustment of node {} failed: {}",
+                    id, e.0
+                )));
+            }
+            adjusted.push(node);
+        }
+
+        let mut originals = Vec::with_capacity(adjustments.len());
+        for (position, node) in positions.into_iter().zip(adjusted) {
+            originals.push(std::mem::replace(items[position], node));
+        }
+
+        Ok(AdjustmentUndo::new(originals))
+    }
+
+    /// Restores all nodes of a batch adjustment from its undo token.
+    ///
+    /// Returns an AdjustmentError and leaves all nodes unchanged
+    /// if a node of the token no longer exists.
+    fn undo_adjustments(&mut self, undo: AdjustmentUndo<A>) -> Result<(), AdjustmentError> {
+        let mut items = self.get_all_items_mut();
+
+        let mut positions = Vec::with_capacity(undo.len());
+        for original in undo.originals() {
+            match items.iter().position(|item| item.id() == original.id()) {
+                Some(position) => positions.push(position),
+                None => {
+                    return Err(AdjustmentError(format!(
+                        "Node {} does not exist and cannot be restored",
+                        original.id()
+                    )))
+                }
+            }
+        }
+
+        for (position, original) in positions.into_iter().zip(undo.into_originals()) {
+            *items[position] = original;
+        }
+
+        Ok(())
+    }
+}
+
+/// The OnlineAdjustable trait updates a numeric parameter, i.e. a threshold,
+/// incrementally from prediction errors.
+///
+/// Each update moves the parameter by learning_rate * error, clamped to max_step
+/// and to the bounds of the parameter. With the error as difference between a target
+/// and the current parameter, repeated updates form an exponentially weighted
+/// moving average of the targets.
+///
+/// For logging all changes, see OnlineLearner.
+pub trait OnlineAdjustable: Identifiable {
+    fn parameter(&self) -> NumericalValue;
+
+    fn set_parameter(&mut self, value: NumericalValue);
+
+    /// Returns the lower and upper bound of the parameter.
+    /// The default implementation is unbounded.
+    fn parameter_bounds(&self) -> (NumericalValue, NumericalValue) {
+        (NumericalValue::NEG_INFINITY, NumericalValue::INFINITY)
+    }
+
+    /// Moves the parameter by learning_rate * error, clamped to max_step and the bounds.
+    ///
+    /// Returns the change of the parameter or an AdjustmentError if the error is not finite,
+    /// the learning rate is not in (0, 1], max_step is not positive, or the bounds are invalid.
+    /// The parameter is unchanged in case of an error.
+    fn adjust_online(
+        &mut self,
+        error: NumericalValue,
+        learning_rate: NumericalValue,
+        max_step: NumericalValue,
+    ) -> Result<ParameterChange, AdjustmentError> {
+        online_learner::check_rates(learning_rate, max_step)?;
+
+        if !error.is_finite() {
+            return Err(AdjustmentError(format!(
+                "Prediction error of node {} is not finite: {}",
+                self.id(),
+                error
+            )));
+        }
+
+        let (lower, upper) = self.parameter_bounds();
+        if lower.is_nan() || upper.is_nan() || lower > upper {
+            return Err(AdjustmentError(format!(
+                "Invalid parameter bounds of node {}: [{}, {}]",
+                self.id(),
+                lower,
+                upper
+            )));
+        }
+
+        let previous = self.parameter();
+        let step = (learning_rate * error).clamp(-max_step, max_step);
+        let current = (previous + step).clamp(lower, upper);
+        self.set_parameter(current);
+
+        Ok(ParameterChange::new(self.id(), previous, current, error))
+    }
+}
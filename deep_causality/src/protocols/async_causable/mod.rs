@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::errors::CausalityError;
+use crate::prelude::{Causable, NumericalValue};
+
+/// The AsyncCausable trait verifies a cause without blocking,
+/// for causal functions that await i.e. a database or remote feature store.
+///
+/// It requires implementing the Causable trait.
+///
+/// The returned future is runtime agnostic and can be awaited in any async executor.
+/// It is Send so that it can be spawned on a multi-threaded runtime,
+/// which requires the implementing type to be Sync.
+/// The default implementation evaluates verify_single_cause and returns its result as a ready future,
+/// hence every Causable can implement AsyncCausable with an empty impl.
+///
+pub trait AsyncCausable: Causable {
+    fn verify_single_cause_async<'a>(
+        &'a self,
+        obs: &NumericalValue,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, CausalityError>> + Send + 'a>>
+    where
+        Self: Sync,
+    {
+        Box::pin(std::future::ready(self.verify_single_cause(obs)))
+    }
+}
@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-use crate::errors::CausalityError;
+use crate::errors::{CausalityError, ReasoningError, ReasoningErrorKind};
 use crate::prelude::{Identifiable, IdentificationValue, NumericalValue};
 
 /// The Causable trait defines the core behavior for causal reasoning.
@@ -21,6 +21,10 @@ use crate::prelude::{Identifiable, IdentificationValue, NumericalValue};
 /// `verify_single_cause` and `verify_all_causes` return a Result indicating
 /// if the cause was validated or not.
 ///
+/// `try_verify_single_cause` and `try_verify_all_causes` are provided and return
+/// a ReasoningError of kind Evaluation that carries the id of the cause and
+/// the CausalityError as its source if the verification failed.
+///
 pub trait Causable: Identifiable {
     fn explain(&self) -> Result<String, CausalityError>;
     fn is_active(&self) -> bool;
@@ -33,6 +37,27 @@ pub trait Causable: Identifiable {
         data: &[NumericalValue],
         data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
     ) -> Result<bool, CausalityError>;
+
+    fn try_verify_single_cause(&self, obs: &NumericalValue) -> Result<bool, ReasoningError> {
+        self.verify_single_cause(obs)
+            .map_err(|e| evaluation_error(self.id(), e))
+    }
+
+    fn try_verify_all_causes(
+        &self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<bool, ReasoningError> {
+        self.verify_all_causes(data, data_index)
+            .map_err(|e| evaluation_error(self.id(), e))
+    }
+}
+
+// Wraps the failed verification of the cause with the given id into a ReasoningError.
+fn evaluation_error(id: IdentificationValue, err: CausalityError) -> ReasoningError {
+    ReasoningError::new(ReasoningErrorKind::Evaluation, err.0.clone())
+        .with_causaloid_id(id)
+        .with_source(err)
 }
 
 /// The CausableReasoning trait provides default implementations for reasoning over collections of Causable items.
@@ -0,0 +1,407 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use crate::errors::CausalityGraphError;
+use crate::prelude::{
+    Attribution, Causable, CausableGraphReasoning, IdentificationValue, NumericalValue,
+    ReasoningPolicy,
+};
+use crate::protocols::causable_graph::graph_reasoning_utils;
+
+/// Maximum number of inputs for exact Shapley values, which requires 2^n evaluations.
+pub const MAX_EXACT_SHAPLEY_INPUTS: usize = 16;
+
+/// The CausableGraphAttribution trait quantifies how much each input causaloid
+/// contributes to the effect of a target causaloid.
+///
+/// It requires the graph to implement CausableGraphReasoning.
+///
+/// The graph serves as value function: for a coalition of inputs, the inputs in the
+/// coalition receive their observed data, all other inputs receive their baseline data,
+/// and the value of the coalition is 1 if the target evaluates to true and 0 otherwise.
+///
+/// Provides methods to:
+///
+/// - Compute leave-one-out attributions
+/// - Compute exact Shapley values
+/// - Compute sampled Shapley values for a large number of inputs
+///
+pub trait CausableGraphAttribution<T>: CausableGraphReasoning<T>
+where
+    T: Causable + PartialEq,
+{
+    /// Computes the leave-one-out attribution of each input for the effect of the target.
+    ///
+    /// The attribution of an input is the value of the coalition of all inputs
+    /// minus the value of the coalition of all inputs except this one, i.e. 1 if resetting
+    /// the input to its baseline alone removes the effect, and 0 otherwise.
+    /// Redundant inputs that each suffice for the effect all receive 0.
+    ///
+    /// target_index: NodeIndex - index of the target causaloid
+    /// inputs: &[usize] - indices of the singleton input causaloids
+    /// data: &[NumericalValue] - observed data
+    /// baseline: &[NumericalValue] - reference data with the same layout as data
+    /// Optional: data_index - provide when the data have a different index sorting than
+    /// the causaloids.
+    ///
+    /// Requires n + 1 evaluations of the graph for n inputs.
+    ///
+    /// Returns Result either the attribution of each input in the order of inputs or
+    /// a CausalityGraphError in case of failure.
+    fn leave_one_out_attribution(
+        &self,
+        target_index: usize,
+        inputs: &[usize],
+        data: &[NumericalValue],
+        baseline: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<Vec<Attribution>, CausalityGraphError> {
+        let positions = input_positions(self, target_index, inputs, data, baseline, data_index)?;
+
+        let mut coalition = vec![true; inputs.len()];
+        let value_all = coalition_value(
+            self,
+            target_index,
+            &positions,
+            &coalition,
+            data,
+            baseline,
+            data_index,
+        )?;
+
+        let mut attributions = Vec::with_capacity(inputs.len());
+        for (i, input) in inputs.iter().enumerate() {
+            coalition[i] = false;
+            let value_without = coalition_value(
+                self,
+                target_index,
+                &positions,
+                &coalition,
+                data,
+                baseline,
+                data_index,
+            )?;
+            coalition[i] = true;
+
+            attributions.push(new_attribution(self, *input, value_all - value_without));
+        }
+
+        Ok(attributions)
+    }
+
+    /// Computes the exact Shapley value of each input for the effect of the target.
+    ///
+    /// target_index: NodeIndex - index of the target causaloid
+    /// inputs: &[usize] - indices of the singleton input causaloids
+    /// data: &[NumericalValue] - observed data
+    /// baseline: &[NumericalValue] - reference data with the same layout as data
+    /// Optional: data_index - provide when the data have a different index sorting than
+    /// the causaloids.
+    ///
+    /// Requires 2^n evaluations of the graph for n inputs, therefore the number of inputs
+    /// is limited to MAX_EXACT_SHAPLEY_INPUTS.
+    ///
+    /// Returns Result either the attribution of each input in the order of inputs or
+    /// a CausalityGraphError in case of failure.
+    fn shapley_attribution(
+        &self,
+        target_index: usize,
+        inputs: &[usize],
+        data: &[NumericalValue],
+        baseline: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<Vec<Attribution>, CausalityGraphError> {
+        let positions = input_positions(self, target_index, inputs, data, baseline, data_index)?;
+
+        let n = inputs.len();
+        if n > MAX_EXACT_SHAPLEY_INPUTS {
+            return Err(CausalityGraphError(format!(
+                "Exact Shapley values support at most {} inputs but got {}. Use sampled_shapley_attribution instead.",
+                MAX_EXACT_SHAPLEY_INPUTS, n
+            )));
+        }
+
+        // Value of each coalition, encoded as bit mask over the inputs.
+        let mut values = Vec::with_capacity(1 << n);
+        for mask in 0..(1usize << n) {
+            values.push(coalition_value(
+                self,
+                target_index,
+                &positions,
+                &mask_coalition(mask, n),
+                data,
+                baseline,
+                data_index,
+            )?);
+        }
+
+        // Shapley weight |S|! (n - |S| - 1)! / n! for a coalition S without the input.
+        let factorial = |k: usize| {
+            (1..=k)
+                .map(|i| i as NumericalValue)
+                .product::<NumericalValue>()
+        };
+        let n_factorial = factorial(n);
+
+        let mut attributions = Vec::with_capacity(n);
+        for (i, input) in inputs.iter().enumerate() {
+            let bit = 1usize << i;
+            let mut value = 0.0;
+
+            for mask in 0..(1usize << n) {
+                if mask & bit != 0 {
+                    continue;
+                }
+                let size = mask.count_ones() as usize;
+                let weight = factorial(size) * factorial(n - size - 1) / n_factorial;
+                value += weight * (values[mask | bit] - values[mask]);
+            }
+
+            attributions.push(new_attribution(self, *input, value));
+        }
+
+        Ok(attributions)
+    }
+
+    /// Approximates the Shapley value of each input for the effect of the target
+    /// by sampling random permutations of the inputs.
+    ///
+    /// target_index: NodeIndex - index of the target causaloid
+    /// inputs: &[usize] - indices of the singleton input causaloids
+    /// data: &[NumericalValue] - observed data
+    /// baseline: &[NumericalValue] - reference data with the same layout as data
+    /// Optional: data_index - provide when the data have a different index sorting than
+    /// the causaloids.
+    /// samples: usize - number of sampled permutations
+    /// seed: u64 - seed of the permutation sampler; the same seed yields the same result.
+    ///
+    /// Requires samples * n evaluations of the graph for n inputs.
+    ///
+    /// Returns Result either the attribution of each input in the order of inputs or
+    /// a CausalityGraphError in case of failure.
+    #[allow(clippy::too_many_arguments)]
+    fn sampled_shapley_attribution(
+        &self,
+        target_index: usize,
+        inputs: &[usize],
+        data: &[NumericalValue],
+        baseline: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+        samples: usize,
+        seed: u64,
+    ) -> Result<Vec<Attribution>, CausalityGraphError> {
+        let positions = input_positions(self, target_index, inputs, data, baseline, data_index)?;
+
+        if samples == 0 {
+            return Err(CausalityGraphError(
+                "Number of samples must be greater than zero".into(),
+            ));
+        }
+
+        let n = inputs.len();
+        let mut totals = vec![0.0; n];
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sampler = PermutationSampler::new(seed);
+
+        for _ in 0..samples {
+            // Fisher-Yates shuffle with uniformly drawn swap positions.
+            for i in (1..n).rev() {
+                let j = sampler.below(i as u64 + 1) as usize;
+                permutation.swap(i, j);
+            }
+
+            let mut coalition = vec![false; n];
+            let mut previous = coalition_value(
+                self,
+                target_index,
+                &positions,
+                &coalition,
+                data,
+                baseline,
+                data_index,
+            )?;
+
+            for &i in permutation.iter() {
+                coalition[i] = true;
+                let current = coalition_value(
+                    self,
+                    target_index,
+                    &positions,
+                    &coalition,
+                    data,
+                    baseline,
+                    data_index,
+                )?;
+                totals[i] += current - previous;
+                previous = current;
+            }
+        }
+
+        Ok(inputs
+            .iter()
+            .zip(totals)
+            .map(|(input, total)| new_attribution(self, *input, total / samples as NumericalValue))
+            .collect())
+    }
+}
+
+// Validates the arguments and returns the data position of each input.
+fn input_positions<T, G>(
+    graph: &G,
+    target_index: usize,
+    inputs: &[usize],
+    data: &[NumericalValue],
+    baseline: &[NumericalValue],
+    data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+) -> Result<Vec<usize>, CausalityGraphError>
+where
+    T: Causable + PartialEq,
+    G: CausableGraphReasoning<T> + ?Sized,
+{
+    if !graph.contains_root_causaloid() {
+        return Err(CausalityGraphError(
+            "Graph does not contains root causaloid".into(),
+        ));
+    }
+
+    if !graph.contains_causaloid(target_index) {
+        return Err(CausalityGraphError(
+            "Graph does not contains target causaloid".into(),
+        ));
+    }
+
+    if inputs.is_empty() {
+        return Err(CausalityGraphError("Inputs are empty (len ==0).".into()));
+    }
+
+    if data.len() != baseline.len() {
+        return Err(CausalityGraphError(format!(
+            "Data and baseline differ in length: {} vs {}",
+            data.len(),
+            baseline.len()
+        )));
+    }
+
+    let mut positions = Vec::with_capacity(inputs.len());
+    for (i, index) in inputs.iter().enumerate() {
+        if inputs[..i].contains(index) {
+            return Err(CausalityGraphError(format!(
+                "Input causaloid at index {} is listed more than once",
+                index
+            )));
+        }
+
+        let cause = match graph.get_causaloid(*index) {
+            Some(cause) if cause.is_singleton() => cause,
+            Some(_) => {
+                return Err(CausalityGraphError(format!(
+                    "Input causaloid at index {} is not a singleton",
+                    index
+                )))
+            }
+            None => {
+                return Err(CausalityGraphError(format!(
+                    "Graph does not contains input causaloid at index {}",
+                    index
+                )))
+            }
+        };
+
+        match graph_reasoning_utils::get_data_position(cause.id(), data.len(), &data_index) {
+            Some(position) => positions.push(position),
+            None => {
+                return Err(CausalityGraphError(format!(
+                    "No data found for input causaloid at index {}",
+                    index
+                )))
+            }
+        }
+    }
+
+    Ok(positions)
+}
+
+// Deterministic random number generator for permutation sampling.
+// Uses splitmix64, which yields a distinct, well mixed sequence for every seed including zero.
+struct PermutationSampler {
+    state: u64,
+}
+
+impl PermutationSampler {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Returns a uniformly distributed value in 0..bound for bound > 0.
+    // Multiply-shift with rejection of the biased low products avoids the modulo bias.
+    fn below(&mut self, bound: u64) -> u64 {
+        let mut product = self.next_u64() as u128 * bound as u128;
+        if (product as u64) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while (product as u64) < threshold {
+                product = self.next_u64() as u128 * bound as u128;
+            }
+        }
+        (product >> 64) as u64
+    }
+}
+
+// Returns the coalition encoded as bit mask over the first n inputs.
+fn mask_coalition(mask: usize, n: usize) -> Vec<bool> {
+    (0..n).map(|i| mask & (1 << i) != 0).collect()
+}
+
+// Returns 1 if the target evaluates to true when only the inputs in the coalition
+// receive their observed data, 0 otherwise.
+fn coalition_value<T, G>(
+    graph: &G,
+    target_index: usize,
+    positions: &[usize],
+    coalition: &[bool],
+    data: &[NumericalValue],
+    baseline: &[NumericalValue],
+    data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+) -> Result<NumericalValue, CausalityGraphError>
+where
+    T: Causable + PartialEq,
+    G: CausableGraphReasoning<T> + ?Sized,
+{
+    let mut coalition_data = data.to_vec();
+    for (position, in_coalition) in positions.iter().zip(coalition) {
+        if !in_coalition {
+            coalition_data[*position] = baseline[*position];
+        }
+    }
+
+    let root_index = graph.get_root_index().expect("Root causaloid not found.");
+    let report = graph.reason_subgraph_from_cause_with_policy(
+        root_index,
+        &coalition_data,
+        data_index,
+        ReasoningPolicy::FailFast,
+    )?;
+
+    Ok(match report.effect_of(target_index) {
+        Some(true) => 1.0,
+        _ => 0.0,
+    })
+}
+
+fn new_attribution<T, G>(graph: &G, index: usize, value: NumericalValue) -> Attribution
+where
+    T: Causable + PartialEq,
+    G: CausableGraphReasoning<T> + ?Sized,
+{
+    let cause = graph.get_causaloid(index).expect("Failed to get causaloid");
+    Attribution::new(index, cause.id(), value)
+}
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use ultragraph::prelude::*;
+
+use crate::errors::CausalityGraphError;
+use crate::prelude::{
+    Causable, CausableGraphReasoning, IdentificationValue, NumericalValue, ReasoningPolicy,
+    RootCause,
+};
+use crate::protocols::causable_graph::graph_reasoning_utils;
+
+/// The CausableGraphDiagnosing trait provides root cause analysis
+/// for anomalous outcomes of a causal graph.
+///
+/// It requires the graph to implement CausableGraphReasoning.
+///
+/// Provides methods to:
+///
+/// - Rank the upstream causaloids of a target by counterfactual contribution
+///
+pub trait CausableGraphDiagnosing<T>: CausableGraphReasoning<T>
+where
+    T: Causable + PartialEq,
+{
+    /// Ranks all upstream causaloids of a target causaloid by their counterfactual
+    /// contribution to an anomalous effect of the target.
+    ///
+    /// target_index: NodeIndex - index of the causaloid showing the anomalous effect
+    /// data: &[NumericalValue] - observed data that produced the anomalous effect
+    /// baseline: &[NumericalValue] - reference data of normal operation with the same layout as data
+    /// Optional: data_index - provide when the data have a different index sorting than
+    /// the causaloids.
+    /// anomalous_effect: bool - the anomalous effect observed at the target
+    ///
+    /// For each upstream singleton causaloid, the data of only that causaloid are replaced
+    /// by its baseline data (leave-one-intervention-out) and the graph is reasoned from the root again.
+    /// A causaloid contributes if the intervention resolves the anomalous effect of the target.
+    /// The per-node deltas list all causaloids whose effect changed under the intervention.
+    /// Causaloids whose data equal the baseline cannot contribute and are not ranked.
+    ///
+    /// The ranking orders causaloids that resolve the anomalous effect first,
+    /// then by the number of changed causaloids, and then by index.
+    ///
+    /// Returns Result either the ranking or a CausalityGraphError if the graph has no root,
+    /// the target does not exist or does not show the anomalous effect, the data and baseline
+    /// differ in length, or reasoning fails.
+    fn rank_root_causes(
+        &self,
+        target_index: usize,
+        data: &[NumericalValue],
+        baseline: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+        anomalous_effect: bool,
+    ) -> Result<Vec<RootCause>, CausalityGraphError> {
+        if !self.contains_root_causaloid() {
+            return Err(CausalityGraphError(
+                "Graph does not contains root causaloid".into(),
+            ));
+        }
+
+        if !self.contains_causaloid(target_index) {
+            return Err(CausalityGraphError(
+                "Graph does not contains target causaloid".into(),
+            ));
+        }
+
+        if data.len() != baseline.len() {
+            return Err(CausalityGraphError(format!(
+                "Data and baseline differ in length: {} vs {}",
+                data.len(),
+                baseline.len()
+            )));
+        }
+
+        let root_index = self.get_root_index().expect("Root causaloid not found.");
+
+        let observed = self.reason_subgraph_from_cause_with_policy(
+            root_index,
+            data,
+            data_index,
+            ReasoningPolicy::FailFast,
+        )?;
+
+        if observed.effect_of(target_index).unwrap_or(false) != anomalous_effect {
+            return Err(CausalityGraphError(format!(
+                "Target causaloid at index {} does not show the anomalous effect {}",
+                target_index, anomalous_effect
+            )));
+        }
+
+        let edges = self.get_graph().get_all_edges();
+        let ancestors = graph_reasoning_utils::get_ancestors(&edges, target_index);
+
+        let mut root_causes = Vec::with_capacity(ancestors.len());
+
+        for index in ancestors {
+            let cause = self.get_causaloid(index).expect("Failed to get causaloid");
+
+            if !cause.is_singleton() {
+                continue;
+            }
+
+            let position =
+                match graph_reasoning_utils::get_data_position(cause.id(), data.len(), &data_index)
+                {
+                    Some(position) => position,
+                    None => continue,
+                };
+
+            if data[position] == baseline[position] {
+                continue;
+            }
+
+            let mut counterfactual_data = data.to_vec();
+            counterfactual_data[position] = baseline[position];
+
+            let counterfactual = self.reason_subgraph_from_cause_with_policy(
+                root_index,
+                &counterfactual_data,
+                data_index,
+                ReasoningPolicy::FailFast,
+            )?;
+
+            let outcome_flipped =
+                counterfactual.effect_of(target_index).unwrap_or(false) != anomalous_effect;
+
+            root_causes.push(RootCause::new(
+                index,
+                cause.id(),
+                outcome_flipped,
+                observed.diff(&counterfactual).deltas().clone(),
+            ));
+        }
+
+        root_causes.sort_by(|a, b| {
+            b.outcome_flipped()
+                .cmp(a.outcome_flipped())
+                .then(b.deltas().len().cmp(&a.deltas().len()))
+                .then(a.index().cmp(b.index()))
+        });
+
+        Ok(root_causes)
+    }
+}
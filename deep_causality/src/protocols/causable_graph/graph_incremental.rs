@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::{HashMap, HashSet};
+
+use ultragraph::prelude::*;
+
+use crate::errors::CausalityGraphError;
+use crate::prelude::{
+    Causable, CausableGraphReasoning, EvaluationCache, IdentificationValue, NumericalValue,
+};
+use crate::protocols::causable_graph::graph_reasoning_utils;
+
+/// The CausableGraphIncremental trait reasons over a causal graph
+/// while re-using the effects of causaloids whose input did not change.
+///
+/// It requires the graph to implement CausableGraphReasoning.
+///
+/// Provides methods to:
+///
+/// - Reason incrementally over the entire graph with an EvaluationCache
+///
+pub trait CausableGraphIncremental<T>: CausableGraphReasoning<T>
+where
+    T: Causable + PartialEq,
+{
+    /// Reason over the entire graph and re-evaluate only causaloids with changed input.
+    ///
+    /// data: &[NumericalValue] - data applied to the graph
+    /// Optional: data_index - provide when the data have a different index sorting than
+    /// the causaloids.
+    /// cache: &mut EvaluationCache - effects of previous reasoning, updated in place
+    ///
+    /// Starting from the root, every reachable causaloid is visited once.
+    /// A singleton causaloid is re-evaluated if its observation differs from the cached one,
+    /// a causaloid of a collection or graph if any of the data or the data index changed,
+    /// and any causaloid
+    /// marked dirty in the cache. All other causaloids return their cached effect.
+    /// Causaloids that depend on a context should be marked dirty when the context changes.
+    ///
+    /// Reasoning stops at the first causaloid with a false effect, as in reason_all_causes.
+    /// The same cache must only be used with the same graph.
+    ///
+    /// Returns Result either true or false in case of successful reasoning or
+    /// a CausalityGraphError in case of failure.
+    fn evaluate_incremental(
+        &self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+        cache: &mut EvaluationCache,
+    ) -> Result<bool, CausalityGraphError> {
+        if !self.contains_root_causaloid() {
+            return Err(CausalityGraphError(
+                "Graph does not contains root causaloid".into(),
+            ));
+        }
+
+        if data.is_empty() {
+            return Err(CausalityGraphError("Data are empty (len ==0).".into()));
+        }
+
+        let root_index = self.get_root_index().expect("Root causaloid not found.");
+        let data_changed = cache.begin(data, data_index);
+
+        let mut visited = HashSet::with_capacity(self.size());
+        let mut stack = vec![root_index];
+
+        while let Some(index) = stack.pop() {
+            if !visited.insert(index) {
+                continue;
+            }
+
+            let cause = self.get_causaloid(index).expect("Failed to get causaloid");
+
+            let effect = if cause.is_singleton() {
+                let obs = graph_reasoning_utils::try_get_obs(cause.id(), data, &data_index)
+                    .ok_or_else(|| {
+                        CausalityGraphError(format!("No data for causaloid at index {}", index))
+                    })?;
+
+                match cache.lookup_single(index, obs) {
+                    Some(effect) => effect,
+                    None => {
+                        let effect = cause
+                            .verify_single_cause(&obs)
+                            .map_err(|e| CausalityGraphError(e.0))?;
+                        cache.store(index, Some(obs), effect);
+                        effect
+                    }
+                }
+            } else {
+                match cache.lookup_all(index, data_changed) {
+                    Some(effect) => effect,
+                    None => {
+                        let effect = cause
+                            .verify_all_causes(data, data_index)
+                            .map_err(|e| CausalityGraphError(e.0))?;
+                        cache.store(index, None, effect);
+                        effect
+                    }
+                }
+            };
+
+            if !effect {
+                return Ok(false);
+            }
+
+            let children = self
+                .get_graph()
+                .outgoing_edges(index)
+                .expect("Failed to get children");
+            stack.extend(children);
+        }
+
+        Ok(true)
+    }
+}
@@ -5,8 +5,11 @@ use std::collections::HashMap;
 
 use ultragraph::prelude::*;
 
-use crate::errors::CausalityGraphError;
-use crate::prelude::{Causable, CausableGraph, IdentificationValue, NumericalValue};
+use crate::errors::{CausalityGraphError, ReasoningError, ReasoningErrorKind};
+use crate::prelude::{
+    Causable, CausableGraph, EvalArena, IdentificationValue, NodeFailure, NumericalValue,
+    ReasoningPolicy, ReasoningReport,
+};
 use crate::protocols::causable_graph::graph_reasoning_utils;
 
 /// Describes signatures for causal reasoning and explaining
@@ -125,6 +128,193 @@ where
         }
     }
 
+    /// Reason over the entire graph and apply the given policy to failing causaloids.
+    ///
+    /// data: &[NumericalValue] - data applied to the graph
+    /// Optional: data_index - provide when the data have a different index sorting than
+    /// the causaloids.
+    /// policy: ReasoningPolicy - determines how a failing causaloid is handled.
+    ///
+    /// See reason_subgraph_from_cause_with_policy for details.
+    ///
+    /// Returns Result either a ReasoningReport in case of successful reasoning or
+    /// a ReasoningError in case of failure.
+    fn reason_all_causes_with_policy(
+        &self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+        policy: ReasoningPolicy,
+    ) -> Result<ReasoningReport, ReasoningError> {
+        if !self.contains_root_causaloid() {
+            return Err(ReasoningError::new(
+                ReasoningErrorKind::GraphStructure,
+                "Graph does not contains root causaloid",
+            ));
+        }
+
+        let start_index = self.get_root_index().expect("Root causaloid not found.");
+
+        self.reason_subgraph_from_cause_with_policy(start_index, data, data_index, policy)
+    }
+
+    /// Reason over a subgraph starting from a given node index
+    /// and apply the given policy to failing causaloids.
+    ///
+    /// start_index: NodeIndex - index of the starting node
+    /// data: &[NumericalValue] - data applied to the subgraph
+    /// Optional: data_index - provide when the data have a different index sorting than
+    /// the causaloids.
+    /// policy: ReasoningPolicy - determines how a failing causaloid is handled.
+    ///
+    /// Traverses the subgraph depth-first and evaluates each reachable causaloid once.
+    /// The children of a causaloid are only evaluated if the causaloid evaluated to true.
+    /// Unlike reason_subgraph_from_cause, reasoning does not stop at the first false causaloid
+    /// so that the report contains the effect of every reachable causaloid.
+    ///
+    /// A causaloid fails if its causal function returns an error or if no data exist for it.
+    /// * FailFast returns the error of the first failing causaloid, including its kind
+    ///   and the id of the causaloid.
+    /// * SkipAndLog records the failure and skips all causaloids reachable only through it.
+    /// * SubstituteDefault records the failure and uses the given value as its effect.
+    ///
+    /// Returns Result either a ReasoningReport in case of successful reasoning or
+    /// a ReasoningError in case of failure.
+    fn reason_subgraph_from_cause_with_policy(
+        &self,
+        start_index: usize,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+        policy: ReasoningPolicy,
+    ) -> Result<ReasoningReport, ReasoningError> {
+        let mut arena = EvalArena::with_capacity(self.size());
+        self.reason_subgraph_from_cause_with_arena(
+            start_index,
+            data,
+            data_index,
+            policy,
+            &mut arena,
+        )?;
+
+        Ok(arena.into_report())
+    }
+
+    /// Reason over the entire graph with the given policy and reuse the buffers of the arena.
+    ///
+    /// Behaves like reason_all_causes_with_policy but keeps the effect of each evaluated
+    /// causaloid and all failures in the arena instead of a newly allocated report.
+    ///
+    /// Returns Result either the overall result in case of successful reasoning or
+    /// a ReasoningError in case of failure.
+    fn reason_all_causes_with_arena(
+        &self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+        policy: ReasoningPolicy,
+        arena: &mut EvalArena,
+    ) -> Result<bool, ReasoningError> {
+        if !self.contains_root_causaloid() {
+            return Err(ReasoningError::new(
+                ReasoningErrorKind::GraphStructure,
+                "Graph does not contains root causaloid",
+            ));
+        }
+
+        let start_index = self.get_root_index().expect("Root causaloid not found.");
+
+        self.reason_subgraph_from_cause_with_arena(start_index, data, data_index, policy, arena)
+    }
+
+    /// Reason over a subgraph starting from a given node index with the given policy
+    /// and reuse the buffers of the arena.
+    ///
+    /// Resets the arena and then behaves like reason_subgraph_from_cause_with_policy,
+    /// but keeps the effect of each evaluated causaloid and all failures in the arena
+    /// until the next evaluation instead of a newly allocated report.
+    ///
+    /// Returns Result either the overall result in case of successful reasoning or
+    /// a ReasoningError in case of failure.
+    fn reason_subgraph_from_cause_with_arena(
+        &self,
+        start_index: usize,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+        policy: ReasoningPolicy,
+        arena: &mut EvalArena,
+    ) -> Result<bool, ReasoningError> {
+        if self.is_empty() {
+            return Err(ReasoningError::new(
+                ReasoningErrorKind::GraphStructure,
+                "Graph is empty",
+            ));
+        }
+
+        if data.is_empty() {
+            return Err(ReasoningError::new(
+                ReasoningErrorKind::InvalidData,
+                "Data are empty (len ==0).",
+            ));
+        }
+
+        if !self.contains_causaloid(start_index) {
+            return Err(ReasoningError::new(
+                ReasoningErrorKind::Index,
+                "Graph does not contains start causaloid",
+            ));
+        }
+
+        arena.reset();
+        arena.push_frontier(start_index);
+
+        while let Some(index) = arena.pop_frontier() {
+            if !arena.visit(index) {
+                continue;
+            }
+
+            let cause = self.get_causaloid(index).expect("Failed to get causaloid");
+
+            let verified = if cause.is_singleton() {
+                match graph_reasoning_utils::try_get_obs(cause.id(), data, &data_index) {
+                    Some(obs) => cause.try_verify_single_cause(&obs),
+                    None => Err(ReasoningError::new(
+                        ReasoningErrorKind::InvalidData,
+                        "No data found for causaloid",
+                    )
+                    .with_causaloid_id(cause.id())),
+                }
+            } else {
+                cause.try_verify_all_causes(data, data_index)
+            };
+
+            let res = match verified {
+                Ok(res) => res,
+                Err(error) => match policy {
+                    ReasoningPolicy::FailFast => return Err(error),
+                    ReasoningPolicy::SkipAndLog => {
+                        arena.record_failure(NodeFailure::new(index, error));
+                        continue;
+                    }
+                    ReasoningPolicy::SubstituteDefault(value) => {
+                        arena.record_failure(NodeFailure::new(index, error));
+                        value
+                    }
+                },
+            };
+
+            arena.record(index, res);
+
+            if res {
+                let children = self
+                    .get_graph()
+                    .outgoing_edges(index)
+                    .expect("Failed to get children");
+
+                arena.extend_frontier(children);
+            }
+        }
+
+        Ok(arena.result())
+    }
+
     /// Reasons over the graph from start_index to stop_index.
     ///
     /// start_index: Node index to start reasoning from
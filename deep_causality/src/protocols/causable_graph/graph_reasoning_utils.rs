@@ -35,3 +35,62 @@ pub(crate) fn get_obs<'a>(
 
     obs.to_owned()
 }
+
+/// Gets the observation value for a cause from the given data
+/// without panicking when either the data index or the data are missing.
+///
+/// Returns None if no observation exists for the cause.
+///
+pub(crate) fn try_get_obs(
+    cause_id: IdentificationValue,
+    data: &[NumericalValue],
+    data_index: &Option<&HashMap<IdentificationValue, IdentificationValue>>,
+) -> Option<NumericalValue> {
+    let index = match data_index {
+        Some(data_index) => *data_index.get(&cause_id)?,
+        None => cause_id,
+    };
+
+    data.get(index as usize).copied()
+}
+
+/// Returns the indices of all nodes from which the target is reachable,
+/// excluding the target itself, in ascending order.
+pub(crate) fn get_ancestors(edges: &[(usize, usize)], target: usize) -> Vec<usize> {
+    let mut parents: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (a, b) in edges {
+        parents.entry(*b).or_default().push(*a);
+    }
+
+    let mut ancestors = Vec::new();
+    let mut stack = vec![target];
+
+    while let Some(index) = stack.pop() {
+        if let Some(p) = parents.get(&index) {
+            for parent in p {
+                if *parent != target && !ancestors.contains(parent) {
+                    ancestors.push(*parent);
+                    stack.push(*parent);
+                }
+            }
+        }
+    }
+
+    ancestors.sort_unstable();
+    ancestors
+}
+
+/// Returns the position of the data of a cause, either from the data_index
+/// or directly from the cause_id, or None if the cause has no data.
+pub(crate) fn get_data_position(
+    cause_id: IdentificationValue,
+    data_len: usize,
+    data_index: &Option<&HashMap<IdentificationValue, IdentificationValue>>,
+) -> Option<usize> {
+    let position = match data_index {
+        Some(data_index) => *data_index.get(&cause_id)? as usize,
+        None => cause_id as usize,
+    };
+
+    (position < data_len).then_some(position)
+}
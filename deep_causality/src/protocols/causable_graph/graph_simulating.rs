@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use crate::errors::CausalityGraphError;
+use crate::prelude::{
+    Causable, CausableGraphReasoning, IdentificationValue, InterventionSchedule, InterventionSweep,
+    NumericalValue, ReasoningPolicy, ReasoningReport, SweepAxis,
+};
+use crate::protocols::causable_graph::graph_reasoning_utils;
+
+/// The CausableGraphSimulating trait evaluates sequential interventions
+/// on a causal graph over discrete time steps.
+///
+/// It requires the graph to implement CausableGraphReasoning.
+///
+/// Provides methods to:
+///
+/// - Simulate a schedule of interventions while rolling the data forward between steps
+/// - Sweep intervention values of one or two causaloids
+///
+pub trait CausableGraphSimulating<T>: CausableGraphReasoning<T>
+where
+    T: Causable + PartialEq,
+{
+    /// Simulates a schedule of interventions over discrete time steps, g-computation style.
+    ///
+    /// schedule: &InterventionSchedule - interventions per step
+    /// initial_data: &[NumericalValue] - data at step zero
+    /// Optional: data_index - provide when the data have a different index sorting than
+    /// the causaloids.
+    /// transition: FnMut(step, &mut data, &report) - rolls the data forward from one step to the next
+    ///
+    /// At each step, the interventions of the step set the data of the targeted causaloids,
+    /// the graph is reasoned from the root, and the transition updates the data
+    /// for the next step given the report of the current step. The transition
+    /// is not called after the last step. Intervened values persist unless
+    /// the transition changes them.
+    ///
+    /// Returns Result either one reasoning report per step or a CausalityGraphError
+    /// if the graph has no root, an intervention targets a causaloid that does not exist
+    /// or has no data, or reasoning fails.
+    fn simulate_intervention_schedule<F>(
+        &self,
+        schedule: &InterventionSchedule,
+        initial_data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+        mut transition: F,
+    ) -> Result<Vec<ReasoningReport>, CausalityGraphError>
+    where
+        F: FnMut(usize, &mut [NumericalValue], &ReasoningReport),
+    {
+        if !self.contains_root_causaloid() {
+            return Err(CausalityGraphError(
+                "Graph does not contains root causaloid".into(),
+            ));
+        }
+
+        let root_index = self.get_root_index().expect("Root causaloid not found.");
+
+        let mut data = initial_data.to_vec();
+        let mut reports = Vec::with_capacity(schedule.number_steps());
+
+        for step in 0..schedule.number_steps() {
+            for intervention in schedule.interventions_at(step) {
+                let index = *intervention.index();
+                let cause = self.get_causaloid(index).ok_or_else(|| {
+                    CausalityGraphError(format!(
+                        "Intervention at step {} targets unknown causaloid at index {}",
+                        step, index
+                    ))
+                })?;
+
+                let position =
+                    graph_reasoning_utils::get_data_position(cause.id(), data.len(), &data_index)
+                        .ok_or_else(|| {
+                        CausalityGraphError(format!(
+                            "Intervention at step {} targets causaloid at index {} without data",
+                            step, index
+                        ))
+                    })?;
+
+                data[position] = *intervention.value();
+            }
+
+            let report = self.reason_subgraph_from_cause_with_policy(
+                root_index,
+                &data,
+                data_index,
+                ReasoningPolicy::FailFast,
+            )?;
+
+            if step + 1 < schedule.number_steps() {
+                transition(step, &mut data, &report);
+            }
+
+            reports.push(report);
+        }
+
+        Ok(reports)
+    }
+
+    /// Reasons over the graph for each intervention value of the axis,
+    /// i.e. to plot a dose-response curve.
+    ///
+    /// axis: &SweepAxis - causaloid and values to intervene on
+    /// data: &[NumericalValue] - data of all other causaloids
+    /// Optional: data_index - provide when the data have a different index sorting than
+    /// the causaloids.
+    ///
+    /// Returns Result either the sweep with one outcome per value or a CausalityGraphError
+    /// if the graph has no root, the axis has no steps, targets a causaloid that does not exist
+    /// or has no data, or reasoning fails.
+    fn sweep_intervention(
+        &self,
+        axis: &SweepAxis,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<InterventionSweep, CausalityGraphError> {
+        sweep(self, &[*axis], data, data_index)
+    }
+
+    /// Reasons over the graph for each pair of intervention values of both axes,
+    /// i.e. to plot a sensitivity surface.
+    ///
+    /// The outcomes are stored in row-major order,
+    /// hence outcome(&[i, j]) is the outcome for the i-th value of x and the j-th value of y.
+    ///
+    /// Returns Result either the sweep or a CausalityGraphError as in sweep_intervention.
+    fn sweep_intervention_pair(
+        &self,
+        x: &SweepAxis,
+        y: &SweepAxis,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<InterventionSweep, CausalityGraphError> {
+        sweep(self, &[*x, *y], data, data_index)
+    }
+}
+
+// Reasons over the graph for every point of the grid spanned by the axes in row-major order.
+fn sweep<G, T>(
+    graph: &G,
+    axes: &[SweepAxis],
+    data: &[NumericalValue],
+    data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+) -> Result<InterventionSweep, CausalityGraphError>
+where
+    G: CausableGraphReasoning<T> + ?Sized,
+    T: Causable + PartialEq,
+{
+    if !graph.contains_root_causaloid() {
+        return Err(CausalityGraphError(
+            "Graph does not contains root causaloid".into(),
+        ));
+    }
+
+    let mut positions = Vec::with_capacity(axes.len());
+    let mut values = Vec::with_capacity(axes.len());
+
+    for axis in axes {
+        if *axis.steps() == 0 {
+            return Err(CausalityGraphError(format!(
+                "Sweep axis for causaloid at index {} has no steps",
+                axis.index()
+            )));
+        }
+
+        let cause = graph.get_causaloid(*axis.index()).ok_or_else(|| {
+            CausalityGraphError(format!(
+                "Sweep targets unknown causaloid at index {}",
+                axis.index()
+            ))
+        })?;
+
+        let position =
+            graph_reasoning_utils::get_data_position(cause.id(), data.len(), &data_index)
+                .ok_or_else(|| {
+                    CausalityGraphError(format!(
+                        "Sweep targets causaloid at index {} without data",
+                        axis.index()
+                    ))
+                })?;
+
+        positions.push(position);
+        values.push(axis.values());
+    }
+
+    let total: usize = values.iter().map(Vec::len).product();
+    let mut outcomes = Vec::with_capacity(total);
+    let mut data = data.to_vec();
+
+    for offset in 0..total {
+        // Decompose the offset into one step per axis, last axis fastest.
+        let mut rest = offset;
+        for (position, axis_values) in positions.iter().zip(&values).rev() {
+            data[*position] = axis_values[rest % axis_values.len()];
+            rest /= axis_values.len();
+        }
+
+        outcomes.push(graph.reason_all_causes(&data, data_index)?);
+    }
+
+    Ok(InterventionSweep::new(axes.to_vec(), outcomes))
+}
@@ -8,9 +8,13 @@ use crate::errors::{CausalGraphIndexError, CausalityGraphError};
 use crate::prelude::{Causable, IdentificationValue, NumericalValue};
 
 pub mod graph;
+pub mod graph_attribution;
+pub mod graph_diagnosing;
 pub mod graph_explaining;
+pub mod graph_incremental;
 pub mod graph_reasoning;
-mod graph_reasoning_utils;
+pub(crate) mod graph_reasoning_utils;
+pub mod graph_simulating;
 
 // Type alias is shared between trait and implementation
 pub(crate) type CausalGraph<T> = UltraGraph<T>;
@@ -46,6 +46,11 @@ where
     fn contains_node(&self, index: usize) -> bool;
     fn get_node(&self, index: usize) -> Option<&Contextoid<D, S, T, ST, V>>;
     fn remove_node(&mut self, index: usize) -> Result<(), ContextIndexError>;
+    fn update_node(
+        &mut self,
+        index: usize,
+        value: Contextoid<D, S, T, ST, V>,
+    ) -> Result<(), ContextIndexError>;
     fn add_edge(
         &mut self,
         a: usize,
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+/// Trait for types that have a stable content fingerprint.
+///
+/// Provides:
+/// - fingerprint(): Returns a content hash that only changes when the content changes.
+///
+/// Unlike std::hash::Hash with the DefaultHasher, the fingerprint is stable across runs,
+/// platforms, and releases so it can be stored in audit logs to prove which
+/// version of a model or context produced a given decision.
+/// To that end, implementations write each field with a fixed-width little endian
+/// encoding into a FingerprintHasher instead of relying on std Hash impls.
+///
+pub trait Fingerprintable {
+    fn fingerprint(&self) -> u64;
+}
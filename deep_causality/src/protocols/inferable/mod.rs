@@ -4,7 +4,7 @@
 use std::cmp::Ordering;
 use std::fmt::Debug;
 
-use crate::prelude::{DescriptionValue, Identifiable, NumericalValue};
+use crate::prelude::{BetaDistribution, DescriptionValue, Identifiable, NumericalValue};
 use crate::utils::math_utils::abs_num;
 
 /// Trait for inferable types with causal reasoning properties.
@@ -323,3 +323,35 @@ where
         (self.number_non_inferable() / self.len() as NumericalValue) * (100 as NumericalValue)
     }
 }
+
+/// Trait providing Bayesian reasoning methods for collections of Inferable items.
+///
+/// Instead of a point percentage, the methods return the posterior beta distribution
+/// of the probability that an item is inferable, given a prior and all items
+/// of the collection as observations. The posterior carries both the estimate
+/// and its uncertainty, which shrinks as the collection grows.
+///
+/// Provides default implementations using InferableReasoning.
+///
+pub trait BayesianInferable<T>: InferableReasoning<T>
+where
+    T: Inferable,
+{
+    /// Returns the posterior distribution of the probability that an item is inferable.
+    ///
+    /// Each inferable item counts as success and every other item as failure.
+    ///
+    fn posterior_inferable(&self, prior: BetaDistribution) -> BetaDistribution {
+        let holds = self.number_inferable() as usize;
+        prior.posterior(holds, self.len() - holds)
+    }
+
+    /// Returns the posterior distribution of the probability that an item is inverse inferable.
+    ///
+    /// Each inverse inferable item counts as success and every other item as failure.
+    ///
+    fn posterior_inverse_inferable(&self, prior: BetaDistribution) -> BetaDistribution {
+        let holds = self.number_inverse_inferable() as usize;
+        prior.posterior(holds, self.len() - holds)
+    }
+}
@@ -3,10 +3,12 @@
 
 pub mod adjustable;
 pub mod assumable;
+pub mod async_causable;
 pub mod causable;
 pub mod causable_graph;
 pub mod contextuable;
 pub mod contextuable_graph;
+pub mod fingerprintable;
 pub mod identifiable;
 pub mod indexable;
 
@@ -3,7 +3,7 @@
 
 use std::fmt::Debug;
 
-use crate::prelude::{Identifiable, NumericalValue};
+use crate::prelude::{Identifiable, NumericalValue, ObservationStatistics};
 
 /// Observable trait for objects that can be observed.
 ///
@@ -59,6 +59,7 @@ pub trait Observable: Debug + Identifiable {
 /// - number_non_observation() - counts items not meeting criteria
 /// - percent_observation() - % of items meeting criteria
 /// - percent_non_observation() - % of items not meeting criteria
+/// - observation_statistics() - incremental statistics seeded with all items
 ///
 /// Uses T's effect_observed() method to check criteria.
 ///
@@ -137,4 +138,19 @@ where
     ) -> NumericalValue {
         1.0 - self.percent_observation(target_threshold, target_effect)
     }
+
+    /// Aggregates all items into ObservationStatistics for the criteria.
+    ///
+    /// The returned statistics can be updated with new observations
+    /// so that subsequent queries do not need to re-scan the collection.
+    ///
+    fn observation_statistics(
+        &self,
+        target_threshold: NumericalValue,
+        target_effect: NumericalValue,
+    ) -> ObservationStatistics {
+        let mut stats = ObservationStatistics::new(target_threshold, target_effect);
+        stats.update_all(self.get_all_items());
+        stats
+    }
 }
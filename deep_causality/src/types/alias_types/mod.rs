@@ -2,9 +2,12 @@
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
 use crate::prelude::{
-    CausalityError, Causaloid, CausaloidGraph, Context, Contextoid, Data, Space, SpaceTime, Time,
+    CausalState, CausalityError, Causaloid, CausaloidGraph, Context, ContextSet, Contextoid, Data,
+    DynCausaloid, Model, Space, SpaceTime, Time, CSM,
 };
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::{Arc, RwLock};
 
 // Type aliases
@@ -22,12 +25,20 @@ pub type EvalFn = fn(&[NumericalValue]) -> bool;
 // Fn aliases for causal function with and without context
 pub type CausalFn = fn(NumericalValue) -> Result<bool, CausalityError>;
 
+// Future and fn aliases for async causal function
+pub type CausalFuture = Pin<Box<dyn Future<Output = Result<bool, CausalityError>> + Send>>;
+
+pub type AsyncCausalFn = fn(NumericalValue) -> CausalFuture;
+
 pub type ContextualCausalDataFn<'l, D, S, T, ST, V> =
     fn(NumericalValue, &'l Context<D, S, T, ST, V>) -> Result<bool, CausalityError>;
 
 pub type ContextualCausalFn<'l, D, S, T, ST, V> =
     fn(&'l Context<D, S, T, ST, V>) -> Result<bool, CausalityError>;
 
+pub type ContextSetCausalDataFn<'l, D, S, T, ST, V> =
+    fn(NumericalValue, &ContextSet<'l, D, S, T, ST, V>) -> Result<bool, CausalityError>;
+
 // Default type aliases for basic causaloids
 
 pub type BaseNumberType = u64;
@@ -75,6 +86,19 @@ pub type BaseCausalGraph<'l> = CausaloidGraph<
     >,
 >;
 
+// Simple type aliases for context-free numeric models.
+// A causaloid without context borrows nothing, hence the static lifetime.
+pub type SimpleCausaloid = BaseCausaloid<'static>;
+
+pub type SimpleCausaloidVec = BaseCausaloidVec<'static>;
+
+pub type SimpleCausalMap = BaseCausalMap<'static>;
+
+pub type SimpleCausalGraph = BaseCausalGraph<'static>;
+
+// Type alias for heterogeneous causal graphs of type-erased causaloids
+pub type DynCausalGraph<'l> = CausaloidGraph<DynCausaloid<'l>>;
+
 // Default type alias for basic context. It's used in tests
 pub type BaseContext = Context<
     Data<BaseNumberType>,
@@ -84,6 +108,15 @@ pub type BaseContext = Context<
     BaseNumberType,
 >;
 
+pub type BaseContextSet<'l> = ContextSet<
+    'l,
+    Data<BaseNumberType>,
+    Space<BaseNumberType>,
+    Time<BaseNumberType>,
+    SpaceTime<BaseNumberType>,
+    BaseNumberType,
+>;
+
 pub type BaseContextoid = Contextoid<
     Data<BaseNumberType>,
     Space<BaseNumberType>,
@@ -91,3 +124,31 @@ pub type BaseContextoid = Contextoid<
     SpaceTime<BaseNumberType>,
     BaseNumberType,
 >;
+
+pub type BaseModel<'l> = Model<
+    'l,
+    Data<BaseNumberType>,
+    Space<BaseNumberType>,
+    Time<BaseNumberType>,
+    SpaceTime<BaseNumberType>,
+    BaseNumberType,
+>;
+
+// Default type aliases for the causal state machine
+pub type BaseCausalState<'l> = CausalState<
+    'l,
+    Data<BaseNumberType>,
+    Space<BaseNumberType>,
+    Time<BaseNumberType>,
+    SpaceTime<BaseNumberType>,
+    BaseNumberType,
+>;
+
+pub type BaseCSM<'l> = CSM<
+    'l,
+    Data<BaseNumberType>,
+    Space<BaseNumberType>,
+    Time<BaseNumberType>,
+    SpaceTime<BaseNumberType>,
+    BaseNumberType,
+>;
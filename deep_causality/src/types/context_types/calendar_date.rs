@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+
+use crate::errors::BuildError;
+use crate::prelude::TimeScale;
+
+/// A date of the proleptic Gregorian calendar with calendar-aware arithmetic.
+///
+/// CalendarDate complements TimeScale for scales whose length depends on the calendar,
+/// i.e. adding a month to the 31st of January yields the last day of February.
+/// Business days are Monday to Friday; holidays are not considered.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct CalendarDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl CalendarDate {
+    /// Creates a new date. Returns a BuildError if month or day is out of range.
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Self, BuildError> {
+        if !(1..=12).contains(&month) {
+            return Err(BuildError(format!(
+                "Month {} is out of range 1..=12",
+                month
+            )));
+        }
+
+        let max_day = days_in_month(year, month);
+        if day < 1 || day > max_day {
+            return Err(BuildError(format!(
+                "Day {} is out of range 1..={} for {}-{:02}",
+                day, max_day, year, month
+            )));
+        }
+
+        Ok(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the ISO weekday, from 1 for Monday to 7 for Sunday.
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 was a Thursday.
+        (self.days_since_epoch() + 3).rem_euclid(7) as u32 + 1
+    }
+
+    pub fn is_weekend(&self) -> bool {
+        self.weekday() > 5
+    }
+
+    pub fn is_business_day(&self) -> bool {
+        !self.is_weekend()
+    }
+
+    /// Returns the number of days from this date to the other date.
+    /// The result is negative if the other date lies before this date.
+    pub fn days_until(&self, other: &CalendarDate) -> i64 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+
+    /// Adds the number of days. Negative values step backward.
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Adds the number of months. Negative values step backward.
+    /// The day is clamped to the last day of the resulting month.
+    pub fn add_months(&self, months: i64) -> Self {
+        let total = self.year as i64 * 12 + (self.month as i64 - 1) + months;
+        let year = total.div_euclid(12) as i32;
+        let month = total.rem_euclid(12) as u32 + 1;
+        let day = self.day.min(days_in_month(year, month));
+
+        Self { year, month, day }
+    }
+
+    /// Adds the number of quarters, i.e. three months each.
+    pub fn add_quarters(&self, quarters: i64) -> Self {
+        self.add_months(quarters * 3)
+    }
+
+    /// Adds the number of years. The 29th of February becomes the 28th in non-leap years.
+    pub fn add_years(&self, years: i64) -> Self {
+        self.add_months(years * 12)
+    }
+
+    /// Steps the number of business days forward or, for negative values, backward.
+    ///
+    /// Stepping zero business days from a weekend date rolls forward to the next Monday.
+    pub fn add_business_days(&self, business_days: i64) -> Self {
+        let step = if business_days < 0 { -1 } else { 1 };
+
+        let mut date = *self;
+        while date.is_weekend() {
+            date = date.add_days(step);
+        }
+
+        let mut remaining = business_days.abs();
+        while remaining > 0 {
+            date = date.add_days(step);
+            if date.is_business_day() {
+                remaining -= 1;
+            }
+        }
+
+        date
+    }
+
+    /// Adds the amount in the given time scale.
+    ///
+    /// Returns None for NoScale and for scales shorter than a day
+    /// because a CalendarDate has a resolution of one day.
+    pub fn add(&self, amount: i64, time_scale: TimeScale) -> Option<Self> {
+        match time_scale {
+            TimeScale::Day => Some(self.add_days(amount)),
+            TimeScale::Week => Some(self.add_days(amount * 7)),
+            TimeScale::Month => Some(self.add_months(amount)),
+            TimeScale::Quarter => Some(self.add_quarters(amount)),
+            TimeScale::Year => Some(self.add_years(amount)),
+            _ => None,
+        }
+    }
+
+    /// Returns the quarter of the year, from 1 to 4.
+    pub fn quarter(&self) -> u32 {
+        (self.month - 1) / 3 + 1
+    }
+}
+
+impl CalendarDate {
+    // Days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    fn days_since_epoch(&self) -> i64 {
+        let y = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let m = self.month as i64;
+        let d = self.day as i64;
+
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+        era * 146_097 + doe - 719_468
+    }
+
+    fn from_days_since_epoch(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+
+        Self { year, month, day }
+    }
+}
+
+impl Display for CalendarDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns true if the year is a leap year of the Gregorian calendar.
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days of the month, or zero if the month is out of range.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::*;
+
+use deep_causality_macros::Getters;
+
+use crate::prelude::{Contextoid, Datable, RelationKind, SpaceTemporal, Spatial, Temporable};
+
+/// Difference between two contexts, i.e. a counterfactual context and its baseline.
+///
+/// Contextoids and edges are identified by contextoid id rather than node index
+/// so that a delta can be merged into any context with the same ids.
+/// Create with Context::diff and apply with Context::merge.
+#[derive(Getters, Debug, Clone, PartialEq)]
+pub struct ContextDelta<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Contextoids with an id not in the baseline.
+    added_nodes: Vec<Contextoid<D, S, T, ST, V>>,
+    /// Contextoids with an id in the baseline but a different value.
+    updated_nodes: Vec<Contextoid<D, S, T, ST, V>>,
+    /// Ids of contextoids removed from the baseline.
+    removed_nodes: Vec<u64>,
+    /// Edges as (source id, target id, relation) added or with a changed relation.
+    added_edges: Vec<(u64, u64, RelationKind)>,
+    /// Edges as (source id, target id) removed from the baseline.
+    removed_edges: Vec<(u64, u64)>,
+}
+
+impl<D, S, T, ST, V> ContextDelta<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub(crate) fn new(
+        added_nodes: Vec<Contextoid<D, S, T, ST, V>>,
+        updated_nodes: Vec<Contextoid<D, S, T, ST, V>>,
+        removed_nodes: Vec<u64>,
+        added_edges: Vec<(u64, u64, RelationKind)>,
+        removed_edges: Vec<(u64, u64)>,
+    ) -> Self {
+        Self {
+            added_nodes,
+            updated_nodes,
+            removed_nodes,
+            added_edges,
+            removed_edges,
+        }
+    }
+
+    /// Returns the total number of node and edge changes.
+    pub fn len(&self) -> usize {
+        self.added_nodes.len()
+            + self.updated_nodes.len()
+            + self.removed_nodes.len()
+            + self.added_edges.len()
+            + self.removed_edges.len()
+    }
+
+    /// Returns true if both contexts were equal.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
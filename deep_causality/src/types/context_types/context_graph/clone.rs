@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::*;
+
+use crate::prelude::{Context, Datable, SpaceTemporal, Spatial, Temporable};
+
+impl<D, S, T, ST, V> Clone for Context<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Clones the context without its subscriptions
+    /// so that changes of the clone are not published to the subscribers of the original.
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            name: self.name.clone(),
+            base_context: self.base_context.clone(),
+            extra_contexts: self.extra_contexts.clone(),
+            number_of_extra_contexts: self.number_of_extra_contexts,
+            extra_context_id: self.extra_context_id,
+            current_index_map: self.current_index_map.clone(),
+            previous_index_map: self.previous_index_map.clone(),
+            id_index_map: self.id_index_map.clone(),
+            subscribers: Vec::new(),
+        }
+    }
+}
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    // Clones the context together with its subscriptions,
+    // i.e. to replace the original by its clone.
+    pub(crate) fn clone_with_subscribers(&self) -> Self {
+        let mut clone = self.clone();
+        clone.subscribers = self.subscribers.clone();
+        clone
+    }
+}
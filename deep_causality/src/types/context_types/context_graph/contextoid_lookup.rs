@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use super::*;
+
+use crate::errors::ContextIndexError;
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Returns the node index of the contextoid with the given id in the base context.
+    pub fn get_node_index_by_id(&self, id: u64) -> Option<usize> {
+        self.id_index_map.get(&id).copied()
+    }
+
+    /// Returns the contextoid with the given id in the base context.
+    pub fn get_node_by_id(&self, id: u64) -> Option<&Contextoid<D, S, T, ST, V>> {
+        self.base_context.get_node(self.get_node_index_by_id(id)?)
+    }
+
+    /// Returns the datoid of the contextoid with the given id,
+    /// or None if there is no such contextoid or it is not a datoid.
+    pub fn get_data_by_id(&self, id: u64) -> Option<&D> {
+        self.get_node_by_id(id)?.vertex_type().dataoid()
+    }
+
+    /// Returns the tempoid of the contextoid with the given id,
+    /// or None if there is no such contextoid or it is not a tempoid.
+    pub fn get_time_by_id(&self, id: u64) -> Option<&T> {
+        self.get_node_by_id(id)?.vertex_type().tempoid()
+    }
+
+    /// Returns the spaceoid of the contextoid with the given id,
+    /// or None if there is no such contextoid or it is not a spaceoid.
+    pub fn get_space_by_id(&self, id: u64) -> Option<&S> {
+        self.get_node_by_id(id)?.vertex_type().spaceiod()
+    }
+
+    /// Returns the space-tempoid of the contextoid with the given id,
+    /// or None if there is no such contextoid or it is not a space-tempoid.
+    pub fn get_space_time_by_id(&self, id: u64) -> Option<&ST> {
+        self.get_node_by_id(id)?.vertex_type().space_tempoid()
+    }
+
+    /// Replaces the datoid of the contextoid with the given id.
+    /// The node index and all edges of the contextoid remain unchanged.
+    ///
+    /// Returns ContextIndexError if there is no such contextoid or it is not a datoid.
+    pub fn update_data_by_id(&mut self, id: u64, data: D) -> Result<(), ContextIndexError> {
+        if self.get_data_by_id(id).is_none() {
+            return Err(ContextIndexError(format!(
+                "No datoid with contextoid id {} found",
+                id
+            )));
+        }
+
+        let index = self.id_index_map[&id];
+        self.update_node(index, Contextoid::new(id, ContextoidType::Datoid(data)))
+    }
+}
@@ -22,8 +22,13 @@ where
     /// Ads a new Contextoid to the context.
     /// You can add the same contextoid multiple times,
     /// but each one will return a new and unique node index.
+    /// Lookups by id return the contextoid added last with that id.
     fn add_node(&mut self, value: Contextoid<D, S, T, ST, V>) -> usize {
-        self.base_context.add_node(value)
+        let id = value.id();
+        let index = self.base_context.add_node(value);
+        self.id_index_map.insert(id, index);
+        self.publish(id);
+        index
     }
 
     /// Returns only true if the context contains the contextoid with the given index.
@@ -44,10 +49,53 @@ where
             return Err(ContextIndexError(format!("index {} not found", index)));
         };
 
+        let id = self
+            .base_context
+            .get_node(index)
+            .expect("Failed to get contextoid")
+            .id();
+
         if self.base_context.remove_node(index).is_err() {
             return Err(ContextIndexError(format!("index {} not found", index)));
         };
 
+        if self.id_index_map.get(&id) == Some(&index) {
+            self.id_index_map.remove(&id);
+        }
+
+        self.publish(id);
+
+        Ok(())
+    }
+
+    /// Replaces the contextoid with the given index.
+    /// The node index and all edges remain unchanged.
+    /// Returns ContextIndexError if the index is not found
+    fn update_node(
+        &mut self,
+        index: usize,
+        value: Contextoid<D, S, T, ST, V>,
+    ) -> Result<(), ContextIndexError> {
+        let previous_id = match self.base_context.get_node(index) {
+            Some(contextoid) => contextoid.id(),
+            None => return Err(ContextIndexError(format!("index {} not found", index))),
+        };
+
+        let id = value.id();
+        if self.base_context.update_node(index, value).is_err() {
+            return Err(ContextIndexError(format!("index {} not found", index)));
+        };
+
+        if previous_id != id {
+            if self.id_index_map.get(&previous_id) == Some(&index) {
+                self.id_index_map.remove(&previous_id);
+            }
+            self.id_index_map.insert(id, index);
+            self.publish(previous_id);
+        }
+
+        self.publish(id);
+
         Ok(())
     }
 
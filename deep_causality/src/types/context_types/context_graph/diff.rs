@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::ops::*;
+
+use ultragraph::prelude::*;
+
+use crate::errors::ContextIndexError;
+use crate::prelude::{
+    Context, ContextDelta, ContextuableGraph, Datable, Identifiable, RelationKind, SpaceTemporal,
+    Spatial, Temporable,
+};
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable + Clone + PartialEq,
+    S: Spatial<V> + Clone + PartialEq,
+    T: Temporable<V> + Clone + PartialEq,
+    ST: SpaceTemporal<V> + Clone + PartialEq,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Returns the changes that turn this context into the other context.
+    ///
+    /// Contextoids are matched by id, hence ids must be unique within each context.
+    /// Only the base contexts are compared; extra contexts are ignored.
+    pub fn diff(&self, other: &Self) -> ContextDelta<D, S, T, ST, V> {
+        let ids = self.node_ids();
+        let other_ids = other.node_ids();
+
+        let mut added_nodes = Vec::new();
+        let mut updated_nodes = Vec::new();
+        for (id, other_index) in &other_ids {
+            let other_node = other
+                .base_context
+                .get_node(*other_index)
+                .expect("Failed to get contextoid");
+
+            match ids.get(id) {
+                None => added_nodes.push(other_node.clone()),
+                Some(index) => {
+                    let node = self
+                        .base_context
+                        .get_node(*index)
+                        .expect("Failed to get contextoid");
+                    if node != other_node {
+                        updated_nodes.push(other_node.clone());
+                    }
+                }
+            }
+        }
+
+        let removed_nodes = ids
+            .keys()
+            .filter(|id| !other_ids.contains_key(id))
+            .copied()
+            .collect();
+
+        let edges = self.edge_ids();
+        let other_edges = other.edge_ids();
+
+        let added_edges = other_edges
+            .iter()
+            .filter(|(edge, kind)| edges.get(edge) != Some(kind))
+            .map(|((a, b), kind)| (*a, *b, *kind))
+            .collect();
+
+        let removed_edges = edges
+            .keys()
+            .filter(|edge| !other_edges.contains_key(edge))
+            .copied()
+            .collect();
+
+        ContextDelta::new(
+            added_nodes,
+            updated_nodes,
+            removed_nodes,
+            added_edges,
+            removed_edges,
+        )
+    }
+
+    /// Applies the delta to this context.
+    ///
+    /// Removes edges and contextoids first, then updates and adds contextoids,
+    /// and finally adds edges. An added edge replaces an existing edge between
+    /// the same contextoids.
+    ///
+    /// Returns ContextIndexError if the delta refers to a contextoid id that does not
+    /// exist or adds an id that already exists. The merge is not atomic;
+    /// the context may be partially updated in case of an error.
+    pub fn merge(&mut self, delta: &ContextDelta<D, S, T, ST, V>) -> Result<(), ContextIndexError> {
+        let mut ids = self.node_ids();
+
+        let get_index = |ids: &BTreeMap<u64, usize>, id: &u64| {
+            ids.get(id)
+                .copied()
+                .ok_or_else(|| ContextIndexError(format!("Contextoid id {} not found", id)))
+        };
+
+        for (a, b) in delta.removed_edges() {
+            let (a, b) = (get_index(&ids, a)?, get_index(&ids, b)?);
+            self.remove_edge(a, b)?;
+        }
+
+        for id in delta.removed_nodes() {
+            let index = get_index(&ids, id)?;
+            self.remove_node(index)?;
+            ids.remove(id);
+        }
+
+        for contextoid in delta.updated_nodes() {
+            let index = get_index(&ids, &contextoid.id())?;
+            self.update_node(index, contextoid.clone())?;
+        }
+
+        for contextoid in delta.added_nodes() {
+            let id = contextoid.id();
+            if ids.contains_key(&id) {
+                return Err(ContextIndexError(format!(
+                    "Contextoid id {} already exists",
+                    id
+                )));
+            }
+            let index = self.add_node(contextoid.clone());
+            ids.insert(id, index);
+        }
+
+        for (a, b, kind) in delta.added_edges() {
+            let (a, b) = (get_index(&ids, a)?, get_index(&ids, b)?);
+            if self.contains_edge(a, b) {
+                self.remove_edge(a, b)?;
+            }
+            self.add_edge(a, b, *kind)?;
+        }
+
+        Ok(())
+    }
+
+    // Maps each contextoid id to its node index, sorted by id.
+    fn node_ids(&self) -> BTreeMap<u64, usize> {
+        self.node_indices()
+            .into_iter()
+            .map(|index| {
+                let contextoid = self
+                    .base_context
+                    .get_node(index)
+                    .expect("Failed to get contextoid");
+                (contextoid.id(), index)
+            })
+            .collect()
+    }
+
+    // Maps each edge as pair of contextoid ids to its relation, sorted by ids.
+    fn edge_ids(&self) -> BTreeMap<(u64, u64), RelationKind> {
+        let ids: HashMap<usize, u64> = self
+            .node_ids()
+            .into_iter()
+            .map(|(id, index)| (index, id))
+            .collect();
+
+        self.base_context
+            .get_all_edges()
+            .into_iter()
+            .filter_map(|(a, b)| {
+                let kind = self
+                    .base_context
+                    .get_edge_weight(a, b)
+                    .and_then(RelationKind::from_weight)?;
+                Some(((ids[&a], ids[&b]), kind))
+            })
+            .collect()
+    }
+}
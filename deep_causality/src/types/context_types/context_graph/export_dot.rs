@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Write};
+use std::hash::Hash;
+use std::ops::*;
+
+use ultragraph::prelude::*;
+
+use crate::prelude::{
+    escape_dot_label, Context, Datable, RelationKind, SpaceTemporal, Spatial, Temporable,
+};
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable + Display,
+    S: Spatial<V> + Display,
+    T: Temporable<V> + Display,
+    ST: SpaceTemporal<V> + Display,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>
+        + Display,
+{
+    /// Exports the base context as Graphviz DOT digraph.
+    ///
+    /// Each node is named by its index and labelled with its contextoid.
+    /// Each edge is labelled with its RelationKind. Extra contexts are not exported.
+    /// Render i.e. with `dot -Tsvg context.dot -o context.svg`.
+    pub fn export_dot(&self) -> String {
+        let mut dot = format!("digraph \"{}\" {{\n", escape_dot_label(&self.name));
+
+        for index in self.node_indices() {
+            let contextoid = self
+                .base_context
+                .get_node(index)
+                .expect("Failed to get contextoid");
+            writeln!(
+                dot,
+                "    n{} [label=\"{}\"];",
+                index,
+                escape_dot_label(&contextoid.to_string())
+            )
+            .expect("Failed to write DOT node");
+        }
+
+        let mut edges = self.base_context.get_all_edges();
+        edges.sort_unstable();
+        for (a, b) in edges {
+            match self
+                .base_context
+                .get_edge_weight(a, b)
+                .and_then(RelationKind::from_weight)
+            {
+                Some(kind) => writeln!(dot, "    n{} -> n{} [label=\"{}\"];", a, b, kind),
+                None => writeln!(dot, "    n{} -> n{};", a, b),
+            }
+            .expect("Failed to write DOT edge");
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
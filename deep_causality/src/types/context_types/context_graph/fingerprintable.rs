@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::{Hash, Hasher};
+use std::ops::*;
+
+use crate::prelude::{
+    Context, Contextoid, ContextoidType, Contextuable, Datable, FingerprintHasher, Fingerprintable,
+    Identifiable, SpaceTemporal, Spatial, Temporable,
+};
+use crate::utils::fingerprint_utils;
+
+impl<D, S, T, ST, V> Fingerprintable for Context<D, S, T, ST, V>
+where
+    D: Datable + Hash,
+    S: Spatial<V> + Hash,
+    T: Temporable<V> + Hash,
+    ST: SpaceTemporal<V> + Hash,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Returns the fingerprint of a snapshot of the context.
+    ///
+    /// The fingerprint covers the id, the name, all contextoids and edges of the
+    /// base context, and the number, ids, contextoids, and edges of all extra contexts.
+    /// The currently selected extra context is not part of the fingerprint.
+    ///
+    /// Data, spatial, and temporal payloads of contextoids are written by their own
+    /// Hash impl, hence the fingerprint is only stable across releases as far as those impls are.
+    fn fingerprint(&self) -> u64 {
+        let mut state = FingerprintHasher::default();
+
+        state.write_u64(self.id);
+        state.write_text(&self.name);
+
+        fingerprint_utils::hash_graph(&self.base_context, &mut state, hash_contextoid);
+
+        if let Some(extra_contexts) = &self.extra_contexts {
+            let mut ids: Vec<&u64> = extra_contexts.keys().collect();
+            ids.sort_unstable();
+
+            state.write_len(ids.len());
+            for id in ids {
+                state.write_u64(*id);
+                let extra_context = extra_contexts.get(id).expect("Failed to get extra context");
+                fingerprint_utils::hash_graph(extra_context, &mut state, hash_contextoid);
+            }
+        }
+
+        state.finish()
+    }
+}
+
+fn hash_contextoid<D, S, T, ST, V>(
+    contextoid: &Contextoid<D, S, T, ST, V>,
+    state: &mut FingerprintHasher,
+) where
+    D: Datable + Hash,
+    S: Spatial<V> + Hash,
+    T: Temporable<V> + Hash,
+    ST: SpaceTemporal<V> + Hash,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    state.write_u64(contextoid.id());
+
+    match contextoid.vertex_type() {
+        ContextoidType::Datoid(data) => {
+            state.write_u8(0);
+            data.hash(state)
+        }
+        ContextoidType::Tempoid(time) => {
+            state.write_u8(1);
+            time.hash(state)
+        }
+        ContextoidType::Root(root) => {
+            state.write_u8(2);
+            state.write_u64(root.id())
+        }
+        ContextoidType::Spaceoid(space) => {
+            state.write_u8(3);
+            space.hash(state)
+        }
+        ContextoidType::SpaceTempoid(space_time) => {
+            state.write_u8(4);
+            space_time.hash(state)
+        }
+        ContextoidType::_Unreachable(_) => state.write_u8(5),
+    }
+}
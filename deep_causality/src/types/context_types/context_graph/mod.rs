@@ -4,16 +4,23 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 use std::ops::*;
+use std::sync::mpsc::Sender;
 
 use ultragraph::prelude::*;
 
 use crate::prelude::*;
 
+mod clone;
+mod contextoid_lookup;
 mod contextuable_graph;
 mod debug;
+mod diff;
+mod export_dot;
 mod extendable_contextuable_graph;
+mod fingerprintable;
 mod identifiable;
 mod indexable;
+mod subscription;
 
 type ExtraContext<D, S, T, ST, V> = UltraGraph<Contextoid<D, S, T, ST, V>>;
 
@@ -43,6 +50,10 @@ where
     extra_context_id: u64,
     current_index_map: HashMap<usize, usize>,
     previous_index_map: HashMap<usize, usize>,
+    // Maps contextoid id to node index in the base context.
+    id_index_map: HashMap<u64, usize>,
+    // Channels that receive the id of every added, updated, or removed contextoid.
+    subscribers: Vec<Sender<u64>>,
 }
 
 impl<D, S, T, ST, V> Context<D, S, T, ST, V>
@@ -72,6 +83,8 @@ where
             extra_context_id: 0,
             current_index_map: HashMap::new(),
             previous_index_map: HashMap::new(),
+            id_index_map: HashMap::new(),
+            subscribers: Vec::new(),
         }
     }
 
@@ -79,4 +92,20 @@ where
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
+
+    /// Returns the indices of all contextoids in the base context in ascending order.
+    pub(crate) fn node_indices(&self) -> Vec<usize> {
+        let number_nodes = self.base_context.number_nodes();
+        let mut indices = Vec::with_capacity(number_nodes);
+
+        let mut index = 0;
+        while indices.len() < number_nodes {
+            if self.base_context.contains_node(index) {
+                indices.push(index);
+            }
+            index += 1;
+        }
+
+        indices
+    }
 }
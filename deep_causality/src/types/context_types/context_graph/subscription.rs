@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::*;
+use std::sync::mpsc::{channel, Receiver};
+
+use crate::prelude::*;
+
+impl<D, S, T, ST, V> Context<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Subscribes to changes of the base context.
+    ///
+    /// The returned receiver gets the id of every contextoid that is added, updated,
+    /// or removed afterward, in order of the changes. A contextoid updated with a new id
+    /// publishes both the previous and the new id. Dropping the receiver ends the subscription.
+    /// Clones of the context start without subscribers, hence changes of a clone
+    /// are not published to the subscribers of the original.
+    pub fn subscribe(&mut self) -> Receiver<u64> {
+        let (sender, receiver) = channel();
+        self.subscribers.push(sender);
+        receiver
+    }
+
+    /// Returns the number of subscriptions, including those whose receiver
+    /// was dropped since the last change.
+    pub fn number_of_subscribers(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    // Sends the id of a changed contextoid to all subscribers
+    // and drops the subscriptions whose receiver was dropped.
+    pub(super) fn publish(&mut self, id: u64) {
+        if self.subscribers.is_empty() {
+            return;
+        }
+
+        self.subscribers.retain(|sender| sender.send(id).is_ok());
+    }
+}
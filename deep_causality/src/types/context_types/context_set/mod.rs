@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::ops::*;
+
+use crate::prelude::{Context, Datable, SpaceTemporal, Spatial, Temporable};
+
+/// Named contexts available to a causaloid, i.e. a static "configuration" context
+/// next to a fast-moving "telemetry" context.
+///
+/// The contexts are borrowed, so the same context can be shared by many sets.
+#[derive(Debug, Clone)]
+pub struct ContextSet<'l, D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    contexts: BTreeMap<String, &'l Context<D, S, T, ST, V>>,
+}
+
+impl<'l, D, S, T, ST, V> Default for ContextSet<'l, D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    fn default() -> Self {
+        Self {
+            contexts: BTreeMap::new(),
+        }
+    }
+}
+
+impl<'l, D, S, T, ST, V> ContextSet<'l, D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the context under the name and returns the set.
+    /// A context already stored under the name is replaced.
+    pub fn with_context(mut self, name: &str, context: &'l Context<D, S, T, ST, V>) -> Self {
+        self.insert(name, context);
+        self
+    }
+
+    /// Adds the context under the name and returns the context previously
+    /// stored under the name, if any.
+    pub fn insert(
+        &mut self,
+        name: &str,
+        context: &'l Context<D, S, T, ST, V>,
+    ) -> Option<&'l Context<D, S, T, ST, V>> {
+        self.contexts.insert(name.to_string(), context)
+    }
+
+    /// Returns the context stored under the name.
+    pub fn get(&self, name: &str) -> Option<&'l Context<D, S, T, ST, V>> {
+        self.contexts.get(name).copied()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.contexts.contains_key(name)
+    }
+
+    /// Returns the names of all contexts in ascending order.
+    pub fn names(&self) -> Vec<&str> {
+        self.contexts.keys().map(|name| name.as_str()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.contexts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contexts.is_empty()
+    }
+}
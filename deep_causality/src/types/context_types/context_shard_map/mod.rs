@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::*;
+
+use crate::prelude::{
+    Context, ContextualCausalDataFn, Datable, IdentificationValue, NumericalValue, SpaceTemporal,
+    Spatial, Temporable,
+};
+
+mod shard_evaluation;
+
+pub use shard_evaluation::ShardEvaluation;
+
+/// Per-entity contexts for multi-entity models, i.e. a fleet of drones or a cohort of patients.
+///
+/// All entities share a common static template context. The context of an entity is
+/// materialized as a copy of the template only when it is first modified via get_shard_mut.
+/// Until then, reads of the entity fall back to the template so that
+/// thousands of entities do not require thousands of copies.
+pub struct ContextShardMap<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    template: Context<D, S, T, ST, V>,
+    shards: HashMap<IdentificationValue, Context<D, S, T, ST, V>>,
+}
+
+impl<D, S, T, ST, V> ContextShardMap<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Creates a new shard map with the template shared by all entities.
+    pub fn new(template: Context<D, S, T, ST, V>) -> Self {
+        Self {
+            template,
+            shards: HashMap::new(),
+        }
+    }
+
+    pub fn template(&self) -> &Context<D, S, T, ST, V> {
+        &self.template
+    }
+
+    /// Returns the context of the entity, or the template if the context
+    /// of the entity has not been materialized.
+    pub fn get_shard(&self, entity_id: IdentificationValue) -> &Context<D, S, T, ST, V> {
+        self.shards.get(&entity_id).unwrap_or(&self.template)
+    }
+
+    /// Returns the mutable context of the entity and materializes it
+    /// from the template if it does not exist yet.
+    pub fn get_shard_mut(
+        &mut self,
+        entity_id: IdentificationValue,
+    ) -> &mut Context<D, S, T, ST, V> {
+        let template = &self.template;
+        self.shards
+            .entry(entity_id)
+            .or_insert_with(|| template.clone())
+    }
+
+    /// Returns true if the context of the entity has been materialized.
+    pub fn is_materialized(&self, entity_id: IdentificationValue) -> bool {
+        self.shards.contains_key(&entity_id)
+    }
+
+    /// Removes the materialized context of the entity so that
+    /// the entity falls back to the template again.
+    pub fn reset_shard(
+        &mut self,
+        entity_id: IdentificationValue,
+    ) -> Option<Context<D, S, T, ST, V>> {
+        self.shards.remove(&entity_id)
+    }
+
+    /// Returns the ids of all materialized entities in ascending order.
+    pub fn materialized_ids(&self) -> Vec<IdentificationValue> {
+        let mut ids: Vec<IdentificationValue> = self.shards.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    pub fn number_materialized(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Evaluates the contextual causal function for each entity with its observation
+    /// against the context of the entity, or the template if not materialized.
+    ///
+    /// Failures of single entities do not stop the evaluation; they are collected
+    /// in the returned ShardEvaluation next to the effects of all other entities.
+    pub fn evaluate_all<'l>(
+        &'l self,
+        observations: &[(IdentificationValue, NumericalValue)],
+        causal_fn: ContextualCausalDataFn<'l, D, S, T, ST, V>,
+    ) -> ShardEvaluation {
+        let mut evaluation = ShardEvaluation::default();
+
+        for (entity_id, obs) in observations {
+            match causal_fn(*obs, self.get_shard(*entity_id)) {
+                Ok(effect) => evaluation.add_effect(*entity_id, effect),
+                Err(error) => evaluation.add_failure(*entity_id, error),
+            }
+        }
+
+        evaluation
+    }
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use crate::errors::CausalityError;
+use crate::prelude::{IdentificationValue, NumericalValue};
+
+/// Aggregated result of evaluating a causal function across the shards of a ContextShardMap.
+#[derive(Debug, Default)]
+pub struct ShardEvaluation {
+    effects: BTreeMap<IdentificationValue, bool>,
+    failures: BTreeMap<IdentificationValue, CausalityError>,
+}
+
+impl ShardEvaluation {
+    pub(super) fn add_effect(&mut self, entity_id: IdentificationValue, effect: bool) {
+        self.failures.remove(&entity_id);
+        self.effects.insert(entity_id, effect);
+    }
+
+    pub(super) fn add_failure(&mut self, entity_id: IdentificationValue, error: CausalityError) {
+        self.effects.remove(&entity_id);
+        self.failures.insert(entity_id, error);
+    }
+}
+
+impl ShardEvaluation {
+    /// Returns the effects of all successfully evaluated entities keyed by entity id.
+    pub fn effects(&self) -> &BTreeMap<IdentificationValue, bool> {
+        &self.effects
+    }
+
+    /// Returns the errors of all failed entities keyed by entity id.
+    pub fn failures(&self) -> &BTreeMap<IdentificationValue, CausalityError> {
+        &self.failures
+    }
+
+    /// Returns the effect of the entity, or None if the entity was not evaluated or failed.
+    pub fn effect_of(&self, entity_id: IdentificationValue) -> Option<bool> {
+        self.effects.get(&entity_id).copied()
+    }
+
+    /// Returns true if no entity failed.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    pub fn number_true(&self) -> usize {
+        self.effects.values().filter(|e| **e).count()
+    }
+
+    pub fn number_false(&self) -> usize {
+        self.effects.len() - self.number_true()
+    }
+
+    /// Returns the percentage of successfully evaluated entities with a true effect
+    /// between 0.0 and 100.0, or 0.0 if no entity was evaluated successfully.
+    pub fn percent_true(&self) -> NumericalValue {
+        if self.effects.is_empty() {
+            return 0.0;
+        }
+        (self.number_true() as NumericalValue / self.effects.len() as NumericalValue) * 100.0
+    }
+}
+
+impl Display for ShardEvaluation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ShardEvaluation: evaluated: {}, true: {}, false: {}, failed: {}",
+            self.effects.len(),
+            self.number_true(),
+            self.number_false(),
+            self.failures.len()
+        )
+    }
+}
@@ -1,9 +1,16 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
+pub mod calendar_date;
+pub mod context_delta;
 pub mod context_graph;
+pub mod context_set;
+pub mod context_shard_map;
 pub mod contextoid;
 pub mod node_types;
 pub mod node_types_adjustable;
 pub mod relation_kind;
+pub mod resample_method;
+pub mod shared_context;
 pub mod time_scale;
+pub mod time_series_context;
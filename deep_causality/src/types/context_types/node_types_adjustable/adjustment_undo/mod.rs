@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+/// Undo token returned by a successful batch adjustment.
+///
+/// Holds a copy of every node as it was before the batch adjustment.
+/// Pass the token to undo_adjustments to restore these nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdjustmentUndo<A> {
+    originals: Vec<A>,
+}
+
+impl<A> AdjustmentUndo<A> {
+    pub(crate) fn new(originals: Vec<A>) -> Self {
+        Self { originals }
+    }
+
+    /// Returns the nodes as they were before the batch adjustment.
+    pub fn originals(&self) -> &[A] {
+        &self.originals
+    }
+
+    /// Returns the number of adjusted nodes.
+    pub fn len(&self) -> usize {
+        self.originals.len()
+    }
+
+    /// Returns true if the batch adjustment did not adjust any node.
+    pub fn is_empty(&self) -> bool {
+        self.originals.is_empty()
+    }
+
+    pub(crate) fn into_originals(self) -> Vec<A> {
+        self.originals
+    }
+}
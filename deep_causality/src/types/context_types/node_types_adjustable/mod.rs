@@ -4,3 +4,4 @@ pub mod adjustable_data;
 pub mod adjustable_space;
 pub mod adjustable_space_time;
 pub mod adjustable_time;
+pub mod adjustment_undo;
@@ -11,6 +11,19 @@ pub enum RelationKind {
     SpaceTemporal,
 }
 
+impl RelationKind {
+    /// Returns the relation kind stored as edge weight in a context graph, if any.
+    pub fn from_weight(weight: u64) -> Option<Self> {
+        match weight {
+            0 => Some(RelationKind::Datial),
+            1 => Some(RelationKind::Temporal),
+            2 => Some(RelationKind::Spatial),
+            3 => Some(RelationKind::SpaceTemporal),
+            _ => None,
+        }
+    }
+}
+
 impl Display for RelationKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
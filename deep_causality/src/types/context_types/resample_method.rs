@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Debug, Display};
+
+/// Method used to resample a time series onto a regular time grid.
+///
+/// Point methods read the series at each grid time, whereas aggregation methods
+/// combine all samples in the half-open bucket [time, time + step) of each grid time.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(u8)]
+pub enum ResampleMethod {
+    /// Value of the latest sample at or before the grid time.
+    ForwardFill,
+    /// Linear interpolation between the samples surrounding the grid time.
+    Linear,
+    /// Mean of all samples in the bucket.
+    Mean,
+    /// Minimum of all samples in the bucket.
+    Min,
+    /// Maximum of all samples in the bucket.
+    Max,
+    /// Latest sample in the bucket.
+    Last,
+}
+
+impl ResampleMethod {
+    /// Returns true if the method aggregates all samples of a bucket.
+    pub fn is_aggregation(&self) -> bool {
+        matches!(
+            self,
+            ResampleMethod::Mean | ResampleMethod::Min | ResampleMethod::Max | ResampleMethod::Last
+        )
+    }
+}
+
+impl Display for ResampleMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::*;
+use std::sync::Arc;
+
+use crate::prelude::{Context, Datable, SpaceTemporal, Spatial, Temporable};
+
+/// Immutable snapshot of a shared context.
+///
+/// Dereferences to the context, so it can be passed to causaloids and models
+/// as &Context. The snapshot never changes, even while the live context is updated.
+pub struct ContextSnapshot<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    context: Arc<Context<D, S, T, ST, V>>,
+}
+
+impl<D, S, T, ST, V> Clone for ContextSnapshot<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            context: Arc::clone(&self.context),
+        }
+    }
+}
+
+impl<D, S, T, ST, V> Deref for ContextSnapshot<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    type Target = Context<D, S, T, ST, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.context
+    }
+}
+
+/// Context with copy-on-write snapshots.
+///
+/// Readers evaluate against a snapshot, which is taken in O(1) by sharing the live context.
+/// Writers update the live context through update. The live context is only copied
+/// if snapshots of it are still held, so readers never block writers and
+/// never observe partial updates.
+///
+/// Copy-on-write applies to the context as a whole, not per contextoid: the first update
+/// while a snapshot is held copies the entire live context, including all contextoids,
+/// edges, and extra contexts, which takes O(n) time and memory in the size of the context.
+/// Subsequent updates change the copy in place until the next snapshot is taken.
+/// Subscriptions stay with the live context.
+///
+/// To share between threads, wrap the shared context in a Mutex and hold the lock
+/// only to take a snapshot or to update.
+pub struct SharedContext<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    live: Arc<Context<D, S, T, ST, V>>,
+}
+
+impl<D, S, T, ST, V> SharedContext<D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    pub fn new(context: Context<D, S, T, ST, V>) -> Self {
+        Self {
+            live: Arc::new(context),
+        }
+    }
+
+    /// Returns an immutable snapshot of the live context in O(1).
+    pub fn snapshot(&self) -> ContextSnapshot<D, S, T, ST, V> {
+        ContextSnapshot {
+            context: Arc::clone(&self.live),
+        }
+    }
+
+    /// Returns the live context.
+    pub fn context(&self) -> &Context<D, S, T, ST, V> {
+        &self.live
+    }
+
+    /// Applies the update to the live context and returns its result.
+    ///
+    /// Copies the whole live context first, in O(n), if snapshots of it are held,
+    /// so that the update never changes an existing snapshot.
+    pub fn update<F, R>(&mut self, update: F) -> R
+    where
+        F: FnOnce(&mut Context<D, S, T, ST, V>) -> R,
+    {
+        if Arc::get_mut(&mut self.live).is_none() {
+            self.live = Arc::new(self.live.clone_with_subscribers());
+        }
+
+        update(Arc::get_mut(&mut self.live).expect("Live context is not shared"))
+    }
+
+    /// Returns the number of snapshots that share the live context.
+    pub fn number_snapshots(&self) -> usize {
+        Arc::strong_count(&self.live) - 1
+    }
+
+    /// Returns the live context, copying it in O(n) if snapshots of it are held.
+    pub fn into_context(self) -> Context<D, S, T, ST, V> {
+        Arc::try_unwrap(self.live).unwrap_or_else(|live| live.clone_with_subscribers())
+    }
+}
@@ -8,6 +8,9 @@ use std::fmt::{Display, Formatter};
 pub enum TimeScale {
     #[default]
     NoScale,
+    Nanosecond,
+    Microsecond,
+    Millisecond,
     Second,
     Minute,
     Hour,
@@ -18,6 +21,122 @@ pub enum TimeScale {
     Year,
 }
 
+impl TimeScale {
+    /// Returns the number of seconds of one unit of this scale.
+    ///
+    /// Returns None for NoScale, for the sub-second scales, and for the calendar scales
+    /// Month, Quarter, and Year because their length varies.
+    pub fn seconds(&self) -> Option<u64> {
+        match self {
+            TimeScale::Second => Some(1),
+            TimeScale::Minute => Some(60),
+            TimeScale::Hour => Some(3_600),
+            TimeScale::Day => Some(86_400),
+            TimeScale::Week => Some(604_800),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of nanoseconds of one unit of this scale.
+    ///
+    /// Returns None for NoScale and for the calendar scales.
+    pub fn nanoseconds(&self) -> Option<u64> {
+        match self {
+            TimeScale::Nanosecond => Some(1),
+            TimeScale::Microsecond => Some(1_000),
+            TimeScale::Millisecond => Some(1_000_000),
+            _ => self.seconds().map(|seconds| seconds * 1_000_000_000),
+        }
+    }
+
+    /// Returns true if one unit of this scale is shorter than a second.
+    pub fn is_sub_second(&self) -> bool {
+        matches!(
+            self,
+            TimeScale::Nanosecond | TimeScale::Microsecond | TimeScale::Millisecond
+        )
+    }
+
+    /// Returns the number of months of one unit of this scale.
+    ///
+    /// Returns None for all scales that are not calendar scales.
+    pub fn months(&self) -> Option<u64> {
+        match self {
+            TimeScale::Month => Some(1),
+            TimeScale::Quarter => Some(3),
+            TimeScale::Year => Some(12),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the length of one unit of this scale depends on the calendar.
+    pub fn is_calendar_scale(&self) -> bool {
+        self.months().is_some()
+    }
+
+    /// Converts a value from this scale into the target scale.
+    ///
+    /// Conversion is exact and only defined among the fixed scales (Nanosecond to Week)
+    /// or among the calendar scales (Month, Quarter, Year). Conversion between the two
+    /// groups, or from and to NoScale, returns None because it requires a calendar date.
+    /// Use CalendarDate::add for calendar-aware arithmetic instead.
+    pub fn convert(&self, value: f64, to: TimeScale) -> Option<f64> {
+        if let (Some(from), Some(to)) = (self.nanoseconds(), to.nanoseconds()) {
+            return Some(value * from as f64 / to as f64);
+        }
+
+        if let (Some(from), Some(to)) = (self.months(), to.months()) {
+            return Some(value * from as f64 / to as f64);
+        }
+
+        None
+    }
+}
+
+impl TimeScale {
+    // Returns the number of units of the finer scale per unit of the coarser scale,
+    // or None if the scales cannot be converted or from is finer than to.
+    fn ratio(from: TimeScale, to: TimeScale) -> Option<u64> {
+        let (from, to) = match (from.nanoseconds(), to.nanoseconds()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => match (from.months(), to.months()) {
+                (Some(from), Some(to)) => (from, to),
+                _ => return None,
+            },
+        };
+
+        if from % to == 0 {
+            Some(from / to)
+        } else {
+            None
+        }
+    }
+
+    /// Maps a time unit of this scale onto the unit of the coarser target scale
+    /// that contains it, i.e. 1_500 milliseconds map onto second 1.
+    ///
+    /// Returns None if the target scale is finer than this scale, if one unit
+    /// of the target scale is not a whole multiple of one unit of this scale,
+    /// or if the scales cannot be converted.
+    pub fn downsample(&self, value: u64, to: TimeScale) -> Option<u64> {
+        Self::ratio(to, *self).map(|ratio| value / ratio)
+    }
+
+    /// Maps a time unit of this scale onto the half-open range of units
+    /// of the finer target scale it covers, i.e. second 1 maps onto
+    /// milliseconds 1_000 to 2_000.
+    ///
+    /// Returns None if the target scale is coarser than this scale, if one unit
+    /// of this scale is not a whole multiple of one unit of the target scale,
+    /// if the scales cannot be converted, or if the range overflows.
+    pub fn upsample(&self, value: u64, to: TimeScale) -> Option<(u64, u64)> {
+        let ratio = Self::ratio(*self, to)?;
+        let start = value.checked_mul(ratio)?;
+        let end = start.checked_add(ratio)?;
+        Some((start, end))
+    }
+}
+
 impl Display for TimeScale {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::*;
+
+use crate::errors::ContextIndexError;
+use crate::prelude::{
+    Context, Contextoid, ContextoidType, Contextuable, ContextuableGraph, Datable,
+    IdentificationValue, ResampleMethod, SpaceTemporal, Spatial, Temporable,
+};
+use crate::utils::resample_utils;
+
+/// Context that keeps a bounded sliding window of datoids per sensor.
+///
+/// Each sample pushed for a sensor is added as datoid to the wrapped context.
+/// Once the window of a sensor is full, pushing a new sample removes
+/// the oldest datoid of that sensor from the context.
+pub struct TimeSeriesContext<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    context: Context<D, S, T, ST, V>,
+    window_size: usize,
+    // Per sensor: (time, node index) of each sample, oldest first.
+    windows: HashMap<IdentificationValue, VecDeque<(u64, usize)>>,
+}
+
+impl<D, S, T, ST, V> TimeSeriesContext<D, S, T, ST, V>
+where
+    D: Datable,
+    S: Spatial<V>,
+    T: Temporable<V>,
+    ST: SpaceTemporal<V>,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Creates a new time series context that holds at most window_size samples per sensor.
+    ///
+    /// Returns ContextIndexError if window_size is zero.
+    pub fn new(
+        context: Context<D, S, T, ST, V>,
+        window_size: usize,
+    ) -> Result<Self, ContextIndexError> {
+        if window_size == 0 {
+            return Err(ContextIndexError(
+                "Window size must be greater than zero".into(),
+            ));
+        }
+
+        Ok(Self {
+            context,
+            window_size,
+            windows: HashMap::new(),
+        })
+    }
+
+    pub fn context(&self) -> &Context<D, S, T, ST, V> {
+        &self.context
+    }
+
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Adds the datoid as sample of the sensor at the given time and
+    /// evicts the oldest sample of the sensor if its window is full.
+    ///
+    /// Returns the node index of the datoid or ContextIndexError if the time
+    /// precedes the latest sample of the sensor.
+    pub fn push_sample(
+        &mut self,
+        sensor_id: IdentificationValue,
+        time: u64,
+        datoid: D,
+    ) -> Result<usize, ContextIndexError> {
+        let window = self
+            .windows
+            .entry(sensor_id)
+            .or_insert_with(|| VecDeque::with_capacity(self.window_size));
+
+        if let Some((latest, _)) = window.back() {
+            if time < *latest {
+                return Err(ContextIndexError(format!(
+                    "Sample of sensor {} at time {} precedes latest sample at time {}",
+                    sensor_id, time, latest
+                )));
+            }
+        }
+
+        if window.len() == self.window_size {
+            let (_, evicted) = window.pop_front().expect("Failed to evict sample");
+            self.context.remove_node(evicted)?;
+        }
+
+        let contextoid = Contextoid::new(datoid.id(), ContextoidType::Datoid(datoid));
+        let index = self.context.add_node(contextoid);
+        window.push_back((time, index));
+
+        Ok(index)
+    }
+
+    /// Returns the time and datoid of all samples of the sensor, oldest first.
+    pub fn samples(&self, sensor_id: IdentificationValue) -> Vec<(u64, &D)> {
+        match self.windows.get(&sensor_id) {
+            Some(window) => window
+                .iter()
+                .filter_map(|(time, index)| self.get_datoid(*index).map(|d| (*time, d)))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the time and datoid of the latest sample of the sensor.
+    pub fn latest(&self, sensor_id: IdentificationValue) -> Option<(u64, &D)> {
+        let (time, index) = self.windows.get(&sensor_id)?.back()?;
+        self.get_datoid(*index).map(|d| (*time, d))
+    }
+
+    /// Returns the node indices of all samples of the sensor, oldest first.
+    pub fn indices(&self, sensor_id: IdentificationValue) -> Vec<usize> {
+        match self.windows.get(&sensor_id) {
+            Some(window) => window.iter().map(|(_, index)| *index).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the number of samples currently held for the sensor.
+    pub fn number_of_samples(&self, sensor_id: IdentificationValue) -> usize {
+        self.windows.get(&sensor_id).map_or(0, |w| w.len())
+    }
+
+    /// Returns true if the window of the sensor holds window_size samples.
+    pub fn is_filled(&self, sensor_id: IdentificationValue) -> bool {
+        self.number_of_samples(sensor_id) == self.window_size
+    }
+
+    /// Returns the ids of all sensors in ascending order.
+    pub fn sensor_ids(&self) -> Vec<IdentificationValue> {
+        let mut ids: Vec<IdentificationValue> = self.windows.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Aligns the samples of the sensors onto a common grid with the given step
+    /// for causaloids that need synchronized inputs.
+    ///
+    /// The grid spans from the latest first sample to the earliest latest sample
+    /// of all sensors, and value extracts the numerical value of each datoid.
+    /// Returns, for each grid time at which every sensor has a value, the time and
+    /// the values of the sensors in the given order, or an empty vector if any
+    /// sensor has no samples, the windows do not overlap, or step is zero.
+    pub fn aligned_window<F>(
+        &self,
+        sensor_ids: &[IdentificationValue],
+        step: u64,
+        method: ResampleMethod,
+        value: F,
+    ) -> Vec<(u64, Vec<f64>)>
+    where
+        F: Fn(&D) -> f64,
+    {
+        if sensor_ids.is_empty() || step == 0 {
+            return Vec::new();
+        }
+
+        let series: Vec<Vec<(u64, f64)>> = sensor_ids
+            .iter()
+            .map(|id| {
+                self.samples(*id)
+                    .into_iter()
+                    .map(|(time, datoid)| (time, value(datoid)))
+                    .collect()
+            })
+            .collect();
+
+        let mut start = 0;
+        let mut end = u64::MAX;
+        for samples in &series {
+            match (samples.first(), samples.last()) {
+                (Some((first, _)), Some((last, _))) => {
+                    start = start.max(*first);
+                    end = end.min(*last);
+                }
+                _ => return Vec::new(),
+            }
+        }
+
+        if end < start {
+            return Vec::new();
+        }
+
+        let len = ((end - start) / step) as usize + 1;
+        let series: Vec<&[(u64, f64)]> = series.iter().map(|s| s.as_slice()).collect();
+        resample_utils::align_series(&series, start, step, len, method)
+    }
+
+    fn get_datoid(&self, index: usize) -> Option<&D> {
+        self.context.get_node(index)?.vertex_type().dataoid()
+    }
+}
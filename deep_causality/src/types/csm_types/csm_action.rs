@@ -4,15 +4,91 @@ use deep_causality_macros::{Constructor, Getters};
 
 use crate::prelude::ActionError;
 
+/// Step of a composite causal action with an optional compensation
+/// that reverts the step if a later step fails.
 #[derive(Getters, Constructor, Clone, Debug)]
+pub struct ActionStep {
+    action: fn() -> Result<(), ActionError>,
+    compensation: Option<fn() -> Result<(), ActionError>>,
+    descr: &'static str,
+}
+
+#[derive(Getters, Clone, Debug)]
 pub struct CausalAction {
     action: fn() -> Result<(), ActionError>,
     descr: &'static str,
     version: usize,
+    steps: Vec<ActionStep>,
+}
+
+impl CausalAction {
+    pub fn new(
+        action: fn() -> Result<(), ActionError>,
+        descr: &'static str,
+        version: usize,
+    ) -> Self {
+        Self {
+            action,
+            descr,
+            version,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Creates a composite action that executes the steps in order with all-or-nothing semantics.
+    ///
+    /// If step k fails, the compensations of steps k-1 to 0 run in reverse order
+    /// and firing returns an ActionError. The action of a composite action is a no-op;
+    /// firing executes the steps instead.
+    pub fn new_composite(steps: Vec<ActionStep>, descr: &'static str, version: usize) -> Self {
+        Self {
+            action: composite_action,
+            descr,
+            version,
+            steps,
+        }
+    }
+
+    /// Returns true if the action consists of steps.
+    pub fn is_composite(&self) -> bool {
+        !self.steps.is_empty()
+    }
 }
 
 impl CausalAction {
     pub fn fire(&self) -> Result<(), ActionError> {
-        (self.action)()
+        if self.steps.is_empty() {
+            return (self.action)();
+        }
+
+        for (k, step) in self.steps.iter().enumerate() {
+            if let Err(e) = (step.action)() {
+                return Err(self.roll_back(k, e));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Runs the compensations of all steps before the failed step in reverse order.
+    fn roll_back(&self, failed: usize, error: ActionError) -> ActionError {
+        let mut message = format!(
+            "Step {} ({}) of {} failed: {}",
+            failed, self.steps[failed].descr, self.descr, error.0
+        );
+
+        for step in self.steps[..failed].iter().rev() {
+            if let Some(compensation) = step.compensation {
+                if let Err(e) = compensation() {
+                    message.push_str(&format!("; compensation of {} failed: {}", step.descr, e.0));
+                }
+            }
+        }
+
+        ActionError(message)
     }
 }
+
+fn composite_action() -> Result<(), ActionError> {
+    Ok(())
+}
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::CausalAction;
+
+/// Failure policy of a state action in the CSM.
+///
+/// When an action fails, it is retried up to max_retries times. The first retry waits
+/// initial_backoff, each following retry waits backoff_factor times longer than the previous one.
+/// The CSM waits by sleeping, hence retries block the thread that evaluates the CSM
+/// for the sum of all backoffs.
+/// If all retries fail, the fallback action fires, if any. Then, if the policy escalates,
+/// the CSM returns an ActionError; otherwise, the failure is recorded in the CSM
+/// and the evaluation continues.
+///
+/// The default policy neither retries nor has a fallback and escalates all failures.
+#[derive(Debug, Copy, Clone)]
+pub struct ActionPolicy<'l> {
+    max_retries: u32,
+    initial_backoff: Duration,
+    backoff_factor: u32,
+    fallback: Option<&'l CausalAction>,
+    escalate: bool,
+}
+
+impl Default for ActionPolicy<'_> {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: Duration::ZERO,
+            backoff_factor: 2,
+            fallback: None,
+            escalate: true,
+        }
+    }
+}
+
+impl<'l> ActionPolicy<'l> {
+    /// Creates the default policy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retries a failed action up to max_retries times, waiting initial_backoff before the first retry.
+    pub fn with_retries(mut self, max_retries: u32, initial_backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the factor by which the backoff grows after each retry. Defaults to 2.
+    pub fn with_backoff_factor(mut self, backoff_factor: u32) -> Self {
+        self.backoff_factor = backoff_factor;
+        self
+    }
+
+    /// Fires the fallback action once all retries have failed.
+    pub fn with_fallback(mut self, fallback: &'l CausalAction) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    /// Sets whether a failure is returned as ActionError (true)
+    /// or recorded in the CSM without interrupting the evaluation (false).
+    pub fn with_escalation(mut self, escalate: bool) -> Self {
+        self.escalate = escalate;
+        self
+    }
+}
+
+impl<'l> ActionPolicy<'l> {
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+    pub fn backoff_factor(&self) -> u32 {
+        self.backoff_factor
+    }
+    pub fn fallback(&self) -> Option<&'l CausalAction> {
+        self.fallback
+    }
+    pub fn escalate(&self) -> bool {
+        self.escalate
+    }
+
+    /// Returns the time to wait before the given retry, starting at 1.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let mut delay = self.initial_backoff;
+        for _ in 1..retry {
+            delay = delay.saturating_mul(self.backoff_factor);
+        }
+        delay
+    }
+}
+
+/// Record of a state action that failed under a non-escalating policy.
+#[derive(Getters, Constructor, Debug, Clone, Eq, PartialEq)]
+pub struct ActionFailure {
+    state_id: usize,
+    attempts: u32,
+    fallback_fired: bool,
+    error: String,
+}
+
+impl Display for ActionFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ActionFailure: state: {}, attempts: {}, fallback fired: {}, error: {}",
+            self.state_id, self.attempts, self.fallback_fired, self.error
+        )
+    }
+}
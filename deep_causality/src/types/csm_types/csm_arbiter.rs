@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+use std::time::Instant;
+
+use crate::errors::UpdateError;
+use crate::prelude::{
+    Datable, SpaceTemporal, Spatial, StateArbitration, SuppressedAction, SuppressionReason,
+    Temporable, CSM,
+};
+
+// Number of suppressed actions kept by default.
+const DEFAULT_SUPPRESSED_CAPACITY: usize = 1024;
+
+/// Arbitration settings, firing history, and bounded suppression log of a CSM.
+#[derive(Debug)]
+pub(super) struct CSMArbiter {
+    settings: HashMap<usize, StateArbitration>,
+    // Times of the successful firings per rate limited state, oldest first.
+    history: HashMap<usize, VecDeque<Instant>>,
+    suppressed_capacity: usize,
+    suppressed: VecDeque<SuppressedAction>,
+}
+
+impl Default for CSMArbiter {
+    fn default() -> Self {
+        Self {
+            settings: HashMap::new(),
+            history: HashMap::new(),
+            suppressed_capacity: DEFAULT_SUPPRESSED_CAPACITY,
+            suppressed: VecDeque::new(),
+        }
+    }
+}
+
+impl CSMArbiter {
+    pub(super) fn remove(&mut self, state_id: usize) {
+        self.settings.remove(&state_id);
+        self.history.remove(&state_id);
+    }
+
+    pub(super) fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(usize) -> bool,
+    {
+        self.settings.retain(|id, _| keep(*id));
+        self.history.retain(|id, _| keep(*id));
+    }
+
+    // Records the successful firing of the state at time now if the state is rate limited.
+    pub(super) fn fired(&mut self, state_id: usize, now: Instant) {
+        let rate_limited = self
+            .settings
+            .get(&state_id)
+            .is_some_and(|settings| settings.rate_limit().is_some());
+
+        if rate_limited {
+            self.history.entry(state_id).or_default().push_back(now);
+        }
+    }
+
+    // Appends a suppressed action to the log and drops the oldest one if the log is full.
+    fn log(&mut self, action: SuppressedAction) {
+        if self.suppressed.len() == self.suppressed_capacity {
+            self.suppressed.pop_front();
+        }
+        self.suppressed.push_back(action);
+    }
+
+    // Returns true if the state may fire at time now.
+    fn admit_rate(&mut self, state_id: usize, settings: &StateArbitration, now: Instant) -> bool {
+        let (max_fires, window) = match settings.rate_limit() {
+            Some(rate_limit) => rate_limit,
+            None => return true,
+        };
+
+        let times = self.history.entry(state_id).or_default();
+        while times
+            .front()
+            .is_some_and(|fired| now.duration_since(*fired) >= window)
+        {
+            times.pop_front();
+        }
+
+        if times.len() >= max_fires as usize {
+            self.log(SuppressedAction::new(
+                state_id,
+                SuppressionReason::RateLimited { max_fires, window },
+            ));
+            return false;
+        }
+
+        true
+    }
+}
+
+impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Sets the arbitration settings of the state with the given id.
+    /// Returns UpdateError if the state does not exist.
+    pub fn set_arbitration(
+        &self,
+        state_id: usize,
+        arbitration: StateArbitration,
+    ) -> Result<(), UpdateError> {
+        if !self.state_actions.borrow().contains_key(&state_id) {
+            return Err(UpdateError(format!(
+                "State {} does not exists. Add it first before setting its arbitration",
+                state_id
+            )));
+        }
+
+        self.arbiter
+            .borrow_mut()
+            .settings
+            .insert(state_id, arbitration);
+
+        Ok(())
+    }
+
+    /// Returns the arbitration settings of the state with the given id,
+    /// which are the default settings if none were set.
+    pub fn get_arbitration(&self, state_id: usize) -> StateArbitration {
+        self.arbiter
+            .borrow()
+            .settings
+            .get(&state_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the most recent actions suppressed by the arbitration, oldest first.
+    pub fn suppressed_actions(&self) -> Vec<SuppressedAction> {
+        self.arbiter.borrow().suppressed.iter().copied().collect()
+    }
+
+    /// Returns the number of suppressed actions kept, which is 1024 by default.
+    pub fn suppressed_capacity(&self) -> usize {
+        self.arbiter.borrow().suppressed_capacity
+    }
+
+    /// Keeps the most recent capacity suppressed actions
+    /// and drops the oldest records that no longer fit.
+    /// Returns UpdateError if the capacity is zero.
+    pub fn set_suppressed_capacity(&self, capacity: usize) -> Result<(), UpdateError> {
+        if capacity == 0 {
+            return Err(UpdateError(
+                "Suppressed actions capacity must be greater than zero".to_string(),
+            ));
+        }
+
+        let mut arbiter = self.arbiter.borrow_mut();
+        arbiter.suppressed_capacity = capacity;
+        while arbiter.suppressed.len() > capacity {
+            arbiter.suppressed.pop_front();
+        }
+
+        Ok(())
+    }
+
+    /// Removes all records of suppressed actions.
+    pub fn clear_suppressed_actions(&self) {
+        self.arbiter.borrow_mut().suppressed.clear();
+    }
+
+    // Decides which of the states that fire in the same evaluation cycle may execute
+    // their actions and logs all others. Returns the admitted ids in the given order.
+    // Admitted states count towards their rate limit only once their action fired, see fired.
+    pub(super) fn arbitrate(&self, candidates: &[usize]) -> Vec<usize> {
+        let mut arbiter = self.arbiter.borrow_mut();
+
+        if candidates
+            .iter()
+            .all(|id| !arbiter.settings.contains_key(id))
+        {
+            return candidates.to_vec();
+        }
+
+        let settings: Vec<StateArbitration> = candidates
+            .iter()
+            .map(|id| arbiter.settings.get(id).copied().unwrap_or_default())
+            .collect();
+
+        // Stable sort keeps the evaluation order among equal priorities.
+        let mut ranked: Vec<usize> = (0..candidates.len()).collect();
+        ranked.sort_by_key(|pos| std::cmp::Reverse(settings[*pos].priority()));
+
+        let now = Instant::now();
+        let mut winners: HashMap<u64, usize> = HashMap::new();
+        let mut admitted = vec![false; candidates.len()];
+
+        for pos in ranked {
+            let id = candidates[pos];
+            let setting = &settings[pos];
+
+            if let Some(group) = setting.group() {
+                if let Some(winner) = winners.get(&group) {
+                    arbiter.log(SuppressedAction::new(
+                        id,
+                        SuppressionReason::Excluded {
+                            group,
+                            winner: *winner,
+                        },
+                    ));
+                    continue;
+                }
+            }
+
+            if !arbiter.admit_rate(id, setting, now) {
+                continue;
+            }
+
+            if let Some(group) = setting.group() {
+                winners.insert(group, id);
+            }
+            admitted[pos] = true;
+        }
+
+        candidates
+            .iter()
+            .zip(admitted)
+            .filter(|(_, admitted)| *admitted)
+            .map(|(id, _)| *id)
+            .collect()
+    }
+}
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use deep_causality_macros::{Constructor, Getters};
+
+/// Arbitration settings of a state action in the CSM.
+///
+/// When several states fire in the same evaluation cycle, actions are admitted
+/// in descending priority. Of all states in the same mutual exclusion group,
+/// only the admitted one with the highest priority fires; ties go to the state evaluated first.
+/// A rate limit caps the number of firings of the action within a sliding time window.
+///
+/// The default settings have priority zero, no group, and no rate limit.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct StateArbitration {
+    priority: i32,
+    group: Option<u64>,
+    rate_limit: Option<(u32, Duration)>,
+}
+
+impl StateArbitration {
+    /// Creates the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the priority; higher values win.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the mutual exclusion group of the state.
+    pub fn with_group(mut self, group: u64) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Allows at most max_fires firings within any window.
+    pub fn with_rate_limit(mut self, max_fires: u32, window: Duration) -> Self {
+        self.rate_limit = Some((max_fires, window));
+        self
+    }
+}
+
+impl StateArbitration {
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+    pub fn group(&self) -> Option<u64> {
+        self.group
+    }
+    pub fn rate_limit(&self) -> Option<(u32, Duration)> {
+        self.rate_limit
+    }
+}
+
+/// Reason why the arbitration suppressed an action.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SuppressionReason {
+    /// Another state of the same mutual exclusion group won.
+    Excluded { group: u64, winner: usize },
+    /// The action already fired max_fires times within the window.
+    RateLimited { max_fires: u32, window: Duration },
+}
+
+impl Display for SuppressionReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SuppressionReason::Excluded { group, winner } => {
+                write!(f, "excluded by state {} in group {}", winner, group)
+            }
+            SuppressionReason::RateLimited { max_fires, window } => {
+                write!(f, "rate limited to {} per {:?}", max_fires, window)
+            }
+        }
+    }
+}
+
+/// Record of a state action suppressed by the arbitration.
+#[derive(Getters, Constructor, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SuppressedAction {
+    state_id: usize,
+    reason: SuppressionReason,
+}
+
+impl Display for SuppressedAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SuppressedAction: state: {}, {}",
+            self.state_id, self.reason
+        )
+    }
+}
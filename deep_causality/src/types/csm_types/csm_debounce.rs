@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+use std::time::Instant;
+
+use crate::prelude::{
+    CausalState, Datable, SpaceTemporal, Spatial, StateDebounce, Temporable, CSM,
+};
+
+/// Debounce progress of a single state.
+#[derive(Debug, Default, Copy, Clone)]
+pub(super) struct DebounceTracker {
+    active: bool,
+    // Consecutive evaluations that disagree with the current activation.
+    streak: u32,
+    last_fired: Option<Instant>,
+}
+
+impl DebounceTracker {
+    // Applies one raw evaluation result and returns true if the action should fire.
+    // The cooldown starts only once the action actually fired, see fired.
+    fn update(&mut self, debounce: &StateDebounce, result: bool, now: Instant) -> bool {
+        if result != self.active {
+            self.streak += 1;
+            let required = if self.active {
+                debounce.release()
+            } else {
+                debounce.rise()
+            };
+            if self.streak >= required {
+                self.active = result;
+                self.streak = 0;
+            }
+        } else {
+            self.streak = 0;
+        }
+
+        self.active
+            && self.last_fired.map_or(true, |fired| {
+                now.duration_since(fired) >= debounce.cooldown()
+            })
+    }
+
+    // Starts the cooldown after the action fired at time now.
+    fn fired(&mut self, now: Instant) {
+        self.last_fired = Some(now);
+    }
+}
+
+impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Returns true if the state with the given id is active after debouncing.
+    pub fn is_state_active(&self, state_id: usize) -> bool {
+        self.debounce
+            .borrow()
+            .get(&state_id)
+            .is_some_and(|tracker| tracker.active)
+    }
+
+    /// Resets the debounce progress and cooldown of the state with the given id.
+    pub fn reset_debounce(&self, state_id: usize) {
+        self.debounce.borrow_mut().remove(&state_id);
+    }
+
+    // Applies the debounce configuration of the state to the raw evaluation result
+    // and returns true if the action of the state should fire.
+    pub(super) fn debounce_state(
+        &self,
+        state: &CausalState<'l, D, S, T, ST, V>,
+        result: bool,
+    ) -> bool {
+        let debounce = state.debounce();
+        let mut trackers = self.debounce.borrow_mut();
+        let tracker = trackers.entry(*state.id()).or_default();
+
+        // The default configuration fires on every true result without a cooldown,
+        // hence only the activation needs tracking.
+        if *debounce == StateDebounce::default() {
+            tracker.active = result;
+            return result;
+        }
+
+        tracker.update(debounce, result, Instant::now())
+    }
+
+    // Starts the cooldown of the state with the given id after its action fired.
+    pub(super) fn debounce_fired(&self, state_id: usize) {
+        if let Some(tracker) = self.debounce.borrow_mut().get_mut(&state_id) {
+            tracker.fired(Instant::now());
+        }
+    }
+}
@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::{BTreeSet, HashMap};
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+use crate::errors::{ActionError, UpdateError};
+use crate::prelude::{Datable, SpaceTemporal, Spatial, Temporable, CSM};
+
+impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Declares that the state with id state_id depends on the state with id depends_on,
+    /// meaning depends_on is always evaluated before state_id in eval_all_states.
+    ///
+    /// Returns UpdateError if either state does not exist, if the dependency already exists,
+    /// or if the dependency would create a cycle.
+    pub fn add_dependency(&self, state_id: usize, depends_on: usize) -> Result<(), UpdateError> {
+        {
+            let binding = self.state_actions.borrow();
+            for id in [state_id, depends_on] {
+                if !binding.contains_key(&id) {
+                    return Err(UpdateError(format!(
+                        "State {} does not exists. Add it first before adding a dependency",
+                        id
+                    )));
+                }
+            }
+        }
+
+        if state_id == depends_on {
+            return Err(UpdateError(format!(
+                "State {} cannot depend on itself",
+                state_id
+            )));
+        }
+
+        if self.depends_on(state_id, depends_on) {
+            return Err(UpdateError(format!(
+                "Dependency of state {} on state {} already exists",
+                state_id, depends_on
+            )));
+        }
+
+        // Adding the edge creates a cycle if state_id is already reachable from depends_on.
+        if self.depends_on_transitive(depends_on, state_id) {
+            return Err(UpdateError(format!(
+                "Dependency of state {} on state {} would create a cycle",
+                state_id, depends_on
+            )));
+        }
+
+        self.dependencies
+            .borrow_mut()
+            .entry(state_id)
+            .or_default()
+            .push(depends_on);
+
+        Ok(())
+    }
+
+    /// Removes the dependency of the state with id state_id on the state with id depends_on.
+    /// Returns UpdateError if the dependency does not exist.
+    pub fn remove_dependency(&self, state_id: usize, depends_on: usize) -> Result<(), UpdateError> {
+        let mut binding = self.dependencies.borrow_mut();
+
+        let deps = match binding.get_mut(&state_id) {
+            Some(deps) if deps.contains(&depends_on) => deps,
+            _ => {
+                return Err(UpdateError(format!(
+                    "Dependency of state {} on state {} does not exists",
+                    state_id, depends_on
+                )))
+            }
+        };
+
+        deps.retain(|id| *id != depends_on);
+        if deps.is_empty() {
+            binding.remove(&state_id);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the ids of all states the given state directly depends on.
+    pub fn get_dependencies(&self, state_id: usize) -> Vec<usize> {
+        self.dependencies
+            .borrow()
+            .get(&state_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the ids of all states in the order in which eval_all_states evaluates them.
+    ///
+    /// The order respects all declared dependencies; states without ordering
+    /// constraints between them are ordered by ascending id.
+    /// Returns ActionError if the dependencies contain a cycle.
+    pub fn evaluation_order(&self) -> Result<Vec<usize>, ActionError> {
+        let states = self.state_actions.borrow();
+        let dependencies = self.dependencies.borrow();
+
+        // Number of unresolved dependencies per state and the reverse edges.
+        let mut in_degree: HashMap<usize, usize> = states.keys().map(|id| (*id, 0)).collect();
+        let mut dependents: HashMap<usize, Vec<usize>> = HashMap::new();
+
+        for (id, depends_on) in dependencies.iter() {
+            for dep in depends_on {
+                *in_degree.get_mut(id).expect("State not found") += 1;
+                dependents.entry(*dep).or_default().push(*id);
+            }
+        }
+
+        // Kahn's algorithm with a sorted ready set for a deterministic order.
+        let mut ready: BTreeSet<usize> = in_degree
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(id, _)| *id)
+            .collect();
+
+        let mut order = Vec::with_capacity(states.len());
+
+        while let Some(id) = ready.pop_first() {
+            order.push(id);
+
+            if let Some(next) = dependents.get(&id) {
+                for n in next {
+                    let degree = in_degree.get_mut(n).expect("State not found");
+                    *degree -= 1;
+                    if *degree == 0 {
+                        ready.insert(*n);
+                    }
+                }
+            }
+        }
+
+        if order.len() != states.len() {
+            return Err(ActionError(
+                "CSM[eval]: Dependencies between causal states contain a cycle".to_string(),
+            ));
+        }
+
+        Ok(order)
+    }
+
+    fn depends_on(&self, state_id: usize, depends_on: usize) -> bool {
+        self.dependencies
+            .borrow()
+            .get(&state_id)
+            .is_some_and(|deps| deps.contains(&depends_on))
+    }
+
+    // Returns true if target is reachable from start by following dependencies.
+    fn depends_on_transitive(&self, start: usize, target: usize) -> bool {
+        let dependencies = self.dependencies.borrow();
+
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![start];
+
+        while let Some(id) = stack.pop() {
+            if id == target {
+                return true;
+            }
+
+            if visited.insert(id) {
+                if let Some(deps) = dependencies.get(&id) {
+                    stack.extend(deps.iter().copied());
+                }
+            }
+        }
+
+        false
+    }
+
+    pub(super) fn remove_all_dependencies_of(&self, state_id: usize) {
+        let mut binding = self.dependencies.borrow_mut();
+
+        binding.remove(&state_id);
+        binding.retain(|_, deps| {
+            deps.retain(|id| *id != state_id);
+            !deps.is_empty()
+        });
+    }
+}
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::errors::UpdateError;
+use crate::prelude::{
+    ActionOutcome, CSMRecord, Datable, NumericalValue, SpaceTemporal, Spatial, Temporable, CSM,
+};
+use crate::utils::json_utils::number_json;
+
+/// Bounded buffer of the most recent evaluations of a CSM.
+#[derive(Debug)]
+pub(super) struct CSMHistory {
+    capacity: usize,
+    records: VecDeque<CSMRecord>,
+}
+
+impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Records every evaluation in a history that keeps the most recent capacity records.
+    /// Enabling a history that is already enabled changes its capacity
+    /// and drops the oldest records that no longer fit.
+    /// Returns UpdateError if the capacity is zero.
+    pub fn enable_history(&self, capacity: usize) -> Result<(), UpdateError> {
+        if capacity == 0 {
+            return Err(UpdateError(
+                "History capacity must be greater than zero".to_string(),
+            ));
+        }
+
+        let mut history = self.history.borrow_mut();
+        match history.as_mut() {
+            Some(history) => {
+                history.capacity = capacity;
+                while history.records.len() > capacity {
+                    history.records.pop_front();
+                }
+            }
+            None => {
+                *history = Some(CSMHistory {
+                    capacity,
+                    records: VecDeque::with_capacity(capacity),
+                })
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stops recording and drops the history.
+    pub fn disable_history(&self) {
+        *self.history.borrow_mut() = None;
+    }
+
+    /// Returns true if the CSM records its evaluations.
+    pub fn is_history_enabled(&self) -> bool {
+        self.history.borrow().is_some()
+    }
+
+    /// Returns all recorded evaluations, oldest first.
+    /// Returns an empty vector if the history is disabled.
+    pub fn history(&self) -> Vec<CSMRecord> {
+        match self.history.borrow().as_ref() {
+            Some(history) => history.records.iter().copied().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Removes all recorded evaluations.
+    pub fn clear_history(&self) {
+        if let Some(history) = self.history.borrow_mut().as_mut() {
+            history.records.clear();
+        }
+    }
+
+    /// Serializes the history into a JSON array of records, oldest first.
+    ///
+    /// Each record is an object with the fields timestamp, state_id, input,
+    /// verdict, and outcome. An input that is not a finite number and
+    /// the verdict of a failed evaluation are written as null.
+    pub fn history_to_json(&self) -> String {
+        let records: Vec<String> = self
+            .history()
+            .iter()
+            .map(|record| {
+                let verdict = match record.verdict() {
+                    Some(verdict) => verdict.to_string(),
+                    None => "null".to_string(),
+                };
+                format!(
+                    "{{\"timestamp\":{},\"state_id\":{},\"input\":{},\"verdict\":{},\"outcome\":\"{}\"}}",
+                    record.timestamp(),
+                    record.state_id(),
+                    number_json(*record.input()),
+                    verdict,
+                    record.outcome()
+                )
+            })
+            .collect();
+
+        format!("[{}]", records.join(","))
+    }
+
+    // Appends an evaluation to the history, if enabled.
+    pub(super) fn record_evaluation(
+        &self,
+        state_id: usize,
+        input: NumericalValue,
+        verdict: Option<bool>,
+        outcome: ActionOutcome,
+    ) {
+        let mut binding = self.history.borrow_mut();
+        let history = match binding.as_mut() {
+            Some(history) => history,
+            None => return,
+        };
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64);
+
+        if history.records.len() == history.capacity {
+            history.records.pop_front();
+        }
+        history
+            .records
+            .push_back(CSMRecord::new(timestamp, state_id, input, verdict, outcome));
+    }
+}
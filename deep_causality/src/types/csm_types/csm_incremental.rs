@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeSet;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+use std::sync::mpsc::Receiver;
+
+use crate::errors::{ActionError, UpdateError};
+use crate::prelude::{
+    ContextDelta, Datable, Identifiable, SpaceTemporal, Spatial, Temporable, CSM,
+};
+
+impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Declares that the state with the given id reads the contextoid with the given id
+    /// so that a change of the contextoid triggers a re-evaluation of the state.
+    /// Returns UpdateError if the state does not exist or already watches the contextoid.
+    pub fn watch_contextoid(&self, state_id: usize, contextoid_id: u64) -> Result<(), UpdateError> {
+        if !self.state_actions.borrow().contains_key(&state_id) {
+            return Err(UpdateError(format!(
+                "State {} does not exists. Add it first before watching a contextoid",
+                state_id
+            )));
+        }
+
+        if !self
+            .context_index
+            .borrow_mut()
+            .entry(contextoid_id)
+            .or_default()
+            .insert(state_id)
+        {
+            return Err(UpdateError(format!(
+                "State {} already watches contextoid {}",
+                state_id, contextoid_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Removes the contextoid with the given id from the contextoids watched by the state.
+    /// Returns UpdateError if the state does not watch the contextoid.
+    pub fn unwatch_contextoid(
+        &self,
+        state_id: usize,
+        contextoid_id: u64,
+    ) -> Result<(), UpdateError> {
+        let mut index = self.context_index.borrow_mut();
+
+        let removed = match index.get_mut(&contextoid_id) {
+            Some(states) => {
+                let removed = states.remove(&state_id);
+                if states.is_empty() {
+                    index.remove(&contextoid_id);
+                }
+                removed
+            }
+            None => false,
+        };
+
+        if !removed {
+            return Err(UpdateError(format!(
+                "State {} does not watch contextoid {}",
+                state_id, contextoid_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the ids of all contextoids watched by the state in ascending order.
+    pub fn watched_contextoids(&self, state_id: usize) -> Vec<u64> {
+        let mut ids: Vec<u64> = self
+            .context_index
+            .borrow()
+            .iter()
+            .filter(|(_, states)| states.contains(&state_id))
+            .map(|(id, _)| *id)
+            .collect();
+
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Returns the ids of all states affected by a change of the given contextoids in
+    /// evaluation order.
+    ///
+    /// A state is affected if it watches a changed contextoid or depends,
+    /// directly or transitively, on an affected state.
+    /// Returns ActionError if the dependencies contain a cycle.
+    pub fn affected_states(&self, changed: &[u64]) -> Result<Vec<usize>, ActionError> {
+        let mut affected: BTreeSet<usize> = {
+            let index = self.context_index.borrow();
+            changed
+                .iter()
+                .filter_map(|id| index.get(id))
+                .flatten()
+                .copied()
+                .collect()
+        };
+
+        if affected.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // The evaluation order lists every state after its dependencies,
+        // so a single pass propagates the change to all dependents.
+        let order = self.evaluation_order()?;
+        let dependencies = self.dependencies.borrow();
+
+        let mut result = Vec::with_capacity(affected.len());
+        for id in order {
+            let depends_on_affected = dependencies
+                .get(&id)
+                .is_some_and(|deps| deps.iter().any(|dep| affected.contains(dep)));
+
+            if depends_on_affected {
+                affected.insert(id);
+            }
+
+            if affected.contains(&id) {
+                result.push(id);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Evaluates only the states affected by a change of the given contextoids
+    /// and skips all other states.
+    /// Returns the ids of the evaluated states in evaluation order.
+    /// Returns ActionError if an evaluation failed or the dependencies contain a cycle.
+    pub fn eval_changed_states(&self, changed: &[u64]) -> Result<Vec<usize>, ActionError> {
+        let order = self.affected_states(changed)?;
+
+        self.eval_in_order(&order)?;
+
+        Ok(order)
+    }
+
+    /// Evaluates only the states affected by the given context delta.
+    ///
+    /// Added, updated, and removed contextoids and the endpoints of added
+    /// and removed edges count as changed.
+    /// Returns the ids of the evaluated states in evaluation order.
+    /// Returns ActionError if an evaluation failed or the dependencies contain a cycle.
+    pub fn eval_context_delta(
+        &self,
+        delta: &ContextDelta<D, S, T, ST, V>,
+    ) -> Result<Vec<usize>, ActionError> {
+        let mut changed: Vec<u64> = delta
+            .added_nodes()
+            .iter()
+            .chain(delta.updated_nodes().iter())
+            .map(|node| node.id())
+            .chain(delta.removed_nodes().iter().copied())
+            .collect();
+
+        for (source, target, _) in delta.added_edges() {
+            changed.extend([*source, *target]);
+        }
+        for (source, target) in delta.removed_edges() {
+            changed.extend([*source, *target]);
+        }
+
+        self.eval_changed_states(&changed)
+    }
+
+    /// Evaluates the states affected by all changes received so far
+    /// from a context subscription without blocking.
+    /// Returns the ids of the evaluated states in evaluation order.
+    /// Returns ActionError if an evaluation failed or the dependencies contain a cycle.
+    pub fn eval_pending_changes(&self, changes: &Receiver<u64>) -> Result<Vec<usize>, ActionError> {
+        let changed: Vec<u64> = changes.try_iter().collect();
+
+        self.eval_changed_states(&changed)
+    }
+
+    /// Evaluates the affected states whenever a context subscription receives changes
+    /// and blocks until the subscribed context and all its clones are dropped.
+    ///
+    /// Changes that arrive together are evaluated together,
+    /// so each affected state is evaluated once per batch.
+    /// Returns ActionError if an evaluation failed or the dependencies contain a cycle.
+    pub fn run_on_changes(&self, changes: &Receiver<u64>) -> Result<(), ActionError> {
+        while let Ok(id) = changes.recv() {
+            let mut changed = vec![id];
+            changed.extend(changes.try_iter());
+
+            self.eval_changed_states(&changed)?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn unwatch_all_contextoids_of(&self, state_id: usize) {
+        self.context_index.borrow_mut().retain(|_, states| {
+            states.remove(&state_id);
+            !states.is_empty()
+        });
+    }
+}
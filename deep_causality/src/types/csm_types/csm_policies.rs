@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+use std::thread;
+
+use crate::errors::{ActionError, UpdateError};
+use crate::prelude::{
+    ActionFailure, ActionPolicy, CausalAction, Datable, SpaceTemporal, Spatial, Temporable, CSM,
+};
+use crate::types::csm_types::CSMStateActions;
+
+// Number of action failures kept by default.
+const DEFAULT_FAILURES_CAPACITY: usize = 1024;
+
+/// Bounded log of the most recent action failures of a CSM.
+#[derive(Debug)]
+pub(super) struct CSMFailures {
+    capacity: usize,
+    records: VecDeque<ActionFailure>,
+}
+
+impl Default for CSMFailures {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_FAILURES_CAPACITY,
+            records: VecDeque::new(),
+        }
+    }
+}
+
+impl CSMFailures {
+    // Appends a failure and drops the oldest one if the log is full.
+    fn push(&mut self, failure: ActionFailure) {
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(failure);
+    }
+}
+
+impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Constructs a new CSM with a failure policy per state id.
+    /// States without a policy use the default policy, which escalates all failures.
+    /// Returns UpdateError if a policy refers to a state that does not exist.
+    pub fn new_with_policies(
+        state_actions: &'l CSMStateActions<'l, D, S, T, ST, V>,
+        policies: &[(usize, ActionPolicy<'l>)],
+    ) -> Result<Self, UpdateError> {
+        let csm = Self::new(state_actions);
+        for (id, policy) in policies {
+            csm.set_action_policy(*id, *policy)?;
+        }
+
+        Ok(csm)
+    }
+
+    /// Sets the failure policy of the state with the given id.
+    /// Returns UpdateError if the state does not exist.
+    pub fn set_action_policy(
+        &self,
+        state_id: usize,
+        policy: ActionPolicy<'l>,
+    ) -> Result<(), UpdateError> {
+        if !self.state_actions.borrow().contains_key(&state_id) {
+            return Err(UpdateError(format!(
+                "State {} does not exists. Add it first before setting a policy",
+                state_id
+            )));
+        }
+
+        self.policies.borrow_mut().insert(state_id, policy);
+
+        Ok(())
+    }
+
+    /// Returns the failure policy of the state with the given id,
+    /// which is the default policy if none was set.
+    pub fn get_action_policy(&self, state_id: usize) -> ActionPolicy<'l> {
+        self.policies
+            .borrow()
+            .get(&state_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the most recent failures recorded under non-escalating policies, oldest first.
+    pub fn action_failures(&self) -> Vec<ActionFailure> {
+        self.failures.borrow().records.iter().cloned().collect()
+    }
+
+    /// Removes all recorded failures.
+    pub fn clear_action_failures(&self) {
+        self.failures.borrow_mut().records.clear();
+    }
+
+    /// Returns the number of failures kept, which is 1024 by default.
+    pub fn action_failures_capacity(&self) -> usize {
+        self.failures.borrow().capacity
+    }
+
+    /// Keeps the most recent capacity failures and drops the oldest records that no longer fit.
+    /// Returns UpdateError if the capacity is zero.
+    pub fn set_action_failures_capacity(&self, capacity: usize) -> Result<(), UpdateError> {
+        if capacity == 0 {
+            return Err(UpdateError(
+                "Action failures capacity must be greater than zero".to_string(),
+            ));
+        }
+
+        let mut failures = self.failures.borrow_mut();
+        failures.capacity = capacity;
+        while failures.records.len() > capacity {
+            failures.records.pop_front();
+        }
+
+        Ok(())
+    }
+
+    /// Fires the action of the state with the given id under the state's failure policy.
+    ///
+    /// Backoff between retries blocks the calling thread, hence callers must not hold
+    /// a borrow of the state actions while firing so that the action may update the CSM.
+    /// Returns true if the action fired and false if it failed and the failure was recorded.
+    /// Returns ActionError only if the action failed and the policy escalates.
+    pub(super) fn fire_action(
+        &self,
+        state_id: usize,
+        action: &CausalAction,
+    ) -> Result<bool, ActionError> {
+        let policy = self.get_action_policy(state_id);
+
+        let mut attempts = 0;
+        let error = loop {
+            attempts += 1;
+            match action.fire() {
+                Ok(()) => return Ok(true),
+                Err(e) if attempts > policy.max_retries() => break e,
+                Err(_) => {
+                    let delay = policy.backoff(attempts);
+                    if !delay.is_zero() {
+                        thread::sleep(delay);
+                    }
+                }
+            }
+        };
+
+        let fallback_fired = match policy.fallback() {
+            Some(fallback) => fallback.fire().is_ok(),
+            None => false,
+        };
+
+        if policy.escalate() {
+            return Err(error);
+        }
+
+        self.failures.borrow_mut().push(ActionFailure::new(
+            state_id,
+            attempts,
+            fallback_fired,
+            error.0,
+        ));
+
+        Ok(false)
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::NumericalValue;
+
+/// Outcome of the action of an evaluated state.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ActionOutcome {
+    /// The state was not active after debouncing, or its evaluation failed.
+    NotFired,
+    /// The action fired successfully.
+    Fired,
+    /// The arbitration suppressed the action.
+    Suppressed,
+    /// The action failed after all retries.
+    Failed,
+}
+
+impl Display for ActionOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ActionOutcome::NotFired => "not_fired",
+            ActionOutcome::Fired => "fired",
+            ActionOutcome::Suppressed => "suppressed",
+            ActionOutcome::Failed => "failed",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Entry of the evaluation history of a CSM.
+///
+/// The timestamp is in milliseconds since the Unix epoch.
+/// The verdict is None if the evaluation of the state failed.
+#[derive(Getters, Constructor, Debug, Copy, Clone, PartialEq)]
+pub struct CSMRecord {
+    timestamp: u64,
+    state_id: usize,
+    input: NumericalValue,
+    verdict: Option<bool>,
+    outcome: ActionOutcome,
+}
+
+impl Display for CSMRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let verdict = match self.verdict {
+            Some(verdict) => verdict.to_string(),
+            None => "error".to_string(),
+        };
+        write!(
+            f,
+            "CSMRecord: timestamp: {} state: {} input: {} verdict: {} outcome: {}",
+            self.timestamp, self.state_id, self.input, verdict, self.outcome
+        )
+    }
+}
@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::errors::{ActionError, UpdateError};
+use crate::prelude::{CSMTrigger, Datable, SpaceTemporal, Spatial, Temporable, CSM};
+
+/// Upper bound of the time run_schedule sleeps before checking the stop flag again.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Triggers and due times of all scheduled states of a CSM.
+#[derive(Debug, Default)]
+pub(super) struct CSMSchedule {
+    // Maps a state id to its trigger and, while running, the time it is due next.
+    triggers: BTreeMap<usize, (CSMTrigger, Option<Duration>)>,
+    // Time of the last start or tick; None if the schedule is stopped.
+    now: Option<Duration>,
+}
+
+impl CSMSchedule {
+    pub(super) fn remove(&mut self, state_id: usize) {
+        self.triggers.remove(&state_id);
+    }
+
+    pub(super) fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(usize) -> bool,
+    {
+        self.triggers.retain(|id, _| keep(*id));
+    }
+}
+
+impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Schedules the state with the given id to be evaluated every interval.
+    /// Replaces any existing trigger of the state.
+    /// Returns UpdateError if the state does not exist or the interval is zero.
+    pub fn eval_on_interval(&self, state_id: usize, interval: Duration) -> Result<(), UpdateError> {
+        self.add_trigger(state_id, CSMTrigger::Interval(interval))
+    }
+
+    /// Schedules the state with the given id to be evaluated at every k * period + offset
+    /// measured from the start of the schedule.
+    /// Replaces any existing trigger of the state.
+    /// Returns UpdateError if the state does not exist, the period is zero,
+    /// or the offset is not smaller than the period.
+    pub fn eval_on_schedule(
+        &self,
+        state_id: usize,
+        period: Duration,
+        offset: Duration,
+    ) -> Result<(), UpdateError> {
+        if offset >= period {
+            return Err(UpdateError(format!(
+                "Offset {:?} must be smaller than the period {:?}",
+                offset, period
+            )));
+        }
+
+        self.add_trigger(state_id, CSMTrigger::Aligned { period, offset })
+    }
+
+    fn add_trigger(&self, state_id: usize, trigger: CSMTrigger) -> Result<(), UpdateError> {
+        if !self.state_actions.borrow().contains_key(&state_id) {
+            return Err(UpdateError(format!(
+                "State {} does not exists. Add it first before scheduling",
+                state_id
+            )));
+        }
+
+        if trigger.period().is_zero() {
+            return Err(UpdateError(format!(
+                "State {} cannot be scheduled with a zero period",
+                state_id
+            )));
+        }
+
+        let mut schedule = self.schedule.borrow_mut();
+        // A trigger added to a running schedule becomes due relative to the last tick.
+        let due = schedule.now.map(|now| trigger.first_due(now));
+        schedule.triggers.insert(state_id, (trigger, due));
+
+        Ok(())
+    }
+
+    /// Removes the trigger of the state with the given id.
+    /// Returns UpdateError if the state is not scheduled.
+    pub fn remove_schedule(&self, state_id: usize) -> Result<(), UpdateError> {
+        match self.schedule.borrow_mut().triggers.remove(&state_id) {
+            Some(_) => Ok(()),
+            None => Err(UpdateError(format!(
+                "State {} is not scheduled and cannot be removed",
+                state_id
+            ))),
+        }
+    }
+
+    /// Returns the trigger of the state with the given id, if any.
+    pub fn get_schedule(&self, state_id: usize) -> Option<CSMTrigger> {
+        self.schedule
+            .borrow()
+            .triggers
+            .get(&state_id)
+            .map(|(trigger, _)| *trigger)
+    }
+
+    /// Returns the ids of all scheduled states in ascending order.
+    pub fn scheduled_states(&self) -> Vec<usize> {
+        self.schedule.borrow().triggers.keys().copied().collect()
+    }
+
+    /// Starts the schedule at time now.
+    /// Restarting a running schedule recomputes all due times from now.
+    pub fn start_schedule(&self, now: Duration) {
+        let mut schedule = self.schedule.borrow_mut();
+        schedule.now = Some(now);
+        for (trigger, due) in schedule.triggers.values_mut() {
+            *due = Some(trigger.first_due(now));
+        }
+    }
+
+    /// Stops the schedule. Triggers are kept and resume on the next start.
+    pub fn stop_schedule(&self) {
+        let mut schedule = self.schedule.borrow_mut();
+        schedule.now = None;
+        for (_, due) in schedule.triggers.values_mut() {
+            *due = None;
+        }
+    }
+
+    /// Returns true if the schedule is running.
+    pub fn is_schedule_running(&self) -> bool {
+        self.schedule.borrow().now.is_some()
+    }
+
+    /// Returns the earliest time a scheduled state is due or None if the schedule
+    /// is stopped or has no triggers.
+    pub fn next_due_time(&self) -> Option<Duration> {
+        self.schedule
+            .borrow()
+            .triggers
+            .values()
+            .filter_map(|(_, due)| *due)
+            .min()
+    }
+
+    /// Evaluates all scheduled states that are due at time now and fires their actions.
+    ///
+    /// Due states are evaluated in the order returned by evaluation_order and
+    /// are rescheduled before evaluation so that a failing state does not stay due.
+    /// Evaluations missed because now advanced by more than one period are skipped.
+    /// Returns the ids of the evaluated states, which is empty if the schedule is stopped.
+    /// Returns ActionError if an evaluation failed or the dependencies contain a cycle.
+    pub fn eval_due_states(&self, now: Duration) -> Result<Vec<usize>, ActionError> {
+        let mut due_states = Vec::new();
+        {
+            let mut schedule = self.schedule.borrow_mut();
+            if schedule.now.is_none() {
+                return Ok(due_states);
+            }
+            schedule.now = Some(now);
+
+            for (id, (trigger, due)) in schedule.triggers.iter_mut() {
+                if let Some(time) = due {
+                    if *time <= now {
+                        *due = Some(trigger.next_due(*time, now));
+                        due_states.push(*id);
+                    }
+                }
+            }
+        }
+
+        if due_states.is_empty() {
+            return Ok(due_states);
+        }
+
+        let order: Vec<usize> = self
+            .evaluation_order()?
+            .into_iter()
+            .filter(|id| due_states.contains(id))
+            .collect();
+
+        self.eval_in_order(&order)?;
+
+        Ok(order)
+    }
+
+    /// Runs the schedule in real time on the calling thread until stop is set.
+    ///
+    /// The CSM borrows its states and is therefore not shared across threads;
+    /// set stop from another thread or from an action to end the loop.
+    /// The schedule is stopped when the loop returns.
+    /// Returns ActionError if an evaluation failed.
+    pub fn run_schedule(&self, stop: &AtomicBool) -> Result<(), ActionError> {
+        let clock = Instant::now();
+        self.start_schedule(Duration::ZERO);
+
+        while !stop.load(Ordering::Acquire) {
+            if let Err(e) = self.eval_due_states(clock.elapsed()) {
+                self.stop_schedule();
+                return Err(e);
+            }
+
+            let wait = match self.next_due_time() {
+                Some(due) => due.saturating_sub(clock.elapsed()).min(POLL_INTERVAL),
+                None => POLL_INTERVAL,
+            };
+            thread::sleep(wait);
+        }
+
+        self.stop_schedule();
+        Ok(())
+    }
+}
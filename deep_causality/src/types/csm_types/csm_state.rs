@@ -5,14 +5,14 @@ use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
 use std::ops::*;
 
-use deep_causality_macros::{Constructor, Getters};
+use deep_causality_macros::Getters;
 
 use crate::prelude::{
-    Causable, CausalityError, Causaloid, Datable, NumericalValue, SpaceTemporal, Spatial,
-    Temporable,
+    AsyncCausable, Causable, CausalityError, Causaloid, Datable, NumericalValue, SpaceTemporal,
+    Spatial, StateDebounce, Temporable,
 };
 
-#[derive(Getters, Constructor, Clone, Debug)]
+#[derive(Getters, Clone, Debug)]
 pub struct CausalState<'l, D, S, T, ST, V>
 where
     D: Datable + Clone,
@@ -34,6 +34,7 @@ where
     version: usize,
     data: NumericalValue,
     causaloid: &'l Causaloid<'l, D, S, T, ST, V>,
+    debounce: StateDebounce,
 }
 
 impl<'l, D, S, T, ST, V> CausalState<'l, D, S, T, ST, V>
@@ -53,12 +54,45 @@ where
         + Mul<V, Output = V>
         + Clone,
 {
+    pub fn new(
+        id: usize,
+        version: usize,
+        data: NumericalValue,
+        causaloid: &'l Causaloid<'l, D, S, T, ST, V>,
+    ) -> Self {
+        Self {
+            id,
+            version,
+            data,
+            causaloid,
+            debounce: StateDebounce::default(),
+        }
+    }
+
+    /// Sets the debounce and hysteresis configuration the CSM applies to this state.
+    pub fn with_debounce(mut self, debounce: StateDebounce) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
     pub fn eval(&self) -> Result<bool, CausalityError> {
         self.causaloid.verify_single_cause(&self.data)
     }
     pub fn eval_with_data(&self, data: &NumericalValue) -> Result<bool, CausalityError> {
         self.causaloid.verify_single_cause(data)
     }
+    pub async fn eval_async(&self) -> Result<bool, CausalityError>
+    where
+        Causaloid<'l, D, S, T, ST, V>: Sync,
+    {
+        self.causaloid.verify_single_cause_async(&self.data).await
+    }
+    pub async fn eval_with_data_async(&self, data: &NumericalValue) -> Result<bool, CausalityError>
+    where
+        Causaloid<'l, D, S, T, ST, V>: Sync,
+    {
+        self.causaloid.verify_single_cause_async(data).await
+    }
 
     fn fmt_print(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
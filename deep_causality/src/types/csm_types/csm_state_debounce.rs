@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+/// Debounce and hysteresis configuration of a causal state.
+///
+/// A state becomes active once it evaluates to true for rise consecutive evaluations
+/// and becomes inactive again once it evaluates to false for release consecutive evaluations.
+/// While active, the CSM fires the action of the state on every evaluation
+/// unless the action fired less than cooldown ago.
+///
+/// The default configuration, with rise and release of one and no cooldown,
+/// fires on every evaluation that returns true.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct StateDebounce {
+    rise: u32,
+    release: u32,
+    cooldown: Duration,
+}
+
+impl Default for StateDebounce {
+    fn default() -> Self {
+        Self {
+            rise: 1,
+            release: 1,
+            cooldown: Duration::ZERO,
+        }
+    }
+}
+
+impl StateDebounce {
+    /// Creates the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of consecutive true evaluations required to activate the state.
+    /// Values below one are treated as one.
+    pub fn with_rise(mut self, rise: u32) -> Self {
+        self.rise = rise.max(1);
+        self
+    }
+
+    /// Sets the number of consecutive false evaluations required to deactivate the state.
+    /// Values below one are treated as one.
+    pub fn with_release(mut self, release: u32) -> Self {
+        self.release = release.max(1);
+        self
+    }
+
+    /// Sets the minimum time between two firings of the action of the state.
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+impl StateDebounce {
+    pub fn rise(&self) -> u32 {
+        self.rise
+    }
+    pub fn release(&self) -> u32 {
+        self.release
+    }
+    pub fn cooldown(&self) -> Duration {
+        self.cooldown
+    }
+}
+
+impl Display for StateDebounce {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "StateDebounce: rise: {} release: {} cooldown: {:?}",
+            self.rise, self.release, self.cooldown
+        )
+    }
+}
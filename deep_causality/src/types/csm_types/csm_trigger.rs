@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+/// Trigger that determines when a scheduled state of the CSM is evaluated.
+///
+/// All times are measured relative to the start of the schedule.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CSMTrigger {
+    /// Evaluates the state every interval, starting one interval after the schedule starts.
+    Interval(Duration),
+    /// Evaluates the state at every k * period + offset, similar to a cron entry
+    /// i.e. a period of one hour and an offset of five minutes fires at five past every hour.
+    Aligned { period: Duration, offset: Duration },
+}
+
+impl CSMTrigger {
+    /// Returns the time between two consecutive evaluations.
+    pub fn period(&self) -> Duration {
+        match self {
+            CSMTrigger::Interval(interval) => *interval,
+            CSMTrigger::Aligned { period, .. } => *period,
+        }
+    }
+
+    /// Returns the first time at or after now when the trigger fires.
+    pub fn first_due(&self, now: Duration) -> Duration {
+        match self {
+            CSMTrigger::Interval(interval) => now + *interval,
+            CSMTrigger::Aligned { period, offset } => {
+                if now <= *offset {
+                    return *offset;
+                }
+                let elapsed = (now - *offset).as_nanos();
+                let period_nanos = period.as_nanos();
+                let periods = elapsed.div_ceil(period_nanos);
+                *offset + nanos_to_duration(periods * period_nanos)
+            }
+        }
+    }
+
+    /// Returns the first time strictly after now when a trigger last due at due fires again.
+    /// Missed evaluations are skipped rather than replayed.
+    pub fn next_due(&self, due: Duration, now: Duration) -> Duration {
+        let period = self.period();
+        if due > now {
+            return due;
+        }
+        let missed = (now - due).as_nanos() / period.as_nanos() + 1;
+        due + nanos_to_duration(missed * period.as_nanos())
+    }
+}
+
+fn nanos_to_duration(nanos: u128) -> Duration {
+    let secs = (nanos / 1_000_000_000) as u64;
+    let sub_nanos = (nanos % 1_000_000_000) as u32;
+    Duration::new(secs, sub_nanos)
+}
+
+impl Display for CSMTrigger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CSMTrigger::Interval(interval) => write!(f, "CSMTrigger: every {:?}", interval),
+            CSMTrigger::Aligned { period, offset } => {
+                write!(f, "CSMTrigger: every {:?} at offset {:?}", period, offset)
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::IdentificationValue;
+
+/// Watchdog of a CSM state that fires the action of the state
+/// if the monitored datoid received no update within the deadline,
+/// i.e. to detect a dead sensor.
+#[derive(Getters, Constructor, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Watchdog {
+    datoid_id: IdentificationValue,
+    deadline: Duration,
+}
+
+impl Display for Watchdog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Watchdog: datoid: {} deadline: {:?}",
+            self.datoid_id, self.deadline
+        )
+    }
+}
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+use std::time::Duration;
+
+use crate::errors::{ActionError, UpdateError};
+use crate::prelude::{
+    Datable, IdentificationValue, SpaceTemporal, Spatial, Temporable, Watchdog, CSM,
+};
+
+/// Watchdog of a state with the time of the last update of its datoid.
+#[derive(Debug, Copy, Clone)]
+pub(super) struct WatchdogEntry {
+    watchdog: Watchdog,
+    // None until the first heartbeat or check.
+    last_seen: Option<Duration>,
+    // True once fired until the next heartbeat.
+    tripped: bool,
+}
+
+impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Copy,
+    S: Spatial<V> + Clone + Copy,
+    T: Temporable<V> + Clone + Copy,
+    ST: SpaceTemporal<V> + Clone + Copy,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Turns the state with the given id into a watchdog state that fires
+    /// its action when the watched datoid misses its deadline.
+    /// Replaces any existing watchdog of the state.
+    /// Returns UpdateError if the state does not exist or the deadline is zero.
+    pub fn add_watchdog(&self, state_id: usize, watchdog: Watchdog) -> Result<(), UpdateError> {
+        if !self.state_actions.borrow().contains_key(&state_id) {
+            return Err(UpdateError(format!(
+                "State {} does not exists. Add it first before adding a watchdog",
+                state_id
+            )));
+        }
+
+        if watchdog.deadline().is_zero() {
+            return Err(UpdateError(format!(
+                "Watchdog of state {} cannot have a zero deadline",
+                state_id
+            )));
+        }
+
+        self.watchdogs.borrow_mut().insert(
+            state_id,
+            WatchdogEntry {
+                watchdog,
+                last_seen: None,
+                tripped: false,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Removes the watchdog of the state with the given id.
+    /// Returns UpdateError if the state has no watchdog.
+    pub fn remove_watchdog(&self, state_id: usize) -> Result<(), UpdateError> {
+        match self.watchdogs.borrow_mut().remove(&state_id) {
+            Some(_) => Ok(()),
+            None => Err(UpdateError(format!(
+                "State {} has no watchdog that could be removed",
+                state_id
+            ))),
+        }
+    }
+
+    /// Returns the watchdog of the state with the given id, if any.
+    pub fn get_watchdog(&self, state_id: usize) -> Option<Watchdog> {
+        self.watchdogs
+            .borrow()
+            .get(&state_id)
+            .map(|entry| entry.watchdog)
+    }
+
+    /// Returns true if the watchdog of the state fired and its datoid
+    /// has not been updated since.
+    pub fn is_watchdog_tripped(&self, state_id: usize) -> bool {
+        self.watchdogs
+            .borrow()
+            .get(&state_id)
+            .is_some_and(|entry| entry.tripped)
+    }
+
+    /// Signals that the datoid with the given id was updated at time now,
+    /// which resets all watchdogs of the datoid.
+    pub fn heartbeat(&self, datoid_id: IdentificationValue, now: Duration) {
+        for entry in self.watchdogs.borrow_mut().values_mut() {
+            if *entry.watchdog.datoid_id() == datoid_id {
+                entry.last_seen = Some(now);
+                entry.tripped = false;
+            }
+        }
+    }
+
+    /// Fires the actions of all watchdog states whose datoid received
+    /// no update within the deadline before time now.
+    ///
+    /// Time is measured on the same clock as heartbeat, i.e. the virtual time of
+    /// a simulation or the real time elapsed since the monitoring loop started.
+    /// A watchdog that has not yet seen a heartbeat starts its deadline at the first check.
+    /// A watchdog fires once per missed deadline and again only after the next heartbeat.
+    /// Actions pass the arbitration and the failure policy of their state
+    /// and are recorded in the history with the silence in seconds as input.
+    /// Returns the ids of the tripped states in ascending order.
+    /// Returns ActionError if an action failed and its policy escalates.
+    pub fn check_watchdogs(&self, now: Duration) -> Result<Vec<usize>, ActionError> {
+        let mut verdicts = Vec::new();
+        let mut tripped = Vec::new();
+        {
+            let mut watchdogs = self.watchdogs.borrow_mut();
+            for (id, entry) in watchdogs.iter_mut() {
+                let last_seen = *entry.last_seen.get_or_insert(now);
+                let silence = now.saturating_sub(last_seen);
+
+                if !entry.tripped && silence >= *entry.watchdog.deadline() {
+                    entry.tripped = true;
+                    verdicts.push((*id, silence.as_secs_f64(), true));
+                    tripped.push(*id);
+                }
+            }
+        }
+
+        if tripped.is_empty() {
+            return Ok(tripped);
+        }
+
+        self.fire_candidates(&verdicts, &tripped)?;
+
+        Ok(tripped)
+    }
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+use crate::errors::ActionError;
+use crate::prelude::{
+    Datable, DiscreteEventScheduler, SpaceTemporal, Spatial, StateEvaluation, Temporable, CSM,
+};
+
+impl DiscreteEventScheduler<StateEvaluation> {
+    /// Evaluates all scheduled causal states due at or before the end time on the CSM,
+    /// which fires the action of each state that evaluates to true.
+    ///
+    /// Returns the number of evaluated states or the first ActionError.
+    pub fn run_csm_until<D, S, T, ST, V>(
+        &mut self,
+        csm: &CSM<'_, D, S, T, ST, V>,
+        end_time: u64,
+    ) -> Result<usize, ActionError>
+    where
+        D: Datable + Clone + Copy,
+        S: Spatial<V> + Clone + Copy,
+        T: Temporable<V> + Clone + Copy,
+        ST: SpaceTemporal<V> + Clone + Copy,
+        V: Default
+            + Copy
+            + Clone
+            + Hash
+            + Eq
+            + PartialEq
+            + Add<V, Output = V>
+            + Sub<V, Output = V>
+            + Mul<V, Output = V>,
+    {
+        self.run_until(end_time, |_, _, evaluation| {
+            csm.eval_single_state(*evaluation.id(), *evaluation.data())
+        })
+    }
+}
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::errors::ActionError;
+use crate::prelude::{IdentificationValue, NumericalValue, Time, TimeScale};
+
+mod csm;
+
+/// Evaluation of the causal state with the id on the data,
+/// i.e. a scheduled sensor reading fed into a CSM.
+#[derive(Getters, Constructor, Debug, Copy, Clone, PartialEq)]
+pub struct StateEvaluation {
+    id: usize,
+    data: NumericalValue,
+}
+
+// Queue entry ordered by time and, for equal times, by order of scheduling.
+#[derive(Debug)]
+struct ScheduledEvent<E> {
+    time: u64,
+    sequence: u64,
+    event: E,
+}
+
+impl<E> PartialEq for ScheduledEvent<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.time == other.time && self.sequence == other.sequence
+    }
+}
+
+impl<E> Eq for ScheduledEvent<E> {}
+
+impl<E> PartialOrd for ScheduledEvent<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E> Ord for ScheduledEvent<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.time, self.sequence).cmp(&(other.time, other.sequence))
+    }
+}
+
+/// Lightweight discrete event scheduler over virtual time.
+///
+/// Events are processed in order of their scheduled time; events scheduled
+/// for the same time are processed in the order they were scheduled.
+/// Virtual time advances to the time of each processed event and is measured
+/// in units of the time scale, so that the current time converts into a Time
+/// for context updates.
+///
+/// Handlers may schedule follow-up events, i.e. the next arrival of a queue
+/// or the end of a maintenance window, while the simulation runs.
+#[derive(Debug)]
+pub struct DiscreteEventScheduler<E> {
+    time_scale: TimeScale,
+    now: u64,
+    sequence: u64,
+    number_processed: usize,
+    queue: BinaryHeap<Reverse<ScheduledEvent<E>>>,
+}
+
+impl<E> DiscreteEventScheduler<E> {
+    /// Creates a scheduler at virtual time zero measured in units of the time scale.
+    pub fn new(time_scale: TimeScale) -> Self {
+        Self {
+            time_scale,
+            now: 0,
+            sequence: 0,
+            number_processed: 0,
+            queue: BinaryHeap::new(),
+        }
+    }
+
+    pub fn time_scale(&self) -> TimeScale {
+        self.time_scale
+    }
+
+    /// Returns the current virtual time.
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    /// Returns the current virtual time as Time with the given id.
+    pub fn current_time(&self, id: IdentificationValue) -> Time<u64> {
+        Time::new(id, self.time_scale, self.now)
+    }
+
+    /// Returns the number of pending events.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Returns the number of events processed so far.
+    pub fn number_processed(&self) -> usize {
+        self.number_processed
+    }
+
+    /// Returns the time of the next pending event, if any.
+    pub fn next_event_time(&self) -> Option<u64> {
+        self.queue.peek().map(|Reverse(e)| e.time)
+    }
+
+    /// Schedules the event at the absolute virtual time.
+    ///
+    /// Returns ActionError if the time lies in the past.
+    pub fn schedule_at(&mut self, time: u64, event: E) -> Result<(), ActionError> {
+        if time < self.now {
+            return Err(ActionError(format!(
+                "Cannot schedule event at time {} before current time {}",
+                time, self.now
+            )));
+        }
+
+        self.queue.push(Reverse(ScheduledEvent {
+            time,
+            sequence: self.sequence,
+            event,
+        }));
+        self.sequence += 1;
+
+        Ok(())
+    }
+
+    /// Schedules the event after the delay relative to the current virtual time.
+    pub fn schedule_in(&mut self, delay: u64, event: E) {
+        self.schedule_at(self.now + delay, event)
+            .expect("Event scheduled relative to now cannot lie in the past");
+    }
+
+    /// Removes the next event due at or before the end time,
+    /// advances virtual time to it, and returns its time and the event.
+    ///
+    /// Returns None if no event is due until the end time.
+    pub fn next_event_until(&mut self, end_time: u64) -> Option<(u64, E)> {
+        if self.next_event_time()? > end_time {
+            return None;
+        }
+
+        let Reverse(scheduled) = self.queue.pop()?;
+        self.now = scheduled.time;
+        self.number_processed += 1;
+
+        Some((scheduled.time, scheduled.event))
+    }
+
+    /// Processes all events due at or before the end time with the handler
+    /// and advances virtual time to the end time.
+    ///
+    /// The handler receives the scheduler, the time, and the event,
+    /// and may schedule further events. Processing stops at the first handler error.
+    ///
+    /// Returns the number of processed events or the first handler error.
+    pub fn run_until<F>(&mut self, end_time: u64, mut handler: F) -> Result<usize, ActionError>
+    where
+        F: FnMut(&mut Self, u64, E) -> Result<(), ActionError>,
+    {
+        let mut processed = 0;
+
+        while let Some((time, event)) = self.next_event_until(end_time) {
+            handler(self, time, event)?;
+            processed += 1;
+        }
+
+        self.now = self.now.max(end_time);
+
+        Ok(processed)
+    }
+}
@@ -2,22 +2,49 @@
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::hash::Hash;
 use std::ops::{Add, Mul, Sub};
+use std::time::Instant;
 
 use crate::errors::{ActionError, UpdateError};
 use crate::prelude::{
-    CausalAction, CausalState, Datable, NumericalValue, SpaceTemporal, Spatial, Temporable,
+    ActionOutcome, ActionPolicy, CausalAction, CausalState, Causaloid, Datable, NumericalValue,
+    SpaceTemporal, Spatial, Temporable,
 };
+use crate::types::csm_types::csm_arbiter::CSMArbiter;
+use crate::types::csm_types::csm_debounce::DebounceTracker;
+use crate::types::csm_types::csm_history::CSMHistory;
+use crate::types::csm_types::csm_policies::CSMFailures;
+use crate::types::csm_types::csm_schedule::CSMSchedule;
+use crate::types::csm_types::csm_watchdogs::WatchdogEntry;
 
 pub mod csm_action;
+pub mod csm_action_policy;
+mod csm_arbiter;
+pub mod csm_arbitration;
+mod csm_debounce;
+mod csm_dependencies;
+mod csm_history;
+mod csm_incremental;
+mod csm_policies;
+pub mod csm_record;
+mod csm_schedule;
 pub mod csm_state;
+pub mod csm_state_debounce;
+pub mod csm_trigger;
+pub mod csm_watchdog;
+mod csm_watchdogs;
+pub mod event_scheduler;
 
 pub type CSMMap<'l, D, S, T, ST, V> =
     HashMap<usize, (&'l CausalState<'l, D, S, T, ST, V>, &'l CausalAction)>;
 pub type CSMStateActions<'l, D, S, T, ST, V> =
     [(&'l CausalState<'l, D, S, T, ST, V>, &'l CausalAction)];
+// Maps a state id to the ids of all states that must be evaluated before it.
+type CSMDependencyMap = HashMap<usize, Vec<usize>>;
+// Maps a contextoid id to the ids of all states that read it.
+type CSMContextIndex = HashMap<u64, BTreeSet<usize>>;
 
 pub struct CSM<'l, D, S, T, ST, V>
 where
@@ -36,6 +63,15 @@ where
         + Mul<V, Output = V>,
 {
     state_actions: RefCell<CSMMap<'l, D, S, T, ST, V>>,
+    dependencies: RefCell<CSMDependencyMap>,
+    schedule: RefCell<CSMSchedule>,
+    policies: RefCell<HashMap<usize, ActionPolicy<'l>>>,
+    failures: RefCell<CSMFailures>,
+    context_index: RefCell<CSMContextIndex>,
+    debounce: RefCell<HashMap<usize, DebounceTracker>>,
+    arbiter: RefCell<CSMArbiter>,
+    history: RefCell<Option<CSMHistory>>,
+    watchdogs: RefCell<BTreeMap<usize, WatchdogEntry>>,
 }
 
 impl<'l, D, S, T, ST, V> CSM<'l, D, S, T, ST, V>
@@ -64,6 +100,15 @@ where
 
         Self {
             state_actions: RefCell::new(state_map),
+            dependencies: RefCell::new(HashMap::new()),
+            schedule: RefCell::new(CSMSchedule::default()),
+            policies: RefCell::new(HashMap::new()),
+            failures: RefCell::new(CSMFailures::default()),
+            context_index: RefCell::new(HashMap::new()),
+            debounce: RefCell::new(HashMap::new()),
+            arbiter: RefCell::new(CSMArbiter::default()),
+            history: RefCell::new(None),
+            watchdogs: RefCell::new(BTreeMap::new()),
         }
     }
 
@@ -130,6 +175,17 @@ where
         // remove the new state/action at the idx position
         binding.remove(&id);
 
+        // remove all dependencies from and on the removed state
+        self.remove_all_dependencies_of(id);
+
+        // remove all settings and runtime data of the removed state
+        self.schedule.borrow_mut().remove(id);
+        self.policies.borrow_mut().remove(&id);
+        self.unwatch_all_contextoids_of(id);
+        self.reset_debounce(id);
+        self.arbiter.borrow_mut().remove(id);
+        self.watchdogs.borrow_mut().remove(&id);
+
         Ok(())
     }
 }
@@ -151,28 +207,31 @@ where
         + Mul<V, Output = V>,
 {
     /// Evaluates a single causal state at the index position idx.
+    ///
+    /// A failing action is retried according to the action policy of the state.
+    /// Backoff between retries blocks the calling thread.
     /// Returns ActionError if the evaluation failed.
     pub fn eval_single_state(&self, id: usize, data: NumericalValue) -> Result<(), ActionError> {
-        // Need binding to prevent dropped tmp value warnings
-        let binding = self.state_actions.borrow();
+        // Copy the references out so that the state actions are not borrowed
+        // while the action fires, which may update the CSM.
+        let state_action = self.state_actions.borrow().get(&id).copied();
 
-        // Check if state actually exists in the HashMap
-        let state_action = binding.get(&id);
-        if state_action.is_none() {
-            return Err(ActionError(format!(
-                "State {} does not exists. Add it first before evaluating",
-                id
-            )));
-        }
-
-        // State exists, extract it.
-        let (state, action) = state_action.unwrap();
+        let (state, action) = match state_action {
+            Some(state_action) => state_action,
+            None => {
+                return Err(ActionError(format!(
+                    "State {} does not exists. Add it first before evaluating",
+                    id
+                )))
+            }
+        };
 
         // Apply data and evaluate causal state
         let eval = state.eval_with_data(&data);
 
         // Check if the causal state evaluation returned an error
         if eval.is_err() {
+            self.record_evaluation(id, data, None, ActionOutcome::NotFired);
             return Err(ActionError(format!(
                 "CSM[eval]: Error evaluating causal state: {}",
                 state
@@ -183,17 +242,92 @@ where
         let trigger =
             eval.expect("CSM[eval]: Failed to unwrap evaluation result from causal state}");
 
-        // If the state evaluated to true, fire the associated action.
-        if trigger && action.fire().is_err() {
-            return Err(ActionError(format!(
-                "CSM[eval]: Failed to fire action associated with causal state {}",
-                state
-            )));
-        }
+        self.fire_single_state(id, data, trigger, (state, action))
+    }
+
+    /// Evaluates a single causal state at the index position idx
+    /// and awaits its causal function, i.e. a causaloid created with Causaloid::new_async.
+    /// Returns ActionError if the evaluation failed.
+    pub async fn eval_single_state_async(
+        &self,
+        id: usize,
+        data: NumericalValue,
+    ) -> Result<(), ActionError>
+    where
+        Causaloid<'l, D, S, T, ST, V>: Sync,
+    {
+        // Copy the references out so that the state actions are not borrowed across await.
+        let state_action = self.state_actions.borrow().get(&id).copied();
+
+        let (state, action) = match state_action {
+            Some(state_action) => state_action,
+            None => {
+                return Err(ActionError(format!(
+                    "State {} does not exists. Add it first before evaluating",
+                    id
+                )))
+            }
+        };
+
+        // Apply data and await the causal state
+        let trigger = match state.eval_with_data_async(&data).await {
+            Ok(trigger) => trigger,
+            Err(_) => {
+                self.record_evaluation(id, data, None, ActionOutcome::NotFired);
+                return Err(ActionError(format!(
+                    "CSM[eval]: Error evaluating causal state: {}",
+                    state
+                )));
+            }
+        };
+
+        self.fire_single_state(id, data, trigger, (state, action))
+    }
+
+    // Fires the action of a single evaluated state if the state is active after debouncing
+    // and admitted by the arbitration, and records the evaluation.
+    fn fire_single_state(
+        &self,
+        id: usize,
+        data: NumericalValue,
+        trigger: bool,
+        state_action: (&CausalState<'l, D, S, T, ST, V>, &CausalAction),
+    ) -> Result<(), ActionError> {
+        let (state, action) = state_action;
+
+        let outcome = if !self.debounce_state(state, trigger) {
+            ActionOutcome::NotFired
+        } else if self.arbitrate(&[id]).is_empty() {
+            ActionOutcome::Suppressed
+        } else {
+            match self.fire_action(id, action) {
+                Ok(true) => {
+                    self.action_fired(id);
+                    ActionOutcome::Fired
+                }
+                Ok(false) => ActionOutcome::Failed,
+                Err(_) => {
+                    self.record_evaluation(id, data, Some(trigger), ActionOutcome::Failed);
+                    return Err(ActionError(format!(
+                        "CSM[eval]: Failed to fire action associated with causal state {}",
+                        state
+                    )));
+                }
+            }
+        };
+
+        self.record_evaluation(id, data, Some(trigger), outcome);
 
         Ok(())
     }
 
+    // Starts the cooldown of the state and counts the firing towards its rate limit
+    // once its action actually fired.
+    fn action_fired(&self, id: usize) {
+        self.debounce_fired(id);
+        self.arbiter.borrow_mut().fired(id, Instant::now());
+    }
+
     /// Updates a causal state with a new state at the index position idx.
     /// Returns UpdateError if the update operation failed.
     pub fn update_single_state(
@@ -212,6 +346,9 @@ where
         // Update state/action at the idx position
         self.state_actions.borrow_mut().insert(idx, state_action);
 
+        // Debounce progress starts over for the new state
+        self.reset_debounce(idx);
+
         Ok(())
     }
 }
@@ -233,13 +370,47 @@ where
         + Mul<V, Output = V>,
 {
     /// Evaluates all causal states in the CSM.
-    /// Returns ActionError if the evaluation failed.
+    ///
+    /// States are evaluated in the order returned by evaluation_order so that
+    /// a state is always evaluated after all states it depends on.
+    /// All states evaluate before any action fires so that the arbitration can decide
+    /// between all states that fire in the same cycle. Hence, a state never observes
+    /// the effects of the actions of the states it depends on within the same cycle.
+    /// If a state fails to evaluate, the actions of the states evaluated before it
+    /// still fire and the states after it are not evaluated.
+    /// Failing actions are retried according to the action policy of their state.
+    /// Backoff between retries blocks the calling thread.
+    /// Returns ActionError if the evaluation failed or if the dependencies contain a cycle.
     pub fn eval_all_states(&self) -> Result<(), ActionError> {
-        for (_, (state, action)) in self.state_actions.borrow().iter() {
+        let order = self.evaluation_order()?;
+
+        self.eval_in_order(&order)
+    }
+
+    // Evaluates the given states in the given order with their own data.
+    // All states evaluate before any action fires so that the arbitration
+    // can decide between all states that fire in the same cycle.
+    // An evaluation error stops the evaluation after the actions of
+    // all states evaluated so far have fired.
+    pub(super) fn eval_in_order(&self, order: &[usize]) -> Result<(), ActionError> {
+        let states: Vec<&CausalState<'l, D, S, T, ST, V>> = {
+            let binding = self.state_actions.borrow();
+            order
+                .iter()
+                .map(|id| binding.get(id).expect("State not found").0)
+                .collect()
+        };
+
+        let mut verdicts = Vec::with_capacity(order.len());
+        let mut candidates = Vec::new();
+        for (id, state) in order.iter().copied().zip(states) {
             let eval = state.eval();
 
             // check if the causal state evaluation returned an error
             if eval.is_err() {
+                let fired = self.fire_candidates(&verdicts, &candidates);
+                self.record_evaluation(id, *state.data(), None, ActionOutcome::NotFired);
+                fired?;
                 return Err(ActionError(format!(
                     "CSM[eval]: Error evaluating causal state: {}",
                     state
@@ -249,21 +420,77 @@ where
             // Unpack the bool result
             let trigger =
                 eval.expect("CSM[eval]: Failed to unwrap evaluation result from causal state}");
+            verdicts.push((id, *state.data(), trigger));
 
-            // If the state evaluated to true, fire the associated action.
-            if trigger && action.fire().is_err() {
-                return Err(ActionError(format!(
-                    "CSM[eval]: Failed to fire action associated with causal state {}",
-                    state
-                )));
+            // The state is a candidate for firing if it is active after debouncing.
+            if self.debounce_state(state, trigger) {
+                candidates.push(id);
             }
         }
 
-        Ok(())
+        self.fire_candidates(&verdicts, &candidates)
+    }
+
+    // Fires the actions of all candidates admitted by the arbitration
+    // and records the given verdicts, as (id, input, verdict), with their outcome.
+    pub(super) fn fire_candidates(
+        &self,
+        verdicts: &[(usize, NumericalValue, bool)],
+        candidates: &[usize],
+    ) -> Result<(), ActionError> {
+        let admitted = self.arbitrate(candidates);
+
+        // Copy the references out so that the state actions are not borrowed
+        // while actions fire, which may update the CSM.
+        let state_actions: Vec<_> = {
+            let binding = self.state_actions.borrow();
+            admitted
+                .iter()
+                .map(|id| (*id, *binding.get(id).expect("State not found")))
+                .collect()
+        };
+
+        let mut outcomes: HashMap<usize, ActionOutcome> = candidates
+            .iter()
+            .map(|id| (*id, ActionOutcome::Suppressed))
+            .collect();
+        for id in admitted.iter() {
+            outcomes.insert(*id, ActionOutcome::NotFired);
+        }
+
+        let mut result = Ok(());
+        for (id, (state, action)) in state_actions {
+            match self.fire_action(id, action) {
+                Ok(true) => {
+                    self.action_fired(id);
+                    outcomes.insert(id, ActionOutcome::Fired)
+                }
+                Ok(false) => outcomes.insert(id, ActionOutcome::Failed),
+                Err(_) => {
+                    outcomes.insert(id, ActionOutcome::Failed);
+                    result = Err(ActionError(format!(
+                        "CSM[eval]: Failed to fire action associated with causal state {}",
+                        state
+                    )));
+                    break;
+                }
+            };
+        }
+
+        for (id, input, verdict) in verdicts.iter().copied() {
+            let outcome = outcomes
+                .get(&id)
+                .copied()
+                .unwrap_or(ActionOutcome::NotFired);
+            self.record_evaluation(id, input, Some(verdict), outcome);
+        }
+
+        result
     }
 
     /// Updates all causal state with a new state collection.
     /// Note, this operation erases all previous states in the CSM by generating a new collection.
+    /// Dependencies between states that exist in the new collection are preserved.
     /// Returns UpdateError if the update operation failed.
     pub fn update_all_states(&self, state_actions: &'l CSMStateActions<'l, D, S, T, ST, V>) {
         // Generate a new HashMap from the collection
@@ -272,6 +499,32 @@ where
             state_map.insert(*state.id(), (state, action));
         }
 
+        // Drop all dependencies from or on states that no longer exist.
+        self.dependencies.borrow_mut().retain(|id, depends_on| {
+            depends_on.retain(|dep| state_map.contains_key(dep));
+            state_map.contains_key(id) && !depends_on.is_empty()
+        });
+
+        // Drop all settings and runtime data of states that no longer exist.
+        self.schedule
+            .borrow_mut()
+            .retain(|id| state_map.contains_key(&id));
+        self.policies
+            .borrow_mut()
+            .retain(|id, _| state_map.contains_key(id));
+        self.context_index.borrow_mut().retain(|_, states| {
+            states.retain(|id| state_map.contains_key(id));
+            !states.is_empty()
+        });
+        self.arbiter
+            .borrow_mut()
+            .retain(|id| state_map.contains_key(&id));
+        self.watchdogs
+            .borrow_mut()
+            .retain(|id, _| state_map.contains_key(id));
+        // Debounce progress starts over as states may have been replaced.
+        self.debounce.borrow_mut().clear();
+
         // Replace the existing map with the newly generated one.
         *self.state_actions.borrow_mut() = state_map
     }
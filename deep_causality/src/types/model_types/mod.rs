@@ -1,15 +1,20 @@
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . Marvin Hansen <marvin.hansen@gmail.com> All rights reserved.
 use std::ops::*;
 
-use deep_causality_macros::{Constructor, Getters};
+use deep_causality_macros::Getters;
 
 use crate::prelude::{
-    Assumption, Causaloid, Context, Datable, Identifiable, SpaceTemporal, Spatial, Temporable,
+    Assumable, Assumption, Causaloid, Context, Datable, FingerprintHasher, Fingerprintable,
+    Identifiable, Inference, ModelVersion, SpaceTemporal, Spatial, Temporable,
 };
 
-#[derive(Getters, Constructor, Clone, Debug)]
+pub mod model_card;
+pub mod model_provenance;
+pub mod model_version;
+
+#[derive(Getters, Clone, Debug)]
 pub struct Model<'l, D, S, T, ST, V>
 where
     D: Datable + Clone,
@@ -33,6 +38,67 @@ where
     assumptions: Option<&'l Vec<&'l Assumption>>,
     causaloid: &'l Causaloid<'l, D, S, T, ST, V>,
     context: Option<&'l Context<D, S, T, ST, V>>,
+    version: ModelVersion,
+    // Creation time in milliseconds since the UNIX epoch.
+    created_at: Option<u64>,
+    inferences: Option<&'l Vec<&'l Inference>>,
+}
+
+impl<'l, D, S, T, ST, V> Model<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Creates a new model with the default version, no creation time and no inferences.
+    pub fn new(
+        id: u64,
+        author: &'l str,
+        description: &'l str,
+        assumptions: Option<&'l Vec<&'l Assumption>>,
+        causaloid: &'l Causaloid<'l, D, S, T, ST, V>,
+        context: Option<&'l Context<D, S, T, ST, V>>,
+    ) -> Self {
+        Self {
+            id,
+            author,
+            description,
+            assumptions,
+            causaloid,
+            context,
+            version: ModelVersion::default(),
+            created_at: None,
+            inferences: None,
+        }
+    }
+
+    /// Sets the semantic version of the model.
+    pub fn with_version(mut self, version: ModelVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the creation time of the model in milliseconds since the UNIX epoch.
+    pub fn with_created_at(mut self, created_at: u64) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    /// Sets the inferences the model relies on.
+    pub fn with_inferences(mut self, inferences: &'l Vec<&'l Inference>) -> Self {
+        self.inferences = Some(inferences);
+        self
+    }
 }
 
 impl<'l, D, S, T, ST, V> Identifiable for Model<'l, D, S, T, ST, V>
@@ -55,3 +121,45 @@ where
         self.id
     }
 }
+
+impl<'l, D, S, T, ST, V> Fingerprintable for Model<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Hash,
+    S: Spatial<V> + Clone + Hash,
+    T: Temporable<V> + Clone + Hash,
+    ST: SpaceTemporal<V> + Clone + Hash,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Returns the fingerprint of the model.
+    ///
+    /// The fingerprint covers the id, author, description, the id and description
+    /// of each assumption, and the fingerprints of the causaloid and context.
+    fn fingerprint(&self) -> u64 {
+        let mut state = FingerprintHasher::default();
+
+        state.write_u64(self.id);
+        state.write_text(self.author);
+        state.write_text(self.description);
+
+        if let Some(assumptions) = self.assumptions {
+            state.write_len(assumptions.len());
+            for assumption in assumptions.iter() {
+                state.write_u64(assumption.id());
+                state.write_text(&assumption.description());
+            }
+        }
+
+        state.write_u64(self.causaloid.fingerprint());
+        state.write_option_u64(self.context.map(|context| context.fingerprint()));
+
+        state.finish()
+    }
+}
@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::*;
+
+use crate::prelude::{
+    Assumable, AssumptionCard, CausableGraph, ContextCard, ContextuableGraph, Datable,
+    Fingerprintable, Identifiable, Model, ModelCard, SpaceTemporal, Spatial, Temporable,
+};
+
+const SINGLETON_QUERIES: [&str; 2] = ["verify_single_cause", "explain"];
+
+const COLLECTION_QUERIES: [&str; 2] = ["verify_all_causes", "explain"];
+
+const GRAPH_QUERIES: [&str; 11] = [
+    "verify_all_causes",
+    "explain",
+    "reason_all_causes",
+    "reason_subgraph_from_cause",
+    "reason_shortest_path_between_causes",
+    "reason_single_cause",
+    "explain_all_causes",
+    "explain_subgraph_from_cause",
+    "explain_shortest_path_between_causes",
+    "rank_root_causes",
+    "shapley_attribution",
+];
+
+impl<'l, D, S, T, ST, V> Model<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Hash,
+    S: Spatial<V> + Clone + Hash,
+    T: Temporable<V> + Clone + Hash,
+    ST: SpaceTemporal<V> + Clone + Hash,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Generates a model card documenting this model.
+    ///
+    /// The model card contains the structure of the causaloid, the size of the context,
+    /// the assumptions with the result of their last verification, the supported queries,
+    /// and the fingerprints of model and context.
+    pub fn generate_model_card(&self) -> ModelCard {
+        let causaloid = self.causaloid;
+
+        let (causal_structure, number_causaloids, number_causal_edges, queries) =
+            if let Some(graph) = causaloid.causal_graph() {
+                (
+                    "Graph",
+                    graph.number_nodes(),
+                    graph.number_edges(),
+                    GRAPH_QUERIES.as_slice(),
+                )
+            } else if let Some(coll) = causaloid.causal_collection() {
+                ("Collection", coll.len(), 0, COLLECTION_QUERIES.as_slice())
+            } else {
+                ("Singleton", 1, 0, SINGLETON_QUERIES.as_slice())
+            };
+
+        let context = self.context.map(|context| {
+            ContextCard::new(
+                context.id(),
+                context.name().to_string(),
+                context.node_count(),
+                context.edge_count(),
+                context.fingerprint(),
+            )
+        });
+
+        ModelCard {
+            model_id: self.id,
+            author: self.author.to_string(),
+            description: self.description.to_string(),
+            fingerprint: self.fingerprint(),
+            causaloid_id: causaloid.id(),
+            causaloid_description: causaloid.description().to_string(),
+            causal_structure: causal_structure.to_string(),
+            number_causaloids,
+            number_causal_edges,
+            context,
+            assumptions: self.assumption_cards(),
+            supported_queries: queries.iter().map(|q| q.to_string()).collect(),
+        }
+    }
+
+    /// Returns a summary of each assumption with the result of its last verification.
+    pub(crate) fn assumption_cards(&self) -> Vec<AssumptionCard> {
+        self.assumptions
+            .map(|assumptions| {
+                assumptions
+                    .iter()
+                    .map(|a| {
+                        AssumptionCard::new(
+                            a.id(),
+                            a.description(),
+                            a.assumption_tested(),
+                            a.assumption_valid(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::{AssumptionCard, ContextCard, ModelCard};
+use crate::utils::json_utils::quote_json;
+
+impl ModelCard {
+    /// Serializes the model card into a JSON object.
+    ///
+    /// Fields are written in declaration order so that the output of
+    /// identical model cards is identical.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{");
+
+        write_field(&mut json, "model_id", &self.model_id().to_string());
+        write_field(&mut json, "author", &quote_json(self.author()));
+        write_field(&mut json, "description", &quote_json(self.description()));
+        write_field(&mut json, "fingerprint", &self.fingerprint().to_string());
+        write_field(&mut json, "causaloid_id", &self.causaloid_id().to_string());
+        write_field(
+            &mut json,
+            "causaloid_description",
+            &quote_json(self.causaloid_description()),
+        );
+        write_field(
+            &mut json,
+            "causal_structure",
+            &quote_json(self.causal_structure()),
+        );
+        write_field(
+            &mut json,
+            "number_causaloids",
+            &self.number_causaloids().to_string(),
+        );
+        write_field(
+            &mut json,
+            "number_causal_edges",
+            &self.number_causal_edges().to_string(),
+        );
+
+        let context = match self.context() {
+            Some(context) => context_json(context),
+            None => "null".to_string(),
+        };
+        write_field(&mut json, "context", &context);
+
+        let assumptions: Vec<String> = self.assumptions().iter().map(assumption_json).collect();
+        write_field(
+            &mut json,
+            "assumptions",
+            &format!("[{}]", assumptions.join(",")),
+        );
+
+        let percent_valid = match self.percent_assumptions_valid() {
+            Some(value) => value.to_string(),
+            None => "null".to_string(),
+        };
+        write_field(&mut json, "percent_assumptions_valid", &percent_valid);
+
+        let queries: Vec<String> = self
+            .supported_queries()
+            .iter()
+            .map(|q| quote_json(q))
+            .collect();
+        write_field(
+            &mut json,
+            "supported_queries",
+            &format!("[{}]", queries.join(",")),
+        );
+
+        json.push('}');
+        json
+    }
+}
+
+fn context_json(context: &ContextCard) -> String {
+    format!(
+        "{{\"id\":{},\"name\":{},\"number_nodes\":{},\"number_edges\":{},\"fingerprint\":{}}}",
+        context.id(),
+        quote_json(context.name()),
+        context.number_nodes(),
+        context.number_edges(),
+        context.fingerprint()
+    )
+}
+
+pub(crate) fn assumption_json(assumption: &AssumptionCard) -> String {
+    format!(
+        "{{\"id\":{},\"description\":{},\"tested\":{},\"valid\":{}}}",
+        assumption.id(),
+        quote_json(assumption.description()),
+        assumption.tested(),
+        assumption.valid()
+    )
+}
+
+pub(crate) fn write_field(json: &mut String, key: &str, value: &str) {
+    if json.len() > 1 {
+        json.push(',');
+    }
+    json.push_str(&quote_json(key));
+    json.push(':');
+    json.push_str(value);
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::Write;
+
+use crate::prelude::ModelCard;
+
+impl ModelCard {
+    /// Renders the model card as a markdown document.
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::new();
+
+        let _ = writeln!(md, "# Model Card: {}", self.description());
+        let _ = writeln!(md);
+        let _ = writeln!(md, "| Field | Value |");
+        let _ = writeln!(md, "|---|---|");
+        let _ = writeln!(md, "| Model ID | {} |", self.model_id());
+        let _ = writeln!(md, "| Author | {} |", self.author());
+        let _ = writeln!(md, "| Fingerprint | {:016x} |", self.fingerprint());
+
+        let _ = writeln!(md);
+        let _ = writeln!(md, "## Causal Structure");
+        let _ = writeln!(md);
+        let _ = writeln!(md, "| Field | Value |");
+        let _ = writeln!(md, "|---|---|");
+        let _ = writeln!(md, "| Causaloid ID | {} |", self.causaloid_id());
+        let _ = writeln!(md, "| Description | {} |", self.causaloid_description());
+        let _ = writeln!(md, "| Structure | {} |", self.causal_structure());
+        let _ = writeln!(md, "| Causaloids | {} |", self.number_causaloids());
+        let _ = writeln!(md, "| Causal edges | {} |", self.number_causal_edges());
+
+        let _ = writeln!(md);
+        let _ = writeln!(md, "## Context");
+        let _ = writeln!(md);
+        match self.context() {
+            Some(context) => {
+                let _ = writeln!(md, "| Field | Value |");
+                let _ = writeln!(md, "|---|---|");
+                let _ = writeln!(md, "| Context ID | {} |", context.id());
+                let _ = writeln!(md, "| Name | {} |", context.name());
+                let _ = writeln!(md, "| Nodes | {} |", context.number_nodes());
+                let _ = writeln!(md, "| Edges | {} |", context.number_edges());
+                let _ = writeln!(md, "| Fingerprint | {:016x} |", context.fingerprint());
+            }
+            None => {
+                let _ = writeln!(md, "No context.");
+            }
+        }
+
+        let _ = writeln!(md);
+        let _ = writeln!(md, "## Assumptions");
+        let _ = writeln!(md);
+        if self.assumptions().is_empty() {
+            let _ = writeln!(md, "No assumptions.");
+        } else {
+            let _ = writeln!(md, "| ID | Description | Tested | Valid |");
+            let _ = writeln!(md, "|---|---|---|---|");
+            for a in self.assumptions() {
+                let _ = writeln!(
+                    md,
+                    "| {} | {} | {} | {} |",
+                    a.id(),
+                    a.description(),
+                    a.tested(),
+                    a.valid()
+                );
+            }
+            let _ = writeln!(md);
+            match self.percent_assumptions_valid() {
+                Some(percent) => {
+                    let _ = writeln!(
+                        md,
+                        "Valid: {} of {} tested assumptions ({:.1}%).",
+                        self.number_assumptions_valid(),
+                        self.number_assumptions_tested(),
+                        percent
+                    );
+                }
+                None => {
+                    let _ = writeln!(md, "No assumption has been tested.");
+                }
+            }
+        }
+
+        let _ = writeln!(md);
+        let _ = writeln!(md, "## Supported Queries");
+        let _ = writeln!(md);
+        for query in self.supported_queries() {
+            let _ = writeln!(md, "* {}", query);
+        }
+
+        md
+    }
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::{DescriptionValue, IdentificationValue, NumericalValue};
+
+mod generate;
+pub(crate) mod json;
+mod markdown;
+
+/// Summary of an assumption and its last verification.
+#[derive(Getters, Constructor, Debug, Clone, PartialEq)]
+pub struct AssumptionCard {
+    id: IdentificationValue,
+    description: DescriptionValue,
+    tested: bool,
+    valid: bool,
+}
+
+/// Summary of a context graph.
+#[derive(Getters, Constructor, Debug, Clone, PartialEq)]
+pub struct ContextCard {
+    id: IdentificationValue,
+    name: String,
+    number_nodes: usize,
+    number_edges: usize,
+    fingerprint: u64,
+}
+
+/// Standardized documentation of a causal model.
+///
+/// A model card summarizes the structure of the causal model, its context,
+/// its assumptions with their last verification, the queries the model supports,
+/// and the fingerprint that identifies the documented model.
+///
+/// A model card is generated by Model::generate_model_card and
+/// serializes to JSON via to_json and to markdown via to_markdown.
+#[derive(Getters, Debug, Clone, PartialEq)]
+pub struct ModelCard {
+    model_id: IdentificationValue,
+    author: String,
+    description: String,
+    fingerprint: u64,
+    causaloid_id: IdentificationValue,
+    causaloid_description: String,
+    causal_structure: String,
+    number_causaloids: usize,
+    number_causal_edges: usize,
+    context: Option<ContextCard>,
+    assumptions: Vec<AssumptionCard>,
+    supported_queries: Vec<String>,
+}
+
+impl ModelCard {
+    /// Returns the number of assumptions that have been verified.
+    pub fn number_assumptions_tested(&self) -> usize {
+        self.assumptions.iter().filter(|a| a.tested).count()
+    }
+
+    /// Returns the number of assumptions that have been verified as valid.
+    pub fn number_assumptions_valid(&self) -> usize {
+        self.assumptions
+            .iter()
+            .filter(|a| a.tested && a.valid)
+            .count()
+    }
+
+    /// Returns the percentage of tested assumptions that are valid between 0.0 and 100.0,
+    /// as Assumable::percent_assumption_valid does, or None if no assumption has been tested yet.
+    pub fn percent_assumptions_valid(&self) -> Option<NumericalValue> {
+        let tested = self.number_assumptions_tested();
+        if tested == 0 {
+            return None;
+        }
+        Some((self.number_assumptions_valid() as NumericalValue / tested as NumericalValue) * 100.0)
+    }
+}
@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::*;
+
+use crate::prelude::{
+    Datable, Fingerprintable, Identifiable, Model, ModelProvenance, SpaceTemporal, Spatial,
+    Temporable,
+};
+
+impl<'l, D, S, T, ST, V> Model<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone + Hash,
+    S: Spatial<V> + Clone + Hash,
+    T: Temporable<V> + Clone + Hash,
+    ST: SpaceTemporal<V> + Clone + Hash,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>,
+{
+    /// Returns the content hash of the causaloid including its entire causal graph
+    /// or collection.
+    pub fn causaloid_hash(&self) -> u64 {
+        self.causaloid.fingerprint()
+    }
+
+    /// Generates the provenance report of this model.
+    ///
+    /// The report contains the version and creation metadata of the model,
+    /// the version of this library, the fingerprints of model, causaloid and context,
+    /// and the assumptions and inferences the model relies on.
+    pub fn provenance(&self) -> ModelProvenance {
+        ModelProvenance {
+            model_id: self.id,
+            version: self.version,
+            author: self.author.to_string(),
+            description: self.description.to_string(),
+            created_at: self.created_at,
+            library_version: env!("CARGO_PKG_VERSION").to_string(),
+            fingerprint: self.fingerprint(),
+            causaloid_id: self.causaloid.id(),
+            causaloid_hash: self.causaloid_hash(),
+            context_hash: self.context.map(|context| context.fingerprint()),
+            assumptions: self.assumption_cards(),
+            inferences: self
+                .inferences
+                .map(|inferences| inferences.iter().map(|i| (*i).clone()).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns true if the provenance report was generated by a model with the same id,
+    /// version, and content as this model.
+    pub fn verify_provenance(&self, provenance: &ModelProvenance) -> bool {
+        provenance.model_id == self.id
+            && provenance.version == self.version
+            && provenance.fingerprint == self.fingerprint()
+            && provenance.causaloid_hash == self.causaloid_hash()
+            && provenance.context_hash == self.context.map(|context| context.fingerprint())
+    }
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::{Identifiable, Inferable, Inference, ModelProvenance};
+use crate::types::model_types::model_card::json::{assumption_json, write_field};
+use crate::utils::json_utils::{number_json, quote_json};
+
+impl ModelProvenance {
+    /// Serializes the provenance report into a JSON object.
+    ///
+    /// Fields are written in declaration order so that the output of
+    /// identical reports is identical.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{");
+
+        write_field(&mut json, "model_id", &self.model_id().to_string());
+        write_field(
+            &mut json,
+            "version",
+            &quote_json(&self.version().to_string()),
+        );
+        write_field(&mut json, "author", &quote_json(self.author()));
+        write_field(&mut json, "description", &quote_json(self.description()));
+
+        let created_at = match self.created_at() {
+            Some(created_at) => created_at.to_string(),
+            None => "null".to_string(),
+        };
+        write_field(&mut json, "created_at", &created_at);
+        write_field(
+            &mut json,
+            "library_version",
+            &quote_json(self.library_version()),
+        );
+        write_field(&mut json, "fingerprint", &self.fingerprint().to_string());
+        write_field(&mut json, "causaloid_id", &self.causaloid_id().to_string());
+        write_field(
+            &mut json,
+            "causaloid_hash",
+            &self.causaloid_hash().to_string(),
+        );
+
+        let context_hash = match self.context_hash() {
+            Some(hash) => hash.to_string(),
+            None => "null".to_string(),
+        };
+        write_field(&mut json, "context_hash", &context_hash);
+
+        let assumptions: Vec<String> = self.assumptions().iter().map(assumption_json).collect();
+        write_field(
+            &mut json,
+            "assumptions",
+            &format!("[{}]", assumptions.join(",")),
+        );
+
+        let inferences: Vec<String> = self.inferences().iter().map(inference_json).collect();
+        write_field(
+            &mut json,
+            "inferences",
+            &format!("[{}]", inferences.join(",")),
+        );
+
+        json.push('}');
+        json
+    }
+}
+
+fn inference_json(inference: &Inference) -> String {
+    format!(
+        "{{\"id\":{},\"question\":{},\"observation\":{},\"threshold\":{},\"effect\":{},\"target\":{}}}",
+        inference.id(),
+        quote_json(&inference.question()),
+        number_json(inference.observation()),
+        number_json(inference.threshold()),
+        number_json(inference.effect()),
+        number_json(inference.target())
+    )
+}
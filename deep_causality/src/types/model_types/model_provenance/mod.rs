@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::Getters;
+
+use crate::prelude::{AssumptionCard, IdentificationValue, Inference, ModelVersion};
+
+mod generate;
+mod json;
+
+/// Provenance report of a causal model.
+///
+/// The report identifies the exact model that produced a decision by its version,
+/// its creation metadata, the content hashes of its causaloid and context,
+/// and the assumptions and inferences the model relies on.
+///
+/// A provenance report is generated by Model::provenance, verified against a model
+/// by Model::verify_provenance, and serializes to JSON via to_json.
+#[derive(Getters, Debug, Clone, PartialEq)]
+pub struct ModelProvenance {
+    model_id: IdentificationValue,
+    version: ModelVersion,
+    author: String,
+    description: String,
+    created_at: Option<u64>,
+    library_version: String,
+    fingerprint: u64,
+    causaloid_id: IdentificationValue,
+    causaloid_hash: u64,
+    context_hash: Option<u64>,
+    assumptions: Vec<AssumptionCard>,
+    inferences: Vec<Inference>,
+}
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::errors::BuildError;
+
+/// Semantic version of a causal model.
+///
+/// Versions are ordered by major, minor, and patch number.
+/// A new model without explicit version has version 0.1.0.
+#[derive(Getters, Constructor, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ModelVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl ModelVersion {
+    /// Returns true if a model of the other version can replace a model of this version
+    /// without breaking changes, i.e. both share the same major version,
+    /// or the same minor version while the major version is zero.
+    pub fn is_compatible_with(&self, other: &ModelVersion) -> bool {
+        if self.major == 0 {
+            self.major == other.major && self.minor == other.minor
+        } else {
+            self.major == other.major
+        }
+    }
+}
+
+impl Default for ModelVersion {
+    fn default() -> Self {
+        Self::new(0, 1, 0)
+    }
+}
+
+impl FromStr for ModelVersion {
+    type Err = BuildError;
+
+    /// Parses a version of the form major.minor.patch.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.trim().split('.').collect();
+        if parts.len() != 3 {
+            return Err(BuildError(format!(
+                "Invalid model version {}: expected major.minor.patch",
+                s
+            )));
+        }
+
+        let mut numbers = [0u32; 3];
+        for (number, part) in numbers.iter_mut().zip(parts) {
+            *number = part.parse().map_err(|_| {
+                BuildError(format!(
+                    "Invalid model version {}: {} is not a number",
+                    s, part
+                ))
+            })?;
+        }
+
+        Ok(Self::new(numbers[0], numbers[1], numbers[2]))
+    }
+}
+
+impl Display for ModelVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
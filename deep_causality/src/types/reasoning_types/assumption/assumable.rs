@@ -25,10 +25,9 @@ impl Assumable for Assumption {
         let mut guard_tested = self.assumption_tested.write().unwrap();
         *guard_tested = true;
 
-        if res {
-            let mut guard_valid = self.assumption_valid.write().unwrap();
-            *guard_valid = true;
-        }
+        // Reflect the latest verification so that re-verified assumptions can become invalid.
+        let mut guard_valid = self.assumption_valid.write().unwrap();
+        *guard_valid = res;
         res
     }
 }
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::{
+    Assumable, Assumption, DescriptionValue, Identifiable, IdentificationValue, NumericalValue,
+};
+use crate::protocols::causable_graph::graph_reasoning_utils;
+
+mod reasoning;
+
+/// Evidence against which a monitored assumption is verified.
+///
+/// Graph: the assumption is verified against all data of an evaluation.
+/// Causaloid: the assumption is verified against the observation
+/// of the causaloid at the given index only.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum AssumptionScope {
+    Graph,
+    Causaloid(usize),
+}
+
+impl Display for AssumptionScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// An assumption that did not hold for the evidence of an evaluation.
+#[derive(Getters, Constructor, Debug, Clone, PartialEq)]
+pub struct AssumptionViolation {
+    assumption_id: IdentificationValue,
+    description: DescriptionValue,
+    scope: AssumptionScope,
+    /// Sequence number of the evaluation, starting at 1.
+    evaluation: u64,
+}
+
+impl Display for AssumptionViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AssumptionViolation: id: {}, scope: {}, evaluation: {}, description: {}",
+            self.assumption_id, self.scope, self.evaluation, self.description
+        )
+    }
+}
+
+/// Re-verifies assumptions against the evidence of every graph evaluation.
+///
+/// Assumptions are attached either to the entire graph or to a single causaloid.
+/// Each evaluation through the monitor first verifies all attached assumptions
+/// against the data of the evaluation and records every violation.
+/// If halt_on_violation is set, a violation aborts the evaluation with an error.
+#[derive(Debug, Clone, Default)]
+pub struct AssumptionMonitor<'l> {
+    assumptions: Vec<(AssumptionScope, &'l Assumption)>,
+    violations: Vec<AssumptionViolation>,
+    number_evaluations: u64,
+    halt_on_violation: bool,
+}
+
+impl<'l> AssumptionMonitor<'l> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Aborts evaluations with an error if any assumption is violated.
+    pub fn with_halt_on_violation(mut self, halt_on_violation: bool) -> Self {
+        self.halt_on_violation = halt_on_violation;
+        self
+    }
+
+    /// Attaches the assumption to the entire graph.
+    pub fn attach(&mut self, assumption: &'l Assumption) {
+        self.assumptions.push((AssumptionScope::Graph, assumption));
+    }
+
+    /// Attaches the assumption to the causaloid at the given index.
+    pub fn attach_to_causaloid(&mut self, index: usize, assumption: &'l Assumption) {
+        self.assumptions
+            .push((AssumptionScope::Causaloid(index), assumption));
+    }
+
+    /// Detaches all attachments of the assumption with the given id.
+    ///
+    /// Returns the number of detached attachments.
+    pub fn detach(&mut self, assumption_id: IdentificationValue) -> usize {
+        let before = self.assumptions.len();
+        self.assumptions.retain(|(_, a)| a.id() != assumption_id);
+        before - self.assumptions.len()
+    }
+
+    pub fn number_assumptions(&self) -> usize {
+        self.assumptions.len()
+    }
+
+    pub fn number_evaluations(&self) -> u64 {
+        self.number_evaluations
+    }
+
+    pub fn halt_on_violation(&self) -> bool {
+        self.halt_on_violation
+    }
+
+    /// Verifies all attached assumptions against the data as a new evaluation.
+    ///
+    /// Assumptions attached to a causaloid without data in this evaluation are skipped.
+    /// Returns the violations of this evaluation, which are also recorded.
+    pub fn verify(
+        &mut self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Vec<AssumptionViolation> {
+        self.number_evaluations += 1;
+
+        let mut violations = Vec::new();
+        for (scope, assumption) in self.assumptions.iter() {
+            let valid = match scope {
+                AssumptionScope::Graph => assumption.verify_assumption(data),
+                AssumptionScope::Causaloid(index) => {
+                    match graph_reasoning_utils::try_get_obs(*index as u64, data, &data_index) {
+                        Some(obs) => assumption.verify_assumption(&[obs]),
+                        None => continue,
+                    }
+                }
+            };
+
+            if !valid {
+                violations.push(AssumptionViolation::new(
+                    assumption.id(),
+                    assumption.description(),
+                    *scope,
+                    self.number_evaluations,
+                ));
+            }
+        }
+
+        self.violations.extend(violations.iter().cloned());
+        violations
+    }
+
+    /// Returns all recorded violations in the order detected.
+    pub fn violations(&self) -> &[AssumptionViolation] {
+        &self.violations
+    }
+
+    /// Returns all recorded violations of the assumption with the given id.
+    pub fn violations_of(&self, assumption_id: IdentificationValue) -> Vec<&AssumptionViolation> {
+        self.violations
+            .iter()
+            .filter(|v| v.assumption_id == assumption_id)
+            .collect()
+    }
+
+    pub fn clear_violations(&mut self) {
+        self.violations.clear();
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use crate::errors::{CausalityGraphError, ReasoningError, ReasoningErrorKind};
+use crate::prelude::{
+    AssumptionMonitor, AssumptionViolation, Causable, CausableGraphReasoning, IdentificationValue,
+    NumericalValue, ReasoningPolicy, ReasoningReport,
+};
+
+impl<'l> AssumptionMonitor<'l> {
+    /// Verifies all attached assumptions against the data and then reasons over the entire graph.
+    ///
+    /// Returns the result of reason_all_causes, or a CausalityGraphError
+    /// if halt_on_violation is set and any assumption is violated.
+    pub fn reason_all_causes<G, T>(
+        &mut self,
+        graph: &G,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<bool, CausalityGraphError>
+    where
+        G: CausableGraphReasoning<T>,
+        T: Causable + PartialEq,
+    {
+        self.verify_or_halt(data, data_index)?;
+        graph.reason_all_causes(data, data_index)
+    }
+
+    /// Verifies all attached assumptions against the data and then reasons over
+    /// the entire graph with the given policy.
+    ///
+    /// Returns the result of reason_all_causes_with_policy, or a ReasoningError of kind
+    /// InvalidData if halt_on_violation is set and any assumption is violated.
+    pub fn reason_all_causes_with_policy<G, T>(
+        &mut self,
+        graph: &G,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+        policy: ReasoningPolicy,
+    ) -> Result<ReasoningReport, ReasoningError>
+    where
+        G: CausableGraphReasoning<T>,
+        T: Causable + PartialEq,
+    {
+        self.verify_or_halt(data, data_index)
+            .map_err(|e| ReasoningError::new(ReasoningErrorKind::InvalidData, e.0))?;
+        graph.reason_all_causes_with_policy(data, data_index, policy)
+    }
+
+    fn verify_or_halt(
+        &mut self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<(), CausalityGraphError> {
+        let violations = self.verify(data, data_index);
+
+        if self.halt_on_violation() && !violations.is_empty() {
+            let ids: Vec<String> = violations
+                .iter()
+                .map(AssumptionViolation::assumption_id)
+                .map(|id| id.to_string())
+                .collect();
+            return Err(CausalityGraphError(format!(
+                "Evaluation halted due to violated assumptions: {}",
+                ids.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::Attribution;
+
+impl Display for Attribution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Attribution: index: {}, causaloid_id: {}, value: {}",
+            self.index, self.causaloid_id, self.value
+        )
+    }
+}
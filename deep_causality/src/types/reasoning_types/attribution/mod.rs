@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::{IdentificationValue, NumericalValue};
+
+mod display;
+
+/// Contribution of an input causaloid to the effect of a target causaloid.
+///
+/// The value is the share of the change of the target effect between the
+/// baseline and the observed data that is attributed to this input.
+/// The values of all inputs sum up to that change.
+#[derive(Getters, Constructor, Debug, Copy, Clone, PartialEq)]
+pub struct Attribution {
+    index: usize,
+    causaloid_id: IdentificationValue,
+    value: NumericalValue,
+}
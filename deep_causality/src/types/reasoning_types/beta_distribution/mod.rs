@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+
+use crate::errors::BuildError;
+use crate::prelude::NumericalValue;
+
+/// Beta distribution over the probability that an inference holds.
+///
+/// The beta distribution is the conjugate prior of a binary outcome, therefore
+/// the posterior after observing an inference that holds or does not hold is again
+/// a beta distribution with alpha or beta incremented by one.
+/// Alpha and beta can be read as pseudo counts of successes and failures.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BetaDistribution {
+    alpha: NumericalValue,
+    beta: NumericalValue,
+}
+
+impl BetaDistribution {
+    /// Creates a new beta distribution.
+    ///
+    /// Returns BuildError if alpha or beta is not finite and positive.
+    pub fn new(alpha: NumericalValue, beta: NumericalValue) -> Result<Self, BuildError> {
+        if !(alpha.is_finite() && alpha > 0.0 && beta.is_finite() && beta > 0.0) {
+            return Err(BuildError(format!(
+                "Alpha and beta must be finite and positive but were {} and {}",
+                alpha, beta
+            )));
+        }
+
+        Ok(Self { alpha, beta })
+    }
+
+    /// Creates the uniform distribution Beta(1, 1),
+    /// i.e. the uninformed prior.
+    pub fn uniform() -> Self {
+        Self {
+            alpha: 1.0,
+            beta: 1.0,
+        }
+    }
+
+    pub fn alpha(&self) -> NumericalValue {
+        self.alpha
+    }
+
+    pub fn beta(&self) -> NumericalValue {
+        self.beta
+    }
+
+    /// Returns the expected probability that the inference holds.
+    pub fn mean(&self) -> NumericalValue {
+        self.alpha / (self.alpha + self.beta)
+    }
+
+    pub fn variance(&self) -> NumericalValue {
+        let sum = self.alpha + self.beta;
+        (self.alpha * self.beta) / (sum * sum * (sum + 1.0))
+    }
+
+    /// Returns the most likely probability that the inference holds,
+    /// or None if the distribution has no single mode, i.e. alpha or beta is at most one.
+    pub fn mode(&self) -> Option<NumericalValue> {
+        if self.alpha > 1.0 && self.beta > 1.0 {
+            Some((self.alpha - 1.0) / (self.alpha + self.beta - 2.0))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the sum of alpha and beta, i.e. the weight of the evidence.
+    pub fn concentration(&self) -> NumericalValue {
+        self.alpha + self.beta
+    }
+
+    /// Updates the distribution with a single observed outcome.
+    pub fn update(&mut self, holds: bool) {
+        if holds {
+            self.alpha += 1.0;
+        } else {
+            self.beta += 1.0;
+        }
+    }
+
+    /// Returns the posterior after observing the given number of outcomes
+    /// that hold and do not hold.
+    pub fn posterior(&self, holds: usize, fails: usize) -> Self {
+        Self {
+            alpha: self.alpha + holds as NumericalValue,
+            beta: self.beta + fails as NumericalValue,
+        }
+    }
+}
+
+impl Default for BetaDistribution {
+    fn default() -> Self {
+        Self::uniform()
+    }
+}
+
+impl Display for BetaDistribution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Beta({}, {})", self.alpha, self.beta)
+    }
+}
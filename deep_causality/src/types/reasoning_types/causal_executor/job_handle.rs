@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::sync::mpsc::{Receiver, RecvTimeoutError, TryRecvError};
+use std::time::Duration;
+
+/// Handle to the result of a job submitted to a CausalExecutor.
+///
+/// The result is delivered exactly once. If the job panicked,
+/// no result is delivered and all methods return None.
+#[derive(Debug)]
+pub struct JobHandle<R> {
+    receiver: Receiver<R>,
+}
+
+impl<R> JobHandle<R> {
+    pub(super) fn new(receiver: Receiver<R>) -> Self {
+        Self { receiver }
+    }
+
+    /// Blocks until the job has completed and returns its result,
+    /// or None if the job panicked.
+    pub fn wait(self) -> Option<R> {
+        self.receiver.recv().ok()
+    }
+
+    /// Blocks until the job has completed or the timeout has elapsed.
+    ///
+    /// Returns the result, or None if the timeout has elapsed
+    /// or the job panicked.
+    pub fn wait_timeout(&self, timeout: Duration) -> Option<R> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(result) => Some(result),
+            Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => None,
+        }
+    }
+
+    /// Returns the result if the job has completed without blocking,
+    /// or None if the job is still pending or panicked.
+    pub fn try_wait(&self) -> Option<R> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) | Err(TryRecvError::Disconnected) => None,
+        }
+    }
+}
@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::{HashMap, VecDeque};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::channel;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+
+use crate::errors::{BuildError, ReasoningError};
+use crate::prelude::{
+    Causable, CausableGraphReasoning, IdentificationValue, NumericalValue, ReasoningPolicy,
+    ReasoningReport,
+};
+
+pub use job_handle::JobHandle;
+
+mod job_handle;
+
+// Runs the work of a job and returns the delivery of its result.
+type Job = Box<dyn FnOnce() -> Delivery + Send + 'static>;
+type Delivery = Box<dyn FnOnce() + Send + 'static>;
+
+struct QueuedJob {
+    model_id: IdentificationValue,
+    job: Job,
+}
+
+#[derive(Default)]
+struct ExecutorState {
+    queue: VecDeque<QueuedJob>,
+    running: HashMap<IdentificationValue, usize>,
+    limits: HashMap<IdentificationValue, usize>,
+    shutdown: bool,
+}
+
+impl ExecutorState {
+    fn has_capacity(&self, model_id: IdentificationValue) -> bool {
+        match self.limits.get(&model_id) {
+            Some(limit) => self.running.get(&model_id).copied().unwrap_or(0) < *limit,
+            None => true,
+        }
+    }
+
+    // Takes the oldest queued job whose model has capacity.
+    fn take_next(&mut self) -> Option<QueuedJob> {
+        let position = self
+            .queue
+            .iter()
+            .position(|queued| self.has_capacity(queued.model_id))?;
+        let queued = self.queue.remove(position)?;
+        *self.running.entry(queued.model_id).or_insert(0) += 1;
+        Some(queued)
+    }
+
+    fn finish(&mut self, model_id: IdentificationValue) {
+        if let Some(running) = self.running.get_mut(&model_id) {
+            *running -= 1;
+            if *running == 0 {
+                self.running.remove(&model_id);
+            }
+        }
+    }
+}
+
+type SharedState = Arc<(Mutex<ExecutorState>, Condvar)>;
+
+/// Executes causal evaluation jobs of many models on a fixed set of worker threads.
+///
+/// Each job belongs to a model, identified by its id. Jobs run in submission order,
+/// except that a job is deferred while its model already runs as many jobs as its
+/// concurrency limit allows; jobs of other models run in the meantime.
+/// Models without a limit are only bounded by the number of workers.
+///
+/// Submitting a job returns a JobHandle to wait for its result. A panicking job
+/// does not take down its worker; its handle returns no result instead.
+/// Dropping the executor or calling shutdown runs all queued jobs
+/// and then joins all workers.
+pub struct CausalExecutor {
+    state: SharedState,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl CausalExecutor {
+    /// Creates a new executor with the given number of worker threads.
+    ///
+    /// Returns BuildError if number_workers is zero or a worker cannot be spawned.
+    pub fn new(number_workers: usize) -> Result<Self, BuildError> {
+        if number_workers == 0 {
+            return Err(BuildError(
+                "Number of workers must be greater than zero".into(),
+            ));
+        }
+
+        let state: SharedState = Arc::new((Mutex::new(ExecutorState::default()), Condvar::new()));
+
+        let mut workers = Vec::with_capacity(number_workers);
+        for i in 0..number_workers {
+            let state = Arc::clone(&state);
+            let worker = thread::Builder::new()
+                .name(format!("causal-executor-{}", i))
+                .spawn(move || run_worker(state))
+                .map_err(|e| BuildError(format!("Failed to spawn worker: {}", e)))?;
+            workers.push(worker);
+        }
+
+        Ok(Self { state, workers })
+    }
+
+    pub fn number_workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Limits the number of jobs of the model that run at the same time.
+    ///
+    /// Returns BuildError if the limit is zero.
+    pub fn set_model_limit(
+        &self,
+        model_id: IdentificationValue,
+        limit: usize,
+    ) -> Result<(), BuildError> {
+        if limit == 0 {
+            return Err(BuildError(format!(
+                "Concurrency limit of model {} must be greater than zero",
+                model_id
+            )));
+        }
+
+        let (lock, cvar) = &*self.state;
+        lock.lock().unwrap().limits.insert(model_id, limit);
+        cvar.notify_all();
+        Ok(())
+    }
+
+    /// Removes the concurrency limit of the model.
+    pub fn remove_model_limit(&self, model_id: IdentificationValue) {
+        let (lock, cvar) = &*self.state;
+        lock.lock().unwrap().limits.remove(&model_id);
+        cvar.notify_all();
+    }
+
+    pub fn model_limit(&self, model_id: IdentificationValue) -> Option<usize> {
+        let (lock, _) = &*self.state;
+        lock.lock().unwrap().limits.get(&model_id).copied()
+    }
+
+    /// Returns the number of jobs waiting for a worker.
+    pub fn number_queued(&self) -> usize {
+        let (lock, _) = &*self.state;
+        lock.lock().unwrap().queue.len()
+    }
+
+    /// Returns the number of jobs of the model currently running.
+    pub fn number_running(&self, model_id: IdentificationValue) -> usize {
+        let (lock, _) = &*self.state;
+        lock.lock()
+            .unwrap()
+            .running
+            .get(&model_id)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Submits a job of the model and returns a handle to its result.
+    pub fn submit<F, R>(&self, model_id: IdentificationValue, job: F) -> JobHandle<R>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (sender, receiver) = channel();
+        let job: Job = Box::new(move || {
+            let result = job();
+            Box::new(move || {
+                // The receiver may have been dropped if nobody waits for the result.
+                let _ = sender.send(result);
+            })
+        });
+
+        let (lock, cvar) = &*self.state;
+        lock.lock()
+            .unwrap()
+            .queue
+            .push_back(QueuedJob { model_id, job });
+        cvar.notify_one();
+
+        JobHandle::new(receiver)
+    }
+
+    /// Submits reasoning over the entire graph of the model with the given policy
+    /// and returns a handle to the reasoning report.
+    pub fn submit_reasoning<G, T>(
+        &self,
+        model_id: IdentificationValue,
+        graph: Arc<G>,
+        data: Vec<NumericalValue>,
+        policy: ReasoningPolicy,
+    ) -> JobHandle<Result<ReasoningReport, ReasoningError>>
+    where
+        G: CausableGraphReasoning<T> + Send + Sync + 'static,
+        T: Causable + PartialEq,
+    {
+        self.submit(model_id, move || {
+            graph.reason_all_causes_with_policy(&data, None, policy)
+        })
+    }
+
+    /// Runs all queued jobs and then joins all workers.
+    pub fn shutdown(mut self) {
+        self.join_workers();
+    }
+
+    fn join_workers(&mut self) {
+        let (lock, cvar) = &*self.state;
+        lock.lock().unwrap().shutdown = true;
+        cvar.notify_all();
+
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Drop for CausalExecutor {
+    fn drop(&mut self) {
+        self.join_workers();
+    }
+}
+
+fn run_worker(state: SharedState) {
+    let (lock, cvar) = &*state;
+
+    loop {
+        let queued = {
+            let mut guard = lock.lock().unwrap();
+            loop {
+                if let Some(queued) = guard.take_next() {
+                    break queued;
+                }
+                if guard.shutdown && guard.queue.is_empty() {
+                    return;
+                }
+                guard = cvar.wait(guard).unwrap();
+            }
+        };
+
+        let model_id = queued.model_id;
+        // A panicking job drops its result sender, which its handle reports as no result.
+        let delivery = panic::catch_unwind(AssertUnwindSafe(queued.job));
+
+        // Release the slot of the model before delivering the result
+        // so that the model counts as idle once the result is received.
+        lock.lock().unwrap().finish(model_id);
+        cvar.notify_all();
+
+        if let Ok(delivery) = delivery {
+            delivery();
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::future::Future;
+use std::hash::Hash;
+use std::ops::*;
+use std::pin::Pin;
+
+use crate::errors::CausalityError;
+use crate::prelude::{
+    AsyncCausable, Causable, Causaloid, Datable, NumericalValue, SpaceTemporal, Spatial, Temporable,
+};
+
+impl<D, S, T, ST, V> AsyncCausable for Causaloid<'_, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>
+        + Clone,
+{
+    fn verify_single_cause_async<'a>(
+        &'a self,
+        obs: &NumericalValue,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, CausalityError>> + Send + 'a>>
+    where
+        Self: Sync,
+    {
+        let obs = obs.to_owned();
+
+        Box::pin(async move {
+            match self.async_causal_fn {
+                Some(async_causal_fn) => {
+                    let res = async_causal_fn(obs).await?;
+
+                    let mut guard = self.active.write().unwrap();
+                    *guard = res;
+
+                    Ok(res)
+                }
+                // Causaloids with a synchronous causal function evaluate immediately.
+                None => self.verify_single_cause(&obs),
+            }
+        })
+    }
+}
@@ -95,8 +95,26 @@ where
             let mut guard = self.active.write().unwrap();
             *guard = res;
 
+            Ok(res)
+        } else if let Some(context_set) = self.context_set {
+            let context_set_causal_fn = self
+                .context_set_causal_fn
+                .expect("Causaloid::verify_single_cause: context_set_causal_fn is None");
+
+            let res = (context_set_causal_fn)(obs.to_owned(), context_set)?;
+
+            let mut guard = self.active.write().unwrap();
+            *guard = res;
+
             Ok(res)
         } else {
+            if self.async_causal_fn.is_some() {
+                return Err(CausalityError(format!(
+                    "Causaloid {} has an async causal function. Call verify_single_cause_async instead.",
+                    self.id
+                )));
+            }
+
             let causal_fn = self
                 .causal_fn
                 .expect("Causaloid::verify_single_cause: causal_fn is None");
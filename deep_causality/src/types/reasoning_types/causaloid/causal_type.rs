@@ -6,7 +6,7 @@ use std::fmt::{Display, Formatter};
 // Internal enum to represent the type of causaloid, which
 // is required to dispatch verify and explain method calls to
 // either a singleton, a causal collection, or causal graph.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
 pub(crate) enum CausalType {
     Singleton,
     Collection,
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hasher;
+
+use super::*;
+
+impl<'l, D, S, T, ST, V> Fingerprintable for Causaloid<'l, D, S, T, ST, V>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>
+        + Clone,
+{
+    /// Returns the fingerprint of the causaloid.
+    ///
+    /// Function pointers have no stable identity, therefore the causal function
+    /// is identified by the id, description, and function tag of the causaloid.
+    /// Set a function tag with with_function_tag to distinguish causal functions,
+    /// or versions thereof, that share id and description.
+    /// The fingerprint covers the id, description, function tag, causal type, the id of the
+    /// context, if any, the names and ids of a context set, if any,
+    /// and the fingerprints of a nested collection or graph.
+    /// The activation state is not part of the fingerprint.
+    fn fingerprint(&self) -> u64 {
+        let mut state = FingerprintHasher::default();
+
+        state.write_u64(self.id);
+        state.write_text(self.description);
+        state.write_option_text(self.function_tag);
+        state.write_u8(match self.causal_type {
+            CausalType::Singleton => 0,
+            CausalType::Collection => 1,
+            CausalType::Graph => 2,
+        });
+        state.write_option_u64(self.context.map(|context| context.id()));
+
+        if let Some(context_set) = self.context_set {
+            let names = context_set.names();
+            state.write_len(names.len());
+            for name in names {
+                state.write_text(name);
+                state.write_option_u64(context_set.get(name).map(|c| c.id()));
+            }
+        }
+
+        if let Some(causal_coll) = self.causal_coll {
+            state.write_len(causal_coll.len());
+            for causaloid in causal_coll.iter() {
+                state.write_u64(causaloid.fingerprint());
+            }
+        }
+
+        if let Some(causal_graph) = self.causal_graph {
+            state.write_u64(causal_graph.fingerprint());
+        }
+
+        state.finish()
+    }
+}
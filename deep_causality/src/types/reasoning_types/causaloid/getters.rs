@@ -33,7 +33,13 @@ where
     pub fn description(&self) -> &'l str {
         self.description
     }
+    pub fn function_tag(&self) -> Option<&'l str> {
+        self.function_tag
+    }
     pub fn context(&self) -> Option<&'l Context<D, S, T, ST, V>> {
         self.context
     }
+    pub fn context_set(&self) -> Option<&'l ContextSet<'l, D, S, T, ST, V>> {
+        self.context_set
+    }
 }
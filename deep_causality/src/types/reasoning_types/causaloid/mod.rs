@@ -10,10 +10,12 @@ use std::sync::{Arc, RwLock};
 use crate::prelude::*;
 use crate::types::reasoning_types::causaloid::causal_type::CausalType;
 
+mod async_causable;
 mod causable;
 mod causal_type;
 mod debug;
 mod display;
+mod fingerprintable;
 mod getters;
 mod identifiable;
 mod part_eq;
@@ -43,12 +45,16 @@ where
     active: ArcRWLock<bool>,
     causal_type: CausalType,
     causal_fn: Option<CausalFn>,
+    async_causal_fn: Option<AsyncCausalFn>,
     context_causal_fn: Option<ContextualCausalDataFn<'l, D, S, T, ST, V>>,
     context: Option<&'l Context<D, S, T, ST, V>>,
     has_context: bool,
+    context_set_causal_fn: Option<ContextSetCausalDataFn<'l, D, S, T, ST, V>>,
+    context_set: Option<&'l ContextSet<'l, D, S, T, ST, V>>,
     causal_coll: Option<&'l CausalVec<'l, D, S, T, ST, V>>,
     causal_graph: Option<&'l CausalGraph<'l, D, S, T, ST, V>>,
     description: &'l str,
+    function_tag: Option<&'l str>,
     ty: PhantomData<V>,
 }
 
@@ -78,12 +84,43 @@ where
             active: Arc::new(RwLock::new(false)),
             causal_type: CausalType::Singleton,
             causal_fn: Some(causal_fn),
+            async_causal_fn: None,
             context_causal_fn: None,
             context: None,
             has_context: false,
+            context_set_causal_fn: None,
+            context_set: None,
             causal_coll: None,
             causal_graph: None,
             description,
+            function_tag: None,
+            ty: PhantomData,
+        }
+    }
+
+    /// Singleton constructor for a causal function that returns a future,
+    /// i.e. to query a database or remote feature store during evaluation.
+    /// Evaluate with verify_single_cause_async of the AsyncCausable trait.
+    pub fn new_async(
+        id: IdentificationValue,
+        async_causal_fn: AsyncCausalFn,
+        description: &'l str,
+    ) -> Self {
+        Causaloid {
+            id,
+            active: Arc::new(RwLock::new(false)),
+            causal_type: CausalType::Singleton,
+            causal_fn: None,
+            async_causal_fn: Some(async_causal_fn),
+            context_causal_fn: None,
+            context: None,
+            has_context: false,
+            context_set_causal_fn: None,
+            context_set: None,
+            causal_coll: None,
+            causal_graph: None,
+            description,
+            function_tag: None,
             ty: PhantomData,
         }
     }
@@ -99,12 +136,43 @@ where
             active: Arc::new(RwLock::new(false)),
             causal_type: CausalType::Singleton,
             causal_fn: None,
+            async_causal_fn: None,
             context_causal_fn: Some(context_causal_fn),
             context,
             has_context: true,
+            context_set_causal_fn: None,
+            context_set: None,
             causal_coll: None,
             causal_graph: None,
             description,
+            function_tag: None,
+            ty: PhantomData,
+        }
+    }
+
+    /// Singleton constructor for a causal function that reads multiple named contexts,
+    /// i.e. a static configuration context and a fast-moving telemetry context.
+    pub fn new_with_context_set(
+        id: IdentificationValue,
+        context_set_causal_fn: ContextSetCausalDataFn<'l, D, S, T, ST, V>,
+        context_set: &'l ContextSet<'l, D, S, T, ST, V>,
+        description: &'l str,
+    ) -> Self {
+        Causaloid {
+            id,
+            active: Arc::new(RwLock::new(false)),
+            causal_type: CausalType::Singleton,
+            causal_fn: None,
+            async_causal_fn: None,
+            context_causal_fn: None,
+            context: None,
+            has_context: false,
+            context_set_causal_fn: Some(context_set_causal_fn),
+            context_set: Some(context_set),
+            causal_coll: None,
+            causal_graph: None,
+            description,
+            function_tag: None,
             ty: PhantomData,
         }
     }
@@ -126,12 +194,16 @@ where
             active: Arc::new(RwLock::new(false)),
             causal_type: CausalType::Collection,
             causal_fn: None,
+            async_causal_fn: None,
             causal_coll: Some(causal_coll),
             causal_graph: None,
             description,
             context: None,
             has_context: false,
+            context_set_causal_fn: None,
+            context_set: None,
             context_causal_fn: None,
+            function_tag: None,
             ty: PhantomData,
         }
     }
@@ -151,12 +223,16 @@ where
             active: Arc::new(RwLock::new(false)),
             causal_type: CausalType::Collection,
             causal_fn: None,
+            async_causal_fn: None,
             causal_coll: Some(causal_coll),
             causal_graph: None,
             description,
             context,
             has_context: true,
+            context_set_causal_fn: None,
+            context_set: None,
             context_causal_fn: None,
+            function_tag: None,
             ty: PhantomData,
         }
     }
@@ -178,12 +254,16 @@ where
             active: Arc::new(RwLock::new(false)),
             causal_type: CausalType::Graph,
             causal_fn: None,
+            async_causal_fn: None,
             causal_coll: None,
             causal_graph: Some(causal_graph),
             description,
             context: None,
             has_context: false,
+            context_set_causal_fn: None,
+            context_set: None,
             context_causal_fn: None,
+            function_tag: None,
             ty: PhantomData,
         }
     }
@@ -203,13 +283,27 @@ where
             active: Arc::new(RwLock::new(false)),
             causal_type: CausalType::Graph,
             causal_fn: None,
+            async_causal_fn: None,
             causal_coll: None,
             causal_graph: Some(causal_graph),
             description,
             context,
             has_context: true,
+            context_set_causal_fn: None,
+            context_set: None,
             context_causal_fn: None,
+            function_tag: None,
             ty: PhantomData,
         }
     }
+
+    /// Sets a tag that identifies the causal function, i.e. its name and version.
+    ///
+    /// Function pointers have no stable identity, therefore the fingerprint
+    /// of the causaloid covers the tag to distinguish different causal functions
+    /// with the same id and description.
+    pub fn with_function_tag(mut self, function_tag: &'l str) -> Self {
+        self.function_tag = Some(function_tag);
+        self
+    }
 }
@@ -3,12 +3,24 @@
 
 use super::*;
 
+// See default implementation in protocols/causaloid_graph/graph_attribution. Requires CausableGraphReasoning impl.
+impl<T> CausableGraphAttribution<T> for CausaloidGraph<T> where T: Causable + PartialEq {}
+
+// See default implementation in protocols/causaloid_graph/graph_diagnosing. Requires CausableGraphReasoning impl.
+impl<T> CausableGraphDiagnosing<T> for CausaloidGraph<T> where T: Causable + PartialEq {}
+
 // See default implementation in protocols/causaloid_graph/graph_explaining. Requires CausableGraph impl.
 impl<T> CausableGraphExplaining<T> for CausaloidGraph<T> where T: Causable + PartialEq {}
 
+// See default implementation in protocols/causaloid_graph/graph_incremental. Requires CausableGraphReasoning impl.
+impl<T> CausableGraphIncremental<T> for CausaloidGraph<T> where T: Causable + PartialEq {}
+
 // See default implementation in protocols/causaloid_graph/graph_explaining. Requires CausableGraph impl.
 impl<T> CausableGraphReasoning<T> for CausaloidGraph<T> where T: Causable + PartialEq {}
 
+// See default implementation in protocols/causaloid_graph/graph_simulating. Requires CausableGraphReasoning impl.
+impl<T> CausableGraphSimulating<T> for CausaloidGraph<T> where T: Causable + PartialEq {}
+
 impl<T> CausableGraph<T> for CausaloidGraph<T>
 where
     T: Causable + PartialEq,
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use crate::errors::CausalityGraphError;
+use crate::prelude::{
+    Causable, CausableGraph, CausaloidGraph, IdentificationValue, NumericalValue,
+};
+
+impl<T> CausaloidGraph<T>
+where
+    T: Causable + PartialEq,
+{
+    /// Evaluates every causaloid reachable from the root in topological order
+    /// and returns the effect of each node.
+    ///
+    /// Unlike reason_all_causes, evaluation does not stop at the first causaloid
+    /// that evaluates to false, so the returned map contains the intermediate
+    /// effect of every node, i.e. for debugging or attribution.
+    ///
+    /// data: &[NumericalValue] - data applied to the graph
+    /// Optional: data_index - provide when the data have a different index sorting than
+    /// the causaloids.
+    ///
+    /// Returns a map from node index to effect or a CausalityGraphError if
+    /// the graph has no root, contains a cycle, data are missing, or a causaloid fails.
+    pub fn evaluate_all(
+        &self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<HashMap<usize, bool>, CausalityGraphError> {
+        let order = self.topological_order()?;
+        let mut effects = HashMap::with_capacity(order.len());
+
+        for index in order {
+            let cause = self
+                .get_causaloid(index)
+                .ok_or_else(|| CausalityGraphError(format!("No causaloid at index {}", index)))?;
+
+            let effect = self.evaluate_node(
+                index,
+                cause.id() as usize,
+                cause.is_singleton(),
+                data,
+                data_index,
+            )?;
+
+            effects.insert(index, effect);
+        }
+
+        Ok(effects)
+    }
+}
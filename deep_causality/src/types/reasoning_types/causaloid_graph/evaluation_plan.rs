@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::{HashMap, VecDeque};
+
+use ultragraph::prelude::*;
+
+use crate::errors::CausalityGraphError;
+use crate::prelude::{
+    Causable, CausableGraph, CausaloidGraph, EvaluationPlan, Fingerprintable, IdentificationValue,
+    NumericalValue,
+};
+use crate::protocols::causable_graph::graph_reasoning_utils;
+
+impl<T> CausaloidGraph<T>
+where
+    T: Causable + PartialEq + Fingerprintable,
+{
+    /// Compiles the graph into a flattened evaluation plan.
+    ///
+    /// The plan contains all causaloids reachable from the root in topological order.
+    /// Compile once after the graph has been built and evaluate the plan repeatedly,
+    /// or use an EvaluationPlanCache to re-use plans across identical graphs.
+    ///
+    /// Returns CausalityGraphError if the graph has no root or
+    /// the part reachable from the root contains a cycle.
+    pub fn compile_plan(&self) -> Result<EvaluationPlan, CausalityGraphError> {
+        let order = self.topological_order()?;
+
+        let mut input_offsets = Vec::with_capacity(order.len());
+        let mut singletons = Vec::with_capacity(order.len());
+        for index in &order {
+            let cause = self.get_causaloid(*index).expect("Failed to get causaloid");
+            input_offsets.push(cause.id() as usize);
+            singletons.push(cause.is_singleton());
+        }
+
+        Ok(EvaluationPlan::new(
+            self.fingerprint(),
+            order,
+            input_offsets,
+            singletons,
+        ))
+    }
+
+    /// Evaluates a plan compiled from this graph.
+    ///
+    /// The plan is rejected if its fingerprint differs from the fingerprint of the graph,
+    /// i.e. if the graph changed after the plan was compiled. Computing the fingerprint
+    /// visits all causaloids and edges of the graph.
+    ///
+    /// Each causaloid in the plan is verified in topological order and evaluation stops
+    /// at the first causaloid that evaluates to false. Hence the result is true if and
+    /// only if all causaloids reachable from the root evaluate to true.
+    ///
+    /// Note, reason_all_causes traverses the graph depth-first and returns true as soon as
+    /// it reaches the causaloid at get_last_index, which is the number of causaloids.
+    /// A causaloid at that index only exists after other causaloids have been removed.
+    /// In that case, reason_all_causes never evaluates causaloids it has not visited
+    /// by then, whereas the plan does, and both methods may return different results.
+    /// Otherwise, both evaluate all reachable causaloids and return the same result.
+    ///
+    /// data: &[NumericalValue] - data applied to the graph
+    /// Optional: data_index - provide when the data have a different index sorting than
+    /// the causaloids.
+    ///
+    /// Returns Result either true or false in case of successful evaluation or
+    /// a CausalityGraphError if the plan does not match the graph, data are missing,
+    /// or a causaloid fails.
+    pub fn evaluate_plan(
+        &self,
+        plan: &EvaluationPlan,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<bool, CausalityGraphError> {
+        if *plan.fingerprint() != self.fingerprint() {
+            return Err(CausalityGraphError(
+                "Plan was compiled from a different graph or the graph changed since".into(),
+            ));
+        }
+
+        if plan.is_empty() {
+            return Err(CausalityGraphError("Plan is empty".into()));
+        }
+
+        for ((index, offset), singleton) in plan
+            .order()
+            .iter()
+            .zip(plan.input_offsets())
+            .zip(plan.singletons())
+        {
+            let res = self.evaluate_node(*index, *offset, *singleton, data, data_index)?;
+
+            if !res {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl<T> CausaloidGraph<T>
+where
+    T: Causable + PartialEq,
+{
+    /// Returns the indices of all nodes reachable from the root in topological order.
+    ///
+    /// Returns CausalityGraphError if the graph has no root or
+    /// the part reachable from the root contains a cycle.
+    pub(crate) fn topological_order(&self) -> Result<Vec<usize>, CausalityGraphError> {
+        let root_index = self
+            .get_root_index()
+            .ok_or_else(|| CausalityGraphError("Graph does not contains root causaloid".into()))?;
+
+        // Collect reachable nodes and count their incoming edges.
+        let mut in_degrees: HashMap<usize, usize> = HashMap::from([(root_index, 0)]);
+        let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut stack = vec![root_index];
+
+        while let Some(index) = stack.pop() {
+            let outgoing: Vec<usize> = self
+                .graph
+                .outgoing_edges(index)
+                .map_err(|e| CausalityGraphError(e.to_string()))?
+                .collect();
+
+            for child in &outgoing {
+                let in_degree = in_degrees.entry(*child).or_insert_with(|| {
+                    stack.push(*child);
+                    0
+                });
+                *in_degree += 1;
+            }
+
+            children.insert(index, outgoing);
+        }
+
+        // Kahn's algorithm over the reachable nodes.
+        let mut order = Vec::with_capacity(in_degrees.len());
+        let mut queue = VecDeque::new();
+        if in_degrees[&root_index] == 0 {
+            queue.push_back(root_index);
+        }
+
+        while let Some(index) = queue.pop_front() {
+            order.push(index);
+            for child in &children[&index] {
+                let in_degree = in_degrees.get_mut(child).expect("Failed to get in degree");
+                *in_degree -= 1;
+                if *in_degree == 0 {
+                    queue.push_back(*child);
+                }
+            }
+        }
+
+        if order.len() != in_degrees.len() {
+            return Err(CausalityGraphError("Graph contains a cycle".into()));
+        }
+
+        Ok(order)
+    }
+
+    /// Evaluates a single node against its data offset.
+    pub(crate) fn evaluate_node(
+        &self,
+        index: usize,
+        offset: usize,
+        singleton: bool,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<bool, CausalityGraphError> {
+        let cause = self
+            .get_causaloid(index)
+            .ok_or_else(|| CausalityGraphError(format!("No causaloid at index {}", index)))?;
+
+        let res = if singleton {
+            let obs = graph_reasoning_utils::try_get_obs(
+                offset as IdentificationValue,
+                data,
+                &data_index,
+            )
+            .ok_or_else(|| {
+                CausalityGraphError(format!("No data for causaloid at index {}", index))
+            })?;
+
+            cause.verify_single_cause(&obs)
+        } else {
+            cause.verify_all_causes(data, data_index)
+        }
+        .map_err(|e| CausalityGraphError(e.0))?;
+
+        Ok(res)
+    }
+}
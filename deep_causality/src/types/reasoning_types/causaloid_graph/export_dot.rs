@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::Write;
+use std::hash::Hash;
+use std::ops::*;
+
+use ultragraph::prelude::*;
+
+use crate::prelude::{
+    escape_dot_label, CausableGraph, Causaloid, CausaloidGraph, Datable, Identifiable,
+    SpaceTemporal, Spatial, Temporable,
+};
+
+impl<D, S, T, ST, V> CausaloidGraph<Causaloid<'_, D, S, T, ST, V>>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>
+        + Clone,
+{
+    /// Exports the graph as Graphviz DOT digraph.
+    ///
+    /// Each node is named by its index and labelled with the id and description of its causaloid.
+    /// The root node is drawn with a double border.
+    /// Render i.e. with `dot -Tsvg graph.dot -o graph.svg`.
+    pub fn export_dot(&self) -> String {
+        let mut dot = String::from("digraph CausaloidGraph {\n");
+        let root_index = self.get_root_index();
+
+        let mut found = 0;
+        let mut index = 0;
+        while found < self.number_nodes() {
+            if let Some(causaloid) = self.get_causaloid(index) {
+                let label = format!("{}: {}", causaloid.id(), causaloid.description());
+                let root = if Some(index) == root_index {
+                    ", peripheries=2"
+                } else {
+                    ""
+                };
+
+                writeln!(
+                    dot,
+                    "    n{} [label=\"{}\"{}];",
+                    index,
+                    escape_dot_label(&label),
+                    root
+                )
+                .expect("Failed to write DOT node");
+                found += 1;
+            }
+            index += 1;
+        }
+
+        let mut edges = self.get_graph().get_all_edges();
+        edges.sort_unstable();
+        for (a, b) in edges {
+            writeln!(dot, "    n{} -> n{};", a, b).expect("Failed to write DOT edge");
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hasher;
+
+use crate::prelude::{Causable, CausaloidGraph, FingerprintHasher, Fingerprintable};
+use crate::utils::fingerprint_utils;
+
+impl<T> Fingerprintable for CausaloidGraph<T>
+where
+    T: Causable + PartialEq + Fingerprintable,
+{
+    /// Returns the fingerprint of the causaloid graph.
+    ///
+    /// The fingerprint covers the root index, the index and fingerprint of each causaloid,
+    /// and all edges of the graph.
+    fn fingerprint(&self) -> u64 {
+        let mut state = FingerprintHasher::default();
+
+        fingerprint_utils::hash_graph(&self.graph, &mut state, |causaloid, state| {
+            state.write_u64(causaloid.fingerprint())
+        });
+
+        state.finish()
+    }
+}
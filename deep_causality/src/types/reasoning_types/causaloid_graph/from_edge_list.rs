@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::*;
+
+use crate::errors::BuildError;
+use crate::prelude::{
+    CausableGraph, CausalFnRegistry, Causaloid, CausaloidGraph, Datable, EdgeList,
+    IdentificationValue, SpaceTemporal, Spatial, Temporable,
+};
+
+impl<'l, D, S, T, ST, V> CausaloidGraph<Causaloid<'l, D, S, T, ST, V>>
+where
+    D: Datable + Clone,
+    S: Spatial<V> + Clone,
+    T: Temporable<V> + Clone,
+    ST: SpaceTemporal<V> + Clone,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>
+        + Clone,
+{
+    /// Materializes an edge list into an executable causal graph.
+    ///
+    /// Each node becomes a singleton causaloid with the causal function registered
+    /// under its name, which also serves as its description. Nodes are added in order of
+    /// their first appearance in the edge list and their id equals their index,
+    /// so that data at index i get applied to node i during reasoning.
+    /// The only node without incoming edges becomes the root.
+    ///
+    /// Returns BuildError if the edge list is empty, a node has no registered causal function,
+    /// or the edge list does not have exactly one node without incoming edges.
+    pub fn from_edge_list(
+        edge_list: &EdgeList,
+        registry: &'l CausalFnRegistry,
+    ) -> Result<Self, BuildError> {
+        if edge_list.is_empty() {
+            return Err(BuildError("Edge list is empty".into()));
+        }
+
+        let nodes = edge_list.nodes();
+
+        let roots: Vec<&str> = nodes
+            .iter()
+            .filter(|node| edge_list.edges().iter().all(|(_, target)| target != *node))
+            .copied()
+            .collect();
+
+        if roots.len() != 1 {
+            return Err(BuildError(format!(
+                "Edge list must have exactly one root node without incoming edges, found {}: {:?}",
+                roots.len(),
+                roots
+            )));
+        }
+
+        let mut g = Self::new_with_capacity(nodes.len());
+
+        for (index, node) in nodes.iter().enumerate() {
+            let (description, causal_fn) = registry.get(node).ok_or_else(|| {
+                BuildError(format!("No causal function registered for node {}", node))
+            })?;
+
+            let causaloid = Causaloid::new(index as IdentificationValue, causal_fn, description);
+
+            if *node == roots[0] {
+                g.add_root_causaloid(causaloid);
+            } else {
+                g.add_causaloid(causaloid);
+            }
+        }
+
+        let index_of = |name: &str| nodes.iter().position(|node| *node == name);
+
+        for (source, target) in edge_list.edges() {
+            // Both exist since nodes are collected from the edges.
+            let a = index_of(source).expect("Failed to get source node");
+            let b = index_of(target).expect("Failed to get target node");
+
+            g.add_edge(a, b).map_err(|e| {
+                BuildError(format!(
+                    "Failed to add edge {} -> {}: {}",
+                    source, target, e
+                ))
+            })?;
+        }
+
+        Ok(g)
+    }
+}
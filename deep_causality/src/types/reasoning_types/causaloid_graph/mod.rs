@@ -5,12 +5,19 @@ use ultragraph::prelude::*;
 
 use crate::errors::{CausalGraphIndexError, CausalityGraphError};
 use crate::prelude::{
-    Causable, CausableGraph, CausableGraphExplaining, CausableGraphReasoning, CausalGraph,
-    NumericalValue,
+    Causable, CausableGraph, CausableGraphAttribution, CausableGraphDiagnosing,
+    CausableGraphExplaining, CausableGraphIncremental, CausableGraphReasoning,
+    CausableGraphSimulating, CausalGraph, NumericalValue,
 };
 
 mod causable_graph;
 mod default;
+mod evaluate_all;
+mod evaluation_plan;
+mod export_dot;
+mod fingerprintable;
+mod from_edge_list;
+mod validate;
 
 #[derive(Clone)]
 pub struct CausaloidGraph<T>
@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use ultragraph::prelude::*;
+
+use crate::prelude::{Causable, CausaloidGraph, GraphValidationReport, IdentificationValue};
+
+// DFS state of a causaloid on the current path.
+const ON_PATH: bool = true;
+// DFS state of a causaloid whose descendants have all been visited.
+const DONE: bool = false;
+
+impl<T> CausaloidGraph<T>
+where
+    T: Causable + PartialEq,
+{
+    /// Validates the structure of the graph before reasoning.
+    ///
+    /// Reasoning fails lazily on a structural issue, i.e. only once it reaches an affected
+    /// causaloid, or silently ignores causaloids it never visits. Validating the graph
+    /// after construction reports all issues upfront.
+    ///
+    /// Checks for a missing root, cycles, causaloids unreachable from the root,
+    /// additional causaloids without incoming edges, dangling edges, and causaloids
+    /// sharing the same id.
+    ///
+    /// Returns a GraphValidationReport listing all issues found.
+    pub fn validate(&self) -> GraphValidationReport {
+        let nodes = self.node_indices();
+        let node_set: HashSet<usize> = nodes.iter().copied().collect();
+        let root = self.graph.get_root_index();
+
+        let mut edges = self.graph.get_all_edges();
+        edges.sort_unstable();
+
+        let dangling_edges: Vec<(usize, usize)> = edges
+            .iter()
+            .filter(|(a, b)| !node_set.contains(a) || !node_set.contains(b))
+            .copied()
+            .collect();
+
+        let targets: HashSet<usize> = edges
+            .iter()
+            .filter(|(a, b)| a != b && node_set.contains(a))
+            .map(|(_, b)| *b)
+            .collect();
+
+        let extra_roots: Vec<usize> = nodes
+            .iter()
+            .filter(|i| Some(**i) != root && !targets.contains(i))
+            .copied()
+            .collect();
+
+        let unreachable = match root {
+            Some(root) => {
+                let reachable = self.reachable_from(root);
+                nodes
+                    .iter()
+                    .filter(|i| !reachable.contains(i))
+                    .copied()
+                    .collect()
+            }
+            None => nodes.clone(),
+        };
+
+        let mut ids: BTreeMap<IdentificationValue, Vec<usize>> = BTreeMap::new();
+        for index in nodes.iter() {
+            let causaloid = self
+                .graph
+                .get_node(*index)
+                .expect("Failed to get causaloid");
+            ids.entry(causaloid.id()).or_default().push(*index);
+        }
+        let id_collisions = ids
+            .into_iter()
+            .filter(|(_, indices)| indices.len() > 1)
+            .collect();
+
+        GraphValidationReport::new(
+            root.is_none() && !nodes.is_empty(),
+            self.find_cycles(&nodes),
+            unreachable,
+            extra_roots,
+            dangling_edges,
+            id_collisions,
+        )
+    }
+
+    // Returns the indices of all causaloids in ascending order.
+    // Indices may have gaps after removal hence iterate until all causaloids have been found.
+    fn node_indices(&self) -> Vec<usize> {
+        let number_nodes = self.graph.number_nodes();
+        let mut indices = Vec::with_capacity(number_nodes);
+
+        let mut index = 0;
+        while indices.len() < number_nodes {
+            if self.graph.contains_node(index) {
+                indices.push(index);
+            }
+            index += 1;
+        }
+
+        indices
+    }
+
+    fn children(&self, index: usize) -> Vec<usize> {
+        self.graph
+            .outgoing_edges(index)
+            .map(|children| children.filter(|c| self.graph.contains_node(*c)).collect())
+            .unwrap_or_default()
+    }
+
+    fn reachable_from(&self, root: usize) -> HashSet<usize> {
+        let mut reachable = HashSet::new();
+        let mut stack = vec![root];
+
+        while let Some(index) = stack.pop() {
+            if reachable.insert(index) {
+                stack.extend(self.children(index));
+            }
+        }
+
+        reachable
+    }
+
+    // Finds one cycle per back edge with an iterative depth-first search over all causaloids.
+    fn find_cycles(&self, nodes: &[usize]) -> Vec<Vec<usize>> {
+        let mut state: HashMap<usize, bool> = HashMap::with_capacity(nodes.len());
+        let mut cycles = Vec::new();
+
+        for start in nodes.iter() {
+            if state.contains_key(start) {
+                continue;
+            }
+
+            state.insert(*start, ON_PATH);
+            let mut path = vec![*start];
+            let mut frontier = vec![self.children(*start)];
+
+            while let Some(children) = frontier.last_mut() {
+                match children.pop() {
+                    Some(child) => match state.get(&child) {
+                        None => {
+                            state.insert(child, ON_PATH);
+                            path.push(child);
+                            frontier.push(self.children(child));
+                        }
+                        Some(&ON_PATH) => {
+                            let position = path
+                                .iter()
+                                .position(|i| *i == child)
+                                .expect("Failed to find causaloid on path");
+                            cycles.push(path[position..].to_vec());
+                        }
+                        Some(_) => {}
+                    },
+                    None => {
+                        frontier.pop();
+                        let index = path.pop().expect("Failed to pop path");
+                        state.insert(index, DONE);
+                    }
+                }
+            }
+        }
+
+        cycles
+    }
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::{ConformalCalibrator, PredictionInterval};
+
+impl Display for PredictionInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PredictionInterval: [{}, {}]", self.lower, self.upper)
+    }
+}
+
+impl Display for ConformalCalibrator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ConformalCalibrator: alpha: {}, residuals: {}, quantile: {:?}",
+            self.alpha,
+            self.residuals.len(),
+            self.quantile()
+        )
+    }
+}
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::VecDeque;
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::errors::{BuildError, CausalityError};
+use crate::prelude::NumericalValue;
+
+mod display;
+
+/// Prediction interval with lower and upper bound, both inclusive.
+#[derive(Getters, Constructor, Debug, Copy, Clone, PartialEq)]
+pub struct PredictionInterval {
+    lower: NumericalValue,
+    upper: NumericalValue,
+}
+
+impl PredictionInterval {
+    pub fn contains(&self, value: NumericalValue) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+
+    pub fn width(&self) -> NumericalValue {
+        self.upper - self.lower
+    }
+}
+
+/// Split conformal calibration for numeric predictions of any model.
+///
+/// The calibrator keeps the absolute residuals |observed - predicted| of a calibration set.
+/// For exchangeable data, the interval prediction ± q, where q is the
+/// ceil((n + 1)(1 - alpha))-th smallest of n residuals, contains the observed value
+/// with probability of at least 1 - alpha, regardless of the model or data distribution.
+///
+/// With a capacity, only the latest residuals are kept, which adapts
+/// the intervals to slowly drifting data at the expense of the coverage guarantee.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConformalCalibrator {
+    alpha: NumericalValue,
+    capacity: Option<usize>,
+    residuals: VecDeque<NumericalValue>,
+}
+
+impl ConformalCalibrator {
+    /// Creates a new calibrator with miscoverage rate alpha, i.e. 0.1 for 90% coverage,
+    /// and an optional capacity of the calibration set.
+    ///
+    /// Returns BuildError if alpha is not in (0, 1) or the capacity is zero.
+    pub fn new(alpha: NumericalValue, capacity: Option<usize>) -> Result<Self, BuildError> {
+        if !(alpha > 0.0 && alpha < 1.0) {
+            return Err(BuildError(format!(
+                "Alpha must be in (0, 1) but was {}",
+                alpha
+            )));
+        }
+
+        if capacity == Some(0) {
+            return Err(BuildError("Capacity must be greater than zero".into()));
+        }
+
+        Ok(Self {
+            alpha,
+            capacity,
+            residuals: VecDeque::new(),
+        })
+    }
+
+    pub fn alpha(&self) -> NumericalValue {
+        self.alpha
+    }
+
+    /// Returns the target coverage 1 - alpha.
+    pub fn coverage(&self) -> NumericalValue {
+        1.0 - self.alpha
+    }
+
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Returns the number of residuals in the calibration set.
+    pub fn len(&self) -> usize {
+        self.residuals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.residuals.is_empty()
+    }
+
+    /// Returns the minimum number of residuals required for a finite interval.
+    pub fn min_calibration_size(&self) -> usize {
+        (1.0 / self.alpha).ceil() as usize - 1
+    }
+
+    /// Adds the residual of a prediction and its observed value to the calibration set.
+    /// Evicts the oldest residual if the calibration set is full.
+    ///
+    /// Returns CausalityError if the prediction or the observed value is not finite.
+    pub fn calibrate(
+        &mut self,
+        predicted: NumericalValue,
+        observed: NumericalValue,
+    ) -> Result<(), CausalityError> {
+        if !predicted.is_finite() || !observed.is_finite() {
+            return Err(CausalityError(format!(
+                "Cannot calibrate non-finite values: predicted {}, observed {}",
+                predicted, observed
+            )));
+        }
+
+        if self.capacity == Some(self.residuals.len()) {
+            self.residuals.pop_front();
+        }
+
+        self.residuals.push_back((observed - predicted).abs());
+        Ok(())
+    }
+
+    /// Returns the conformal quantile of the residuals or None if
+    /// the calibration set is too small for the coverage.
+    pub fn quantile(&self) -> Option<NumericalValue> {
+        let n = self.residuals.len();
+        let rank = ((n + 1) as NumericalValue * (1.0 - self.alpha)).ceil() as usize;
+        if n == 0 || rank > n {
+            return None;
+        }
+
+        let mut sorted: Vec<NumericalValue> = self.residuals.iter().copied().collect();
+        sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+        Some(sorted[rank - 1])
+    }
+
+    /// Returns the prediction interval around the prediction or None if
+    /// the calibration set is too small for the coverage.
+    pub fn predict_interval(&self, predicted: NumericalValue) -> Option<PredictionInterval> {
+        let q = self.quantile()?;
+        Some(PredictionInterval::new(predicted - q, predicted + q))
+    }
+
+    /// Removes all residuals from the calibration set.
+    pub fn clear(&mut self) {
+        self.residuals.clear();
+    }
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use crate::errors::CausalityError;
+use crate::prelude::{Causable, DynCausaloid, Identifiable, IdentificationValue, NumericalValue};
+
+impl Identifiable for DynCausaloid<'_> {
+    fn id(&self) -> u64 {
+        self.inner.id()
+    }
+}
+
+impl Causable for DynCausaloid<'_> {
+    fn explain(&self) -> Result<String, CausalityError> {
+        self.inner.explain()
+    }
+
+    fn is_active(&self) -> bool {
+        self.inner.is_active()
+    }
+
+    fn is_singleton(&self) -> bool {
+        self.inner.is_singleton()
+    }
+
+    fn verify_single_cause(&self, obs: &NumericalValue) -> Result<bool, CausalityError> {
+        self.inner.verify_single_cause(obs)
+    }
+
+    fn verify_all_causes(
+        &self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<bool, CausalityError> {
+        self.inner.verify_all_causes(data, data_index)
+    }
+}
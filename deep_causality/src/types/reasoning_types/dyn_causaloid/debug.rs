@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Debug, Formatter};
+
+use crate::prelude::{DynCausaloid, Identifiable};
+
+impl Debug for DynCausaloid<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DynCausaloid: id: {}, is singleton: {}, is active: {}",
+            self.id(),
+            self.inner.is_singleton(),
+            self.inner.is_active()
+        )
+    }
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hash;
+use std::ops::*;
+use std::rc::Rc;
+
+use crate::prelude::{Causable, Causaloid, Datable, SpaceTemporal, Spatial, Temporable};
+
+mod causable;
+mod debug;
+mod part_eq;
+
+/// Type-erased causaloid for heterogeneous causal graphs.
+///
+/// A DynCausaloid wraps any Causable behind a shared trait object so that causaloids
+/// of different context types, or custom Causable types, can be stored in the same
+/// CausaloidGraph without spelling out the generic parameters of each.
+///
+/// Cloning a DynCausaloid is cheap and shares the wrapped causaloid.
+/// Two DynCausaloids are equal if their ids are equal.
+#[derive(Clone)]
+pub struct DynCausaloid<'l> {
+    inner: Rc<dyn Causable + 'l>,
+}
+
+impl<'l> DynCausaloid<'l> {
+    pub fn new<C>(causaloid: C) -> Self
+    where
+        C: Causable + 'l,
+    {
+        Self {
+            inner: Rc::new(causaloid),
+        }
+    }
+
+    /// Returns the wrapped causaloid as trait object.
+    pub fn inner(&self) -> &(dyn Causable + 'l) {
+        self.inner.as_ref()
+    }
+}
+
+impl<'l, D, S, T, ST, V> From<Causaloid<'l, D, S, T, ST, V>> for DynCausaloid<'l>
+where
+    D: Datable + Clone + 'l,
+    S: Spatial<V> + Clone + 'l,
+    T: Temporable<V> + Clone + 'l,
+    ST: SpaceTemporal<V> + Clone + 'l,
+    V: Default
+        + Copy
+        + Clone
+        + Hash
+        + Eq
+        + PartialEq
+        + Add<V, Output = V>
+        + Sub<V, Output = V>
+        + Mul<V, Output = V>
+        + 'l,
+{
+    fn from(causaloid: Causaloid<'l, D, S, T, ST, V>) -> Self {
+        DynCausaloid::new(causaloid)
+    }
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::{DynCausaloid, Identifiable};
+
+impl PartialEq for DynCausaloid<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashSet;
+
+use crate::prelude::{NodeFailure, ReasoningReport};
+
+/// Reusable buffers for graph evaluation.
+///
+/// Policy based graph reasoning allocates a traversal frontier, a visited set,
+/// and the evaluation results on every call. At high event rates, these allocations
+/// dominate the evaluation of small graphs. An arena keeps these buffers across evaluations
+/// so that, once warmed up, repeated evaluations of the same graph reuse them
+/// instead of allocating new ones.
+///
+/// The arena is reset at the start of each evaluation and holds the results
+/// of the last evaluation until the next one.
+#[derive(Debug, Default)]
+pub struct EvalArena {
+    frontier: Vec<usize>,
+    visited: HashSet<usize>,
+    evaluated: Vec<(usize, bool)>,
+    failures: Vec<NodeFailure>,
+}
+
+impl EvalArena {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new arena with buffers for a graph with the given number of causaloids.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            frontier: Vec::with_capacity(capacity),
+            visited: HashSet::with_capacity(capacity),
+            evaluated: Vec::with_capacity(capacity),
+            failures: Vec::new(),
+        }
+    }
+
+    /// Clears the results of the last evaluation while keeping the allocated buffers.
+    pub fn reset(&mut self) {
+        self.frontier.clear();
+        self.visited.clear();
+        self.evaluated.clear();
+        self.failures.clear();
+    }
+
+    /// Returns the number of causaloids the arena can evaluate without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.evaluated.capacity()
+    }
+
+    /// Returns the index and effect of each causaloid evaluated by the last evaluation
+    /// in evaluation order.
+    pub fn evaluated(&self) -> &[(usize, bool)] {
+        &self.evaluated
+    }
+
+    /// Returns all causaloids that failed during the last evaluation.
+    pub fn failures(&self) -> &[NodeFailure] {
+        &self.failures
+    }
+
+    /// Returns true if at least one causaloid was evaluated
+    /// and all evaluated causaloids evaluated to true.
+    pub fn result(&self) -> bool {
+        !self.evaluated.is_empty() && self.evaluated.iter().all(|(_, res)| *res)
+    }
+
+    /// Moves the results of the last evaluation into a reasoning report.
+    pub fn into_report(self) -> ReasoningReport {
+        let result = self.result();
+        ReasoningReport::new(result, self.evaluated, self.failures)
+    }
+
+    pub(crate) fn push_frontier(&mut self, index: usize) {
+        self.frontier.push(index);
+    }
+
+    pub(crate) fn extend_frontier<I: IntoIterator<Item = usize>>(&mut self, indices: I) {
+        self.frontier.extend(indices);
+    }
+
+    pub(crate) fn pop_frontier(&mut self) -> Option<usize> {
+        self.frontier.pop()
+    }
+
+    /// Marks the causaloid as visited and returns false if it has been visited before.
+    pub(crate) fn visit(&mut self, index: usize) -> bool {
+        self.visited.insert(index)
+    }
+
+    pub(crate) fn record(&mut self, index: usize, res: bool) {
+        self.evaluated.push((index, res));
+    }
+
+    pub(crate) fn record_failure(&mut self, failure: NodeFailure) {
+        self.failures.push(failure);
+    }
+}
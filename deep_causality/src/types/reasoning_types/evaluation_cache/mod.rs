@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::prelude::{IdentificationValue, NumericalValue};
+
+/// Memoized effects of causaloids for incremental reasoning over a causal graph.
+///
+/// The cache stores the effect of each evaluated causaloid together with the input
+/// it was evaluated on. A causaloid is re-evaluated only if its input, including
+/// the data index, changed or it has been marked dirty, for example after a change
+/// of the context it depends on.
+///
+/// See CausableGraphIncremental for usage.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EvaluationCache {
+    effects: HashMap<usize, bool>,
+    observations: HashMap<usize, NumericalValue>,
+    data: Vec<NumericalValue>,
+    data_index: Option<HashMap<IdentificationValue, IdentificationValue>>,
+    dirty: HashSet<usize>,
+    number_evaluated: usize,
+}
+
+impl EvaluationCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached effect of the causaloid at index, if any.
+    pub fn effect(&self, index: usize) -> Option<bool> {
+        self.effects.get(&index).copied()
+    }
+
+    /// Returns true if an effect is cached for the causaloid at index.
+    pub fn is_cached(&self, index: usize) -> bool {
+        self.effects.contains_key(&index)
+    }
+
+    /// Returns true if the causaloid at index has been marked dirty.
+    pub fn is_dirty(&self, index: usize) -> bool {
+        self.dirty.contains(&index)
+    }
+
+    /// Marks the causaloid at index dirty so that the next incremental
+    /// reasoning re-evaluates it regardless of its input.
+    pub fn mark_dirty(&mut self, index: usize) {
+        self.dirty.insert(index);
+    }
+
+    /// Removes all cached effects so that the next incremental reasoning
+    /// re-evaluates every causaloid.
+    pub fn clear(&mut self) {
+        self.effects.clear();
+        self.observations.clear();
+        self.data.clear();
+        self.data_index = None;
+        self.dirty.clear();
+        self.number_evaluated = 0;
+    }
+
+    /// Returns the number of causaloids with a cached effect.
+    pub fn number_cached(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Returns the number of causaloids evaluated during the last incremental reasoning.
+    pub fn number_evaluated(&self) -> usize {
+        self.number_evaluated
+    }
+}
+
+// Bookkeeping used by CausableGraphIncremental.
+impl EvaluationCache {
+    // Starts a new incremental reasoning and returns true if the data or
+    // the data index changed since the previous one.
+    pub(crate) fn begin(
+        &mut self,
+        data: &[NumericalValue],
+        data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> bool {
+        self.number_evaluated = 0;
+
+        let data_changed = self.data.as_slice() != data;
+        if data_changed {
+            self.data = data.to_vec();
+        }
+
+        let index_changed = self.data_index.as_ref() != data_index;
+        if index_changed {
+            self.data_index = data_index.cloned();
+        }
+
+        data_changed || index_changed
+    }
+
+    pub(crate) fn lookup_single(&self, index: usize, obs: NumericalValue) -> Option<bool> {
+        if self.is_dirty(index) || self.observations.get(&index) != Some(&obs) {
+            return None;
+        }
+        self.effect(index)
+    }
+
+    pub(crate) fn lookup_all(&self, index: usize, data_changed: bool) -> Option<bool> {
+        if data_changed || self.is_dirty(index) {
+            return None;
+        }
+        self.effect(index)
+    }
+
+    pub(crate) fn store(&mut self, index: usize, obs: Option<NumericalValue>, effect: bool) {
+        self.number_evaluated += 1;
+        self.dirty.remove(&index);
+        self.effects.insert(index, effect);
+        match obs {
+            Some(obs) => self.observations.insert(index, obs),
+            None => self.observations.remove(&index),
+        };
+    }
+}
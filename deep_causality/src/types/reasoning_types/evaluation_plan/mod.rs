@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use deep_causality_macros::Getters;
+
+use crate::errors::CausalityGraphError;
+use crate::prelude::{Causable, CausaloidGraph, Fingerprintable};
+
+/// Flattened, topologically ordered evaluation plan of a causal graph.
+///
+/// The plan lists all causaloids reachable from the root such that every causaloid
+/// comes after all of its parents, together with the data offset and kind of each causaloid.
+/// Evaluating the plan walks these arrays in order without traversing the graph.
+///
+/// Create with CausaloidGraph::compile_plan and evaluate with CausaloidGraph::evaluate_plan.
+#[derive(Getters, Debug, Clone, PartialEq)]
+pub struct EvaluationPlan {
+    /// Fingerprint of the graph the plan was compiled from.
+    fingerprint: u64,
+    /// Node indices in topological order.
+    order: Vec<usize>,
+    /// Data offset of each node in order, i.e. its causaloid id.
+    input_offsets: Vec<usize>,
+    /// Whether each node in order is a singleton causaloid.
+    singletons: Vec<bool>,
+}
+
+impl EvaluationPlan {
+    pub(crate) fn new(
+        fingerprint: u64,
+        order: Vec<usize>,
+        input_offsets: Vec<usize>,
+        singletons: Vec<bool>,
+    ) -> Self {
+        Self {
+            fingerprint,
+            order,
+            input_offsets,
+            singletons,
+        }
+    }
+
+    /// Returns the number of causaloids in the plan.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+}
+
+/// Compiled evaluation plans keyed on the fingerprint of their graph.
+///
+/// Since the fingerprint changes with any change of the graph,
+/// a cached plan is only re-used for an identical graph.
+#[derive(Debug, Default, Clone)]
+pub struct EvaluationPlanCache {
+    plans: HashMap<u64, EvaluationPlan>,
+}
+
+impl EvaluationPlanCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached plan of the graph with the fingerprint, if any.
+    pub fn get(&self, fingerprint: u64) -> Option<&EvaluationPlan> {
+        self.plans.get(&fingerprint)
+    }
+
+    pub fn contains(&self, fingerprint: u64) -> bool {
+        self.plans.contains_key(&fingerprint)
+    }
+
+    /// Returns the cached plan of the graph or compiles and caches it.
+    ///
+    /// Returns CausalityGraphError if the plan cannot be compiled.
+    pub fn get_or_compile<T>(
+        &mut self,
+        graph: &CausaloidGraph<T>,
+    ) -> Result<&EvaluationPlan, CausalityGraphError>
+    where
+        T: Causable + PartialEq + Fingerprintable,
+    {
+        let fingerprint = graph.fingerprint();
+
+        match self.plans.entry(fingerprint) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => Ok(entry.insert(graph.compile_plan()?)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.plans.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.plans.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.plans.clear();
+    }
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use crate::prelude::CausalFn;
+
+mod parse;
+
+/// Maps node names of an edge list to registered causal functions.
+///
+/// See CausaloidGraph::from_edge_list for usage.
+#[derive(Debug, Default, Clone)]
+pub struct CausalFnRegistry {
+    fns: HashMap<String, CausalFn>,
+}
+
+impl CausalFnRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the causal function under the name.
+    /// A later registration with the same name overrides an earlier one.
+    pub fn register(&mut self, name: impl Into<String>, causal_fn: CausalFn) {
+        self.fns.insert(name.into(), causal_fn);
+    }
+
+    /// Returns the registered name and causal function, if any.
+    pub fn get(&self, name: &str) -> Option<(&str, CausalFn)> {
+        self.fns
+            .get_key_value(name)
+            .map(|(name, causal_fn)| (name.as_str(), *causal_fn))
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.fns.contains_key(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.fns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fns.is_empty()
+    }
+}
+
+/// Directed edges between named nodes, i.e. authored in an external tool
+/// and exported as CSV or Graphviz DOT.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EdgeList {
+    edges: Vec<(String, String)>,
+}
+
+impl EdgeList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a directed edge from source to target.
+    pub fn add_edge(&mut self, source: impl Into<String>, target: impl Into<String>) {
+        self.edges.push((source.into(), target.into()));
+    }
+
+    pub fn edges(&self) -> &[(String, String)] {
+        &self.edges
+    }
+
+    /// Returns all node names in order of their first appearance.
+    pub fn nodes(&self) -> Vec<&str> {
+        let mut nodes: Vec<&str> = Vec::new();
+        for (source, target) in &self.edges {
+            for name in [source.as_str(), target.as_str()] {
+                if !nodes.contains(&name) {
+                    nodes.push(name);
+                }
+            }
+        }
+        nodes
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::BuildError;
+use crate::prelude::EdgeList;
+
+impl EdgeList {
+    /// Parses an edge list from CSV with one `source,target` pair per line.
+    ///
+    /// Empty lines, lines starting with '#', and a `source,target` header are skipped.
+    /// Surrounding whitespace and double quotes of names are removed.
+    ///
+    /// Returns BuildError if a line does not contain exactly two non-empty names.
+    pub fn from_csv(csv: &str) -> Result<Self, BuildError> {
+        let mut edge_list = EdgeList::new();
+
+        for (number, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(',').map(clean_name).collect();
+            if number == 0 && fields.len() == 2 && fields[0].eq_ignore_ascii_case("source") {
+                continue;
+            }
+
+            match fields.as_slice() {
+                [source, target] if !source.is_empty() && !target.is_empty() => {
+                    edge_list.add_edge(*source, *target)
+                }
+                _ => {
+                    return Err(BuildError(format!(
+                        "Invalid edge in line {}: {}",
+                        number + 1,
+                        line
+                    )))
+                }
+            }
+        }
+
+        Ok(edge_list)
+    }
+
+    /// Parses the edges of a Graphviz DOT digraph.
+    ///
+    /// Each statement with `->` adds its edges, including chains such as `a -> b -> c`.
+    /// Statements may be separated by `;`, commas, or line breaks, and the graph
+    /// may be written on a single line. Names may be quoted and then contain any
+    /// character, including `;`, `[`, or `->`. Attributes in brackets, the graph
+    /// header and braces, node statements, and `//`, `/* */`, and `#` comments are ignored.
+    ///
+    /// Returns BuildError if an edge lacks a source or target, or contains an empty name,
+    /// or if a quoted name, attribute list, or comment is not terminated.
+    pub fn from_dot(dot: &str) -> Result<Self, BuildError> {
+        let mut edge_list = EdgeList::new();
+
+        // Names of the current edge chain with the line of each name.
+        let mut chain: Vec<(String, usize)> = Vec::new();
+        // Line of the last arrow if it still awaits its target.
+        let mut pending_arrow: Option<usize> = None;
+
+        for token in tokenize_dot(dot)? {
+            match token {
+                DotToken::Name(name, line) => {
+                    if pending_arrow.take().is_none() {
+                        add_dot_chain(&mut edge_list, &chain)?;
+                        chain.clear();
+                    }
+                    chain.push((name, line));
+                }
+                DotToken::Arrow(line) => {
+                    if chain.is_empty() || pending_arrow.is_some() {
+                        return Err(BuildError(format!("Edge without source in line {}", line)));
+                    }
+                    pending_arrow = Some(line);
+                }
+                DotToken::Separator(line) => {
+                    if pending_arrow.is_some() {
+                        return Err(BuildError(format!("Edge without target in line {}", line)));
+                    }
+                    add_dot_chain(&mut edge_list, &chain)?;
+                    chain.clear();
+                }
+            }
+        }
+
+        if let Some(line) = pending_arrow {
+            return Err(BuildError(format!("Edge without target in line {}", line)));
+        }
+        add_dot_chain(&mut edge_list, &chain)?;
+
+        Ok(edge_list)
+    }
+}
+
+enum DotToken {
+    Name(String, usize),
+    Arrow(usize),
+    Separator(usize),
+}
+
+// Adds the edges of a chain a -> b -> c; a single name is a node statement.
+fn add_dot_chain(edge_list: &mut EdgeList, chain: &[(String, usize)]) -> Result<(), BuildError> {
+    if chain.len() < 2 {
+        return Ok(());
+    }
+
+    if let Some((_, line)) = chain.iter().find(|(name, _)| name.is_empty()) {
+        return Err(BuildError(format!("Empty name in edge in line {}", line)));
+    }
+
+    for pair in chain.windows(2) {
+        edge_list.add_edge(pair[0].0.as_str(), pair[1].0.as_str());
+    }
+
+    Ok(())
+}
+
+// Splits a DOT document into names, arrows, and separators.
+// Comments and attribute lists are dropped; every other character
+// that cannot be part of a name, i.e. `{`, `}`, `;`, or `=`, separates statements.
+fn tokenize_dot(dot: &str) -> Result<Vec<DotToken>, BuildError> {
+    let mut tokens = Vec::new();
+    let mut chars = dot.chars().peekable();
+    let mut line = 1;
+    let mut line_start = true;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => {
+                line += 1;
+                line_start = true;
+                continue;
+            }
+            c if c.is_whitespace() => continue,
+            // Preprocessor output lines
+            '#' if line_start => while chars.next_if(|c| *c != '\n').is_some() {},
+            '/' if chars.peek() == Some(&'/') => while chars.next_if(|c| *c != '\n').is_some() {},
+            '/' if chars.peek() == Some(&'*') => {
+                let start = line;
+                chars.next();
+                let mut previous = ' ';
+                loop {
+                    match chars.next() {
+                        Some('/') if previous == '*' => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            previous = c;
+                        }
+                        None => {
+                            return Err(BuildError(format!(
+                                "Unterminated comment starting in line {}",
+                                start
+                            )))
+                        }
+                    }
+                }
+            }
+            '"' => {
+                let start = line;
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            name.push('"');
+                        }
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            name.push(c);
+                        }
+                        None => {
+                            return Err(BuildError(format!(
+                                "Unterminated quoted name starting in line {}",
+                                start
+                            )))
+                        }
+                    }
+                }
+                tokens.push(DotToken::Name(name.trim().to_string(), start));
+            }
+            '[' => {
+                let start = line;
+                let mut in_quotes = false;
+                loop {
+                    match chars.next() {
+                        Some('"') => in_quotes = !in_quotes,
+                        Some('\\') if in_quotes => {
+                            chars.next();
+                        }
+                        Some(']') if !in_quotes => break,
+                        Some('\n') => line += 1,
+                        Some(_) => {}
+                        None => {
+                            return Err(BuildError(format!(
+                                "Unterminated attribute list starting in line {}",
+                                start
+                            )))
+                        }
+                    }
+                }
+            }
+            '-' if chars.peek() == Some(&'>') => {
+                chars.next();
+                tokens.push(DotToken::Arrow(line));
+            }
+            c if is_dot_name_char(c) => {
+                let mut name = String::from(c);
+                while let Some(c) = chars.next_if(|c| is_dot_name_char(*c)) {
+                    // Stop before an arrow directly following the name, i.e. a->b
+                    if c == '-' && chars.peek() == Some(&'>') {
+                        tokens.push(DotToken::Name(name, line));
+                        chars.next();
+                        tokens.push(DotToken::Arrow(line));
+                        name = String::new();
+                        break;
+                    }
+                    name.push(c);
+                }
+                if !name.is_empty() {
+                    tokens.push(DotToken::Name(name, line));
+                }
+            }
+            _ => tokens.push(DotToken::Separator(line)),
+        }
+
+        line_start = false;
+    }
+
+    Ok(tokens)
+}
+
+fn is_dot_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.' || c == '-'
+}
+
+fn clean_name(name: &str) -> &str {
+    name.trim().trim_matches('"').trim()
+}
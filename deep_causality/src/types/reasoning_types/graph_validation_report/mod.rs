@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::IdentificationValue;
+
+/// Structural issues of a causaloid graph found by CausaloidGraph::validate.
+///
+/// * missing_root: the graph has causaloids but no root causaloid
+/// * cycles: each cycle as the indices along the cycle, starting at its first visited causaloid
+/// * unreachable: indices of causaloids not reachable from the root
+/// * extra_roots: indices of causaloids other than the root without incoming edges,
+///   i.e. additional roots that reasoning from the root never visits
+/// * dangling_edges: edges with at least one endpoint that is not a causaloid of the graph
+/// * id_collisions: each id shared by more than one causaloid with the indices of those causaloids
+///
+/// All indices are in ascending order except the indices of a cycle, which are in cycle order.
+#[derive(Getters, Constructor, Debug, Clone, PartialEq)]
+pub struct GraphValidationReport {
+    missing_root: bool,
+    cycles: Vec<Vec<usize>>,
+    unreachable: Vec<usize>,
+    extra_roots: Vec<usize>,
+    dangling_edges: Vec<(usize, usize)>,
+    id_collisions: Vec<(IdentificationValue, Vec<usize>)>,
+}
+
+impl GraphValidationReport {
+    /// Returns true if no structural issue has been found.
+    pub fn is_valid(&self) -> bool {
+        self.number_issues() == 0
+    }
+
+    /// Returns the number of structural issues found.
+    pub fn number_issues(&self) -> usize {
+        self.missing_root as usize
+            + self.cycles.len()
+            + self.unreachable.len()
+            + self.extra_roots.len()
+            + self.dangling_edges.len()
+            + self.id_collisions.len()
+    }
+}
+
+impl Display for GraphValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_valid() {
+            return write!(f, "GraphValidationReport: valid");
+        }
+
+        write!(
+            f,
+            "GraphValidationReport: {} issue(s)",
+            self.number_issues()
+        )?;
+        if self.missing_root {
+            write!(f, "; missing root")?;
+        }
+        if !self.cycles.is_empty() {
+            write!(f, "; cycles: {:?}", self.cycles)?;
+        }
+        if !self.unreachable.is_empty() {
+            write!(f, "; unreachable: {:?}", self.unreachable)?;
+        }
+        if !self.extra_roots.is_empty() {
+            write!(f, "; extra roots: {:?}", self.extra_roots)?;
+        }
+        if !self.dangling_edges.is_empty() {
+            write!(f, "; dangling edges: {:?}", self.dangling_edges)?;
+        }
+        if !self.id_collisions.is_empty() {
+            write!(f, "; id collisions: {:?}", self.id_collisions)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use crate::prelude::{BetaDistribution, IdentificationValue, Inferable};
+
+/// Maintains the posterior distribution of each inference as observations arrive.
+///
+/// Each inference, identified by its id, starts with the shared prior.
+/// Every observed inference updates the posterior of its id with
+/// whether it is inferable, so that the posterior mean estimates the probability
+/// that the inference holds together with the uncertainty of that estimate.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InferencePosteriors {
+    prior: BetaDistribution,
+    posteriors: HashMap<IdentificationValue, BetaDistribution>,
+}
+
+impl InferencePosteriors {
+    /// Creates new posteriors starting from the given prior.
+    pub fn new(prior: BetaDistribution) -> Self {
+        Self {
+            prior,
+            posteriors: HashMap::new(),
+        }
+    }
+
+    pub fn prior(&self) -> BetaDistribution {
+        self.prior
+    }
+
+    /// Updates the posterior of the inference with whether it is inferable.
+    ///
+    /// Returns the updated posterior.
+    pub fn observe<I>(&mut self, inference: &I) -> BetaDistribution
+    where
+        I: Inferable,
+    {
+        self.update(inference.id(), inference.is_inferable())
+    }
+
+    /// Updates the posterior of the inference with the given id with an observed outcome.
+    ///
+    /// Returns the updated posterior.
+    pub fn update(&mut self, id: IdentificationValue, holds: bool) -> BetaDistribution {
+        let posterior = self.posteriors.entry(id).or_insert(self.prior);
+        posterior.update(holds);
+        *posterior
+    }
+
+    /// Returns the posterior of the inference with the given id,
+    /// or the prior if no observation of the inference has been made.
+    pub fn posterior(&self, id: IdentificationValue) -> BetaDistribution {
+        self.posteriors.get(&id).copied().unwrap_or(self.prior)
+    }
+
+    /// Returns true if at least one observation of the inference has been made.
+    pub fn contains(&self, id: IdentificationValue) -> bool {
+        self.posteriors.contains_key(&id)
+    }
+
+    /// Returns the ids of all observed inferences in ascending order.
+    pub fn ids(&self) -> Vec<IdentificationValue> {
+        let mut ids: Vec<IdentificationValue> = self.posteriors.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Resets the posterior of the inference to the prior.
+    pub fn reset(&mut self, id: IdentificationValue) {
+        self.posteriors.remove(&id);
+    }
+
+    pub fn clear(&mut self) {
+        self.posteriors.clear();
+    }
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::{Intervention, InterventionSchedule};
+
+impl Display for Intervention {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "do(index: {}, value: {})", self.index, self.value)
+    }
+}
+
+impl Display for InterventionSchedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "InterventionSchedule: steps: {}", self.steps.len())?;
+        for (step, interventions) in self.steps.iter().enumerate() {
+            if interventions.is_empty() {
+                continue;
+            }
+            let interventions: Vec<String> = interventions.iter().map(|i| i.to_string()).collect();
+            write!(f, ", step {}: [{}]", step, interventions.join(", "))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::NumericalValue;
+
+mod display;
+
+/// Sets the data of the causaloid at index to a fixed value, i.e. a do-operation.
+#[derive(Getters, Constructor, Debug, Copy, Clone, PartialEq)]
+pub struct Intervention {
+    index: usize,
+    value: NumericalValue,
+}
+
+/// Interventions over a sequence of discrete time steps.
+///
+/// Each step holds the interventions applied at that step. Steps without interventions
+/// leave the data as rolled forward from the previous step.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct InterventionSchedule {
+    steps: Vec<Vec<Intervention>>,
+}
+
+impl InterventionSchedule {
+    /// Creates a schedule of the number of steps without interventions.
+    pub fn new(number_steps: usize) -> Self {
+        Self {
+            steps: vec![Vec::new(); number_steps],
+        }
+    }
+
+    /// Adds an intervention at the step. The schedule grows to include the step if necessary.
+    /// A later intervention on the same index at the same step overrides an earlier one.
+    pub fn add_intervention(&mut self, step: usize, index: usize, value: NumericalValue) {
+        if step >= self.steps.len() {
+            self.steps.resize(step + 1, Vec::new());
+        }
+        self.steps[step].push(Intervention::new(index, value));
+    }
+
+    /// Returns the interventions of the step, or an empty slice if the step is out of range.
+    pub fn interventions_at(&self, step: usize) -> &[Intervention] {
+        self.steps.get(step).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn number_steps(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
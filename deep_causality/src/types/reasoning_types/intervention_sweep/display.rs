@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::{InterventionSweep, SweepAxis};
+
+impl Display for SweepAxis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SweepAxis: index: {}, from {} to {} in {} steps",
+            self.index, self.start, self.end, self.steps
+        )
+    }
+}
+
+impl Display for InterventionSweep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "InterventionSweep: shape: {:?}, true: {:.2}%",
+            self.shape(),
+            self.percent_true()
+        )
+    }
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::NumericalValue;
+
+mod display;
+
+/// Intervention values of one sweep axis: the data of the causaloid at index
+/// are set to steps evenly spaced values from start to end, both inclusive.
+#[derive(Getters, Constructor, Debug, Copy, Clone, PartialEq)]
+pub struct SweepAxis {
+    index: usize,
+    start: NumericalValue,
+    end: NumericalValue,
+    steps: usize,
+}
+
+impl SweepAxis {
+    /// Returns the intervention values of this axis.
+    /// A single step yields the start value.
+    pub fn values(&self) -> Vec<NumericalValue> {
+        match self.steps {
+            0 => Vec::new(),
+            1 => vec![self.start],
+            n => {
+                let delta = (self.end - self.start) / (n - 1) as NumericalValue;
+                (0..n)
+                    .map(|i| self.start + delta * i as NumericalValue)
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Outcomes of reasoning over a graph across a grid of intervention values,
+/// i.e. a dose-response curve for one axis or a sensitivity surface for two axes.
+///
+/// The outcomes are stored in row-major order with the shape of the axes,
+/// so that the last axis varies fastest.
+#[derive(Getters, Constructor, Debug, Clone, PartialEq)]
+pub struct InterventionSweep {
+    axes: Vec<SweepAxis>,
+    outcomes: Vec<bool>,
+}
+
+impl InterventionSweep {
+    /// Returns the number of steps per axis.
+    pub fn shape(&self) -> Vec<usize> {
+        self.axes.iter().map(|axis| *axis.steps()).collect()
+    }
+
+    /// Returns the outcome at the position given as one step per axis,
+    /// or None if the position is out of bounds.
+    pub fn outcome(&self, position: &[usize]) -> Option<bool> {
+        if position.len() != self.axes.len() {
+            return None;
+        }
+
+        let mut offset = 0;
+        for (axis, step) in self.axes.iter().zip(position) {
+            if *step >= *axis.steps() {
+                return None;
+            }
+            offset = offset * axis.steps() + step;
+        }
+
+        self.outcomes.get(offset).copied()
+    }
+
+    /// Returns the outcomes as numerical values, true as 1.0 and false as 0.0, for plotting.
+    pub fn to_numerical(&self) -> Vec<NumericalValue> {
+        self.outcomes
+            .iter()
+            .map(|outcome| if *outcome { 1.0 } else { 0.0 })
+            .collect()
+    }
+
+    /// Returns the percentage of interventions with a true outcome.
+    pub fn percent_true(&self) -> NumericalValue {
+        if self.outcomes.is_empty() {
+            return 0.0;
+        }
+        let number_true = self.outcomes.iter().filter(|outcome| **outcome).count();
+        (number_true as NumericalValue / self.outcomes.len() as NumericalValue) * 100.0
+    }
+}
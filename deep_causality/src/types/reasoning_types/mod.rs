@@ -2,7 +2,29 @@
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
 pub mod assumption;
+pub mod assumption_monitor;
+pub mod attribution;
+pub mod beta_distribution;
+pub mod causal_executor;
 pub mod causaloid;
 pub mod causaloid_graph;
+pub mod conformal_calibrator;
+pub mod dyn_causaloid;
+pub mod eval_arena;
+pub mod evaluation_cache;
+pub mod evaluation_plan;
+pub mod graph_import;
+pub mod graph_validation_report;
 pub mod inference;
+pub mod inference_posteriors;
+pub mod intervention_schedule;
+pub mod intervention_sweep;
 pub mod observation;
+pub mod observation_statistics;
+pub mod observation_store;
+pub mod online_learner;
+pub mod page_hinkley;
+pub mod parameter_change;
+pub mod reasoning_policy;
+pub mod reasoning_report;
+pub mod root_cause;
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::ObservationStatistics;
+
+impl Display for ObservationStatistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ObservationStatistics: observations: {}, observed: {}, percent observed: {}, weighted percent observed: {}, groups: {}",
+            self.len(),
+            self.number_observation(),
+            self.percent_observation(),
+            self.weighted_percent_observation(),
+            self.groups.len()
+        )
+    }
+}
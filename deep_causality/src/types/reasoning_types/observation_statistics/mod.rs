@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use crate::errors::BuildError;
+use crate::prelude::{IdentificationValue, NumericalValue, Observable};
+
+mod display;
+
+/// Running counts of a group of observations.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+struct Counts {
+    total: u64,
+    observed: u64,
+}
+
+/// Incremental aggregation of observations for a fixed target threshold and effect.
+///
+/// ObservationStatistics updates its counts with each new observation so that
+/// the frequency of the target effect is available in constant time
+/// without re-scanning the full collection of observations.
+///
+/// In addition to the overall counts, it keeps:
+/// * per-group counts for observations added with a group id
+/// * recency weighted counts where each new observation multiplies the weight
+///   of all previous observations by the decay factor
+///
+/// A decay of 1.0 disables recency weighting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObservationStatistics {
+    target_threshold: NumericalValue,
+    target_effect: NumericalValue,
+    decay: NumericalValue,
+    counts: Counts,
+    weighted_total: NumericalValue,
+    weighted_observed: NumericalValue,
+    groups: HashMap<IdentificationValue, Counts>,
+}
+
+impl ObservationStatistics {
+    /// Creates new statistics without recency weighting.
+    pub fn new(target_threshold: NumericalValue, target_effect: NumericalValue) -> Self {
+        Self {
+            target_threshold,
+            target_effect,
+            decay: 1.0,
+            counts: Counts::default(),
+            weighted_total: 0.0,
+            weighted_observed: 0.0,
+            groups: HashMap::new(),
+        }
+    }
+
+    /// Creates new statistics with recency weighting.
+    ///
+    /// Returns a BuildError if the decay is not in the interval (0, 1].
+    pub fn new_with_decay(
+        target_threshold: NumericalValue,
+        target_effect: NumericalValue,
+        decay: NumericalValue,
+    ) -> Result<Self, BuildError> {
+        if !(decay > 0.0 && decay <= 1.0) {
+            return Err(BuildError(format!(
+                "Decay {} is out of range (0, 1]",
+                decay
+            )));
+        }
+
+        let mut stats = Self::new(target_threshold, target_effect);
+        stats.decay = decay;
+        Ok(stats)
+    }
+}
+
+impl ObservationStatistics {
+    /// Adds an observation to the overall counts.
+    pub fn update<T: Observable>(&mut self, observation: &T) {
+        self.update_with(observation);
+    }
+
+    /// Adds an observation to the overall counts and to the counts of the group.
+    pub fn update_group<T: Observable>(&mut self, group: IdentificationValue, observation: &T) {
+        let observed = self.update_with(observation);
+
+        let counts = self.groups.entry(group).or_default();
+        counts.total += 1;
+        if observed {
+            counts.observed += 1;
+        }
+    }
+
+    /// Adds all observations to the overall counts in the order given.
+    pub fn update_all<'a, T, I>(&mut self, observations: I)
+    where
+        T: Observable + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        for observation in observations {
+            self.update_with(observation);
+        }
+    }
+
+    /// Removes all counts while keeping target threshold, target effect, and decay.
+    pub fn reset(&mut self) {
+        self.counts = Counts::default();
+        self.weighted_total = 0.0;
+        self.weighted_observed = 0.0;
+        self.groups.clear();
+    }
+
+    fn update_with<T: Observable>(&mut self, observation: &T) -> bool {
+        let observed = observation.effect_observed(self.target_threshold, self.target_effect);
+
+        self.counts.total += 1;
+        self.weighted_total = self.weighted_total * self.decay + 1.0;
+        self.weighted_observed *= self.decay;
+
+        if observed {
+            self.counts.observed += 1;
+            self.weighted_observed += 1.0;
+        }
+
+        observed
+    }
+}
+
+impl ObservationStatistics {
+    pub fn target_threshold(&self) -> NumericalValue {
+        self.target_threshold
+    }
+    pub fn target_effect(&self) -> NumericalValue {
+        self.target_effect
+    }
+    pub fn decay(&self) -> NumericalValue {
+        self.decay
+    }
+
+    /// Returns the number of all observations added so far.
+    pub fn len(&self) -> usize {
+        self.counts.total as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.total == 0
+    }
+
+    /// Counts the number of observations meeting the criteria.
+    pub fn number_observation(&self) -> NumericalValue {
+        self.counts.observed as NumericalValue
+    }
+
+    /// Counts the number of observations not meeting the criteria.
+    pub fn number_non_observation(&self) -> NumericalValue {
+        (self.counts.total - self.counts.observed) as NumericalValue
+    }
+
+    /// Returns the share of observations meeting the criteria between 0.0 and 1.0.
+    /// Returns 0.0 if no observation has been added.
+    pub fn percent_observation(&self) -> NumericalValue {
+        rate(self.counts)
+    }
+
+    /// Returns 1.0 minus the percent_observation.
+    /// Returns 0.0 if no observation has been added.
+    pub fn percent_non_observation(&self) -> NumericalValue {
+        if self.is_empty() {
+            return 0.0;
+        }
+        1.0 - self.percent_observation()
+    }
+
+    /// Returns the recency weighted share of observations meeting the criteria.
+    /// Equals percent_observation if the decay is 1.0.
+    pub fn weighted_percent_observation(&self) -> NumericalValue {
+        if self.weighted_total == 0.0 {
+            return 0.0;
+        }
+        self.weighted_observed / self.weighted_total
+    }
+
+    /// Returns the number of observations added to the group, if the group exists.
+    pub fn group_len(&self, group: IdentificationValue) -> Option<usize> {
+        self.groups.get(&group).map(|c| c.total as usize)
+    }
+
+    /// Returns the share of observations of the group meeting the criteria, if the group exists.
+    pub fn group_percent_observation(&self, group: IdentificationValue) -> Option<NumericalValue> {
+        self.groups.get(&group).map(|c| rate(*c))
+    }
+
+    /// Returns the ids of all groups in ascending order.
+    pub fn groups(&self) -> Vec<IdentificationValue> {
+        let mut groups: Vec<IdentificationValue> = self.groups.keys().copied().collect();
+        groups.sort_unstable();
+        groups
+    }
+}
+
+fn rate(counts: Counts) -> NumericalValue {
+    if counts.total == 0 {
+        return 0.0;
+    }
+    counts.observed as NumericalValue / counts.total as NumericalValue
+}
@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Error, ErrorKind, Write};
+use std::path::Path;
+
+use crate::prelude::{
+    Identifiable, IdentificationValue, NumericalValue, Observable, Observation, ObservationStore,
+};
+
+const CSV_HEADER: &str = "time,id,observation,observed_effect";
+
+impl ObservationStore {
+    /// Writes all observations as CSV with the header time,id,observation,observed_effect.
+    ///
+    /// Numbers are written in their shortest representation that parses back
+    /// to the identical value, therefore read_csv restores an identical store.
+    pub fn write_csv<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writeln!(writer, "{}", CSV_HEADER)?;
+        for (time, o) in self.entries.iter() {
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                time,
+                o.id(),
+                o.observation(),
+                o.observed_effect()
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Reads observations from CSV as written by write_csv.
+    ///
+    /// Returns an error of kind InvalidData with the line number
+    /// if the header or any row is malformed.
+    pub fn read_csv<R: BufRead>(reader: R) -> std::io::Result<Self> {
+        let mut store = Self::new();
+
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            if number == 0 {
+                if line.trim() != CSV_HEADER {
+                    return Err(invalid_data(1, "expected header ".to_string() + CSV_HEADER));
+                }
+                continue;
+            }
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (time, observation) = parse_row(&line).map_err(|e| invalid_data(number + 1, e))?;
+            store.append(time, observation);
+        }
+
+        Ok(store)
+    }
+
+    /// Writes all observations as CSV file at the path.
+    pub fn save_csv<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_csv(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Reads observations from the CSV file at the path.
+    pub fn load_csv<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        Self::read_csv(BufReader::new(File::open(path)?))
+    }
+}
+
+fn parse_row(line: &str) -> Result<(u64, Observation), String> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    if fields.len() != 4 {
+        return Err(format!("expected 4 fields but found {}", fields.len()));
+    }
+
+    let time: u64 = parse_field(fields[0], "time")?;
+    let id: IdentificationValue = parse_field(fields[1], "id")?;
+    let observation: NumericalValue = parse_field(fields[2], "observation")?;
+    let observed_effect: NumericalValue = parse_field(fields[3], "observed_effect")?;
+
+    Ok((time, Observation::new(id, observation, observed_effect)))
+}
+
+fn parse_field<T: std::str::FromStr>(field: &str, name: &str) -> Result<T, String> {
+    field
+        .parse()
+        .map_err(|_| format!("invalid {}: {}", name, field))
+}
+
+fn invalid_data(line: usize, message: String) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Invalid observation CSV at line {}: {}", line, message),
+    )
+}
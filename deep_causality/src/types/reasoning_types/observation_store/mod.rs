@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::ops::Range;
+
+use crate::prelude::{NumericalValue, Observable, Observation};
+
+mod csv;
+mod observable_reasoning;
+
+/// Time ordered store of observations.
+///
+/// Each observation is stored together with its time, i.e. a unix timestamp.
+/// Observations are kept sorted by time; observations with the same time keep
+/// their insertion order. The store implements ObservableReasoning so that inference
+/// can run directly over all stored observations or over the result of a query.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObservationStore {
+    entries: Vec<(u64, Observation)>,
+}
+
+impl ObservationStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds the observation at the given time.
+    ///
+    /// Appending in time order is O(1); an observation older than the latest one
+    /// is inserted at its position in time.
+    pub fn append(&mut self, time: u64, observation: Observation) {
+        match self.entries.last() {
+            Some((latest, _)) if time < *latest => {
+                let position = self.entries.partition_point(|(t, _)| *t <= time);
+                self.entries.insert(position, (time, observation));
+            }
+            _ => self.entries.push((time, observation)),
+        }
+    }
+
+    /// Returns all entries with their time, oldest first.
+    pub fn entries(&self) -> &[(u64, Observation)] {
+        &self.entries
+    }
+
+    /// Returns the time of the oldest and the latest observation, if any.
+    pub fn time_span(&self) -> Option<(u64, u64)> {
+        let (first, _) = self.entries.first()?;
+        let (last, _) = self.entries.last()?;
+        Some((*first, *last))
+    }
+
+    /// Returns all entries with a time in the half-open range, oldest first.
+    pub fn range(&self, range: Range<u64>) -> &[(u64, Observation)] {
+        let begin = self.entries.partition_point(|(t, _)| *t < range.start);
+        let end = self.entries.partition_point(|(t, _)| *t < range.end);
+        &self.entries[begin..end.max(begin)]
+    }
+
+    /// Returns all observations with an observed value in [min, max], oldest first.
+    pub fn filter_by_observation(
+        &self,
+        min: NumericalValue,
+        max: NumericalValue,
+    ) -> Vec<&Observation> {
+        self.filter(|_, o| o.observation() >= min && o.observation() <= max)
+    }
+
+    /// Returns all observations for which the predicate over time and observation
+    /// returns true, oldest first.
+    pub fn filter<P>(&self, predicate: P) -> Vec<&Observation>
+    where
+        P: Fn(u64, &Observation) -> bool,
+    {
+        self.entries
+            .iter()
+            .filter(|(t, o)| predicate(*t, o))
+            .map(|(_, o)| o)
+            .collect()
+    }
+
+    /// Removes all observations older than the given time.
+    ///
+    /// Returns the number of removed observations.
+    pub fn remove_before(&mut self, time: u64) -> usize {
+        let end = self.entries.partition_point(|(t, _)| *t < time);
+        self.entries.drain(..end);
+        end
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::{ObservableReasoning, Observation, ObservationStore};
+
+impl ObservableReasoning<Observation> for ObservationStore {
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn get_all_items(&self) -> Vec<&Observation> {
+        self.entries.iter().map(|(_, o)| o).collect()
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::AdjustmentError;
+use crate::prelude::{IdentificationValue, NumericalValue, OnlineAdjustable, ParameterChange};
+
+/// Applies online updates with a fixed learning rate and maximum step size
+/// and logs every change for auditing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnlineLearner {
+    learning_rate: NumericalValue,
+    max_step: NumericalValue,
+    changes: Vec<ParameterChange>,
+}
+
+impl OnlineLearner {
+    /// Creates a new online learner.
+    ///
+    /// Returns AdjustmentError if the learning rate is not in (0, 1]
+    /// or max_step is not positive.
+    pub fn new(
+        learning_rate: NumericalValue,
+        max_step: NumericalValue,
+    ) -> Result<Self, AdjustmentError> {
+        check_rates(learning_rate, max_step)?;
+
+        Ok(Self {
+            learning_rate,
+            max_step,
+            changes: Vec::new(),
+        })
+    }
+
+    pub fn learning_rate(&self) -> NumericalValue {
+        self.learning_rate
+    }
+
+    pub fn max_step(&self) -> NumericalValue {
+        self.max_step
+    }
+
+    /// Updates the parameter of the item from the prediction error and logs the change.
+    ///
+    /// Returns the change or an AdjustmentError, in which case nothing is logged.
+    pub fn update<A>(
+        &mut self,
+        item: &mut A,
+        error: NumericalValue,
+    ) -> Result<ParameterChange, AdjustmentError>
+    where
+        A: OnlineAdjustable,
+    {
+        let change = item.adjust_online(error, self.learning_rate, self.max_step)?;
+        self.changes.push(change);
+        Ok(change)
+    }
+
+    /// Returns all logged changes in the order applied.
+    pub fn changes(&self) -> &[ParameterChange] {
+        &self.changes
+    }
+
+    /// Returns all logged changes of the item with the id in the order applied.
+    pub fn changes_of(&self, id: IdentificationValue) -> Vec<&ParameterChange> {
+        self.changes.iter().filter(|c| *c.id() == id).collect()
+    }
+
+    pub fn clear_changes(&mut self) {
+        self.changes.clear();
+    }
+}
+
+pub(crate) fn check_rates(
+    learning_rate: NumericalValue,
+    max_step: NumericalValue,
+) -> Result<(), AdjustmentError> {
+    if !(learning_rate > 0.0 && learning_rate <= 1.0) {
+        return Err(AdjustmentError(format!(
+            "Learning rate must be in (0, 1] but was {}",
+            learning_rate
+        )));
+    }
+
+    if max_step.is_nan() || max_step <= 0.0 {
+        return Err(AdjustmentError(format!(
+            "Maximum step must be positive but was {}",
+            max_step
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::PageHinkley;
+
+impl Display for PageHinkley {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PageHinkley: observed: {}, mean: {}, statistic: {}, detected: {}",
+            self.number_observed,
+            self.mean,
+            self.statistic(),
+            self.number_detected
+        )
+    }
+}
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::{BuildError, CausalityError};
+use crate::prelude::NumericalValue;
+
+mod display;
+
+/// Page-Hinkley test that detects a change of the mean of a data stream,
+/// i.e. when context data depart from the data a model was validated on.
+///
+/// The test accumulates the deviations of each value from the running mean,
+/// minus the tolerated magnitude delta, separately for increases and decreases.
+/// Drift is detected once either cumulative deviation rises more than the threshold
+/// above its minimum. After a detection, the test restarts on the following values.
+///
+/// Feed the detection into a causaloid of a CSM state, i.e. as observation 1.0,
+/// to trigger recalibration or retirement of a model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageHinkley {
+    delta: NumericalValue,
+    threshold: NumericalValue,
+    min_samples: usize,
+    number_observed: usize,
+    mean: NumericalValue,
+    sum_increase: NumericalValue,
+    min_increase: NumericalValue,
+    sum_decrease: NumericalValue,
+    min_decrease: NumericalValue,
+    number_detected: usize,
+}
+
+impl PageHinkley {
+    /// Creates a new Page-Hinkley test.
+    ///
+    /// delta: magnitude of changes tolerated, i.e. half the smallest change to detect
+    /// threshold: detection threshold; larger values detect later but raise fewer false alarms
+    /// min_samples: number of values observed before drift can be detected
+    ///
+    /// Returns BuildError if delta is negative or the threshold is not positive.
+    pub fn new(
+        delta: NumericalValue,
+        threshold: NumericalValue,
+        min_samples: usize,
+    ) -> Result<Self, BuildError> {
+        if delta.is_nan() || delta < 0.0 {
+            return Err(BuildError(format!(
+                "Delta must not be negative but was {}",
+                delta
+            )));
+        }
+
+        if threshold.is_nan() || threshold <= 0.0 {
+            return Err(BuildError(format!(
+                "Threshold must be positive but was {}",
+                threshold
+            )));
+        }
+
+        Ok(Self {
+            delta,
+            threshold,
+            min_samples,
+            number_observed: 0,
+            mean: 0.0,
+            sum_increase: 0.0,
+            min_increase: 0.0,
+            sum_decrease: 0.0,
+            min_decrease: 0.0,
+            number_detected: 0,
+        })
+    }
+
+    /// Adds the value to the test and returns true if drift has been detected.
+    ///
+    /// Returns CausalityError if the value is not finite.
+    pub fn update(&mut self, value: NumericalValue) -> Result<bool, CausalityError> {
+        if !value.is_finite() {
+            return Err(CausalityError(format!(
+                "Cannot test non-finite value for drift: {}",
+                value
+            )));
+        }
+
+        self.number_observed += 1;
+        self.mean += (value - self.mean) / self.number_observed as NumericalValue;
+
+        self.sum_increase += value - self.mean - self.delta;
+        self.min_increase = self.min_increase.min(self.sum_increase);
+
+        self.sum_decrease += self.mean - value - self.delta;
+        self.min_decrease = self.min_decrease.min(self.sum_decrease);
+
+        if self.number_observed >= self.min_samples && self.statistic() > self.threshold {
+            self.number_detected += 1;
+            self.reset();
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    /// Returns the current test statistic, the larger of both cumulative deviations
+    /// above their minimum.
+    pub fn statistic(&self) -> NumericalValue {
+        let increase = self.sum_increase - self.min_increase;
+        let decrease = self.sum_decrease - self.min_decrease;
+        increase.max(decrease)
+    }
+
+    /// Restarts the test. The number of detections is kept.
+    pub fn reset(&mut self) {
+        self.number_observed = 0;
+        self.mean = 0.0;
+        self.sum_increase = 0.0;
+        self.min_increase = 0.0;
+        self.sum_decrease = 0.0;
+        self.min_decrease = 0.0;
+    }
+
+    pub fn delta(&self) -> NumericalValue {
+        self.delta
+    }
+
+    pub fn threshold(&self) -> NumericalValue {
+        self.threshold
+    }
+
+    pub fn min_samples(&self) -> usize {
+        self.min_samples
+    }
+
+    /// Returns the number of values observed since the last restart.
+    pub fn number_observed(&self) -> usize {
+        self.number_observed
+    }
+
+    /// Returns the mean of the values observed since the last restart.
+    pub fn mean(&self) -> NumericalValue {
+        self.mean
+    }
+
+    /// Returns the number of detections since creation.
+    pub fn number_detected(&self) -> usize {
+        self.number_detected
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::ParameterChange;
+
+impl Display for ParameterChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ParameterChange: id: {}, previous: {}, current: {}, error: {}",
+            self.id, self.previous, self.current, self.error
+        )
+    }
+}
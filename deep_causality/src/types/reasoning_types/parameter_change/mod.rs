@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::{IdentificationValue, NumericalValue};
+
+mod display;
+
+/// Record of a single online update of a parameter for auditing.
+#[derive(Getters, Constructor, Debug, Copy, Clone, PartialEq)]
+pub struct ParameterChange {
+    id: IdentificationValue,
+    previous: NumericalValue,
+    current: NumericalValue,
+    error: NumericalValue,
+}
+
+impl ParameterChange {
+    /// Returns the applied step, i.e. current - previous.
+    pub fn step(&self) -> NumericalValue {
+        self.current - self.previous
+    }
+}
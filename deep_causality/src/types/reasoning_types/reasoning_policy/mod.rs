@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+
+/// Determines how graph reasoning handles a causaloid that fails to evaluate.
+///
+/// Independent of the policy, a causaloid that evaluates to false does not abort
+/// reasoning with a policy: its descendants are skipped while all other branches
+/// are still evaluated. The regular reasoning methods instead return false
+/// on the first causaloid that evaluates to false.
+///
+/// FailFast: Aborts reasoning on the first failing causaloid and returns its ReasoningError,
+/// which carries the error kind, the retriable flag, and the id of the failing causaloid.
+///
+/// SkipAndLog: Records the failure, skips all causaloids reachable only
+/// through the failing causaloid, and continues with the remaining branches.
+///
+/// SubstituteDefault: Records the failure and continues reasoning as if
+/// the failing causaloid had evaluated to the given value.
+#[derive(Debug, Default, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum ReasoningPolicy {
+    #[default]
+    FailFast,
+    SkipAndLog,
+    SubstituteDefault(bool),
+}
+
+impl Display for ReasoningPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeSet;
+
+use crate::prelude::{EffectDelta, ReasoningReport, ReasoningReportDiff};
+
+impl ReasoningReportDiff {
+    /// Returns true if the effects of all causaloids and the overall result are equal.
+    pub fn is_empty(&self) -> bool {
+        self.deltas.is_empty() && self.result == self.other_result
+    }
+
+    /// Returns true if the overall result differs between both runs.
+    pub fn result_changed(&self) -> bool {
+        self.result != self.other_result
+    }
+
+    /// Explains the difference with one line per diverging causaloid,
+    /// starting with the first divergence.
+    pub fn explain(&self) -> String {
+        let mut explanation = format!(
+            "Result changed from {} to {}",
+            self.result, self.other_result
+        );
+        if !self.result_changed() {
+            explanation = format!("Result unchanged: {}", self.result);
+        }
+
+        let first = self.first_divergence.iter();
+        let rest = self
+            .deltas
+            .iter()
+            .filter(|d| Some(**d) != self.first_divergence);
+
+        for delta in first.chain(rest) {
+            explanation.push('\n');
+            explanation.push_str(&self.explain_delta(delta));
+        }
+
+        explanation
+    }
+
+    fn explain_delta(&self, delta: &EffectDelta) -> String {
+        let index = *delta.index();
+        let describe = |effect: &Option<bool>, failures: &[(usize, String)]| match effect {
+            Some(effect) => format!("evaluated to {}", effect),
+            None => match failures.iter().find(|(i, _)| *i == index) {
+                Some((_, error)) => format!("failed with {}", error),
+                None => "was not evaluated".to_string(),
+            },
+        };
+
+        format!(
+            "Causaloid at index {} {} in the first run but {} in the second run",
+            index,
+            describe(delta.observed(), &self.failures),
+            describe(delta.counterfactual(), &self.other_failures)
+        )
+    }
+}
+
+impl ReasoningReport {
+    /// Aligns the effects of this report with another report of the same graph
+    /// and returns every causaloid whose effect differs.
+    ///
+    /// The effects of this report become the observed and
+    /// the effects of the other report the counterfactual side of each delta.
+    pub fn diff(&self, other: &ReasoningReport) -> ReasoningReportDiff {
+        let indices: BTreeSet<usize> = self
+            .evaluated
+            .iter()
+            .chain(other.evaluated.iter())
+            .map(|(index, _)| *index)
+            .chain(self.failed_indices())
+            .chain(other.failed_indices())
+            .collect();
+
+        let delta_of = |index: usize| {
+            let before = self.effect_of(index);
+            let after = other.effect_of(index);
+            (before != after).then(|| EffectDelta::new(index, before, after))
+        };
+
+        let deltas = indices.into_iter().filter_map(delta_of).collect();
+
+        let first_divergence = self
+            .evaluated
+            .iter()
+            .chain(other.evaluated.iter())
+            .map(|(index, _)| *index)
+            .chain(self.failed_indices())
+            .chain(other.failed_indices())
+            .find_map(delta_of);
+
+        let failures_of = |report: &ReasoningReport| {
+            report
+                .failures
+                .iter()
+                .map(|f| (*f.index(), f.error().to_string()))
+                .collect()
+        };
+
+        ReasoningReportDiff {
+            result: self.result,
+            other_result: other.result,
+            deltas,
+            first_divergence,
+            failures: failures_of(self),
+            other_failures: failures_of(other),
+        }
+    }
+}
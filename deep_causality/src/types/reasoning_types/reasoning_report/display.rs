@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::{NodeFailure, ReasoningReport, ReasoningReportDiff};
+
+impl Display for NodeFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "NodeFailure: index: {}, error: {}",
+            self.index, self.error
+        )
+    }
+}
+
+impl Display for ReasoningReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ReasoningReport: result: {}, evaluated: {}, failed: {}",
+            self.result,
+            self.evaluated.len(),
+            self.failures.len()
+        )
+    }
+}
+
+impl Display for ReasoningReportDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ReasoningReportDiff: result: {} -> {}, deltas: {}",
+            self.result(),
+            self.other_result(),
+            self.deltas().len()
+        )
+    }
+}
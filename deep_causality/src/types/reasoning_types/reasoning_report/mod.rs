@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::{EffectDelta, IdentificationValue, ReasoningError};
+
+mod diff;
+mod display;
+
+/// A causaloid that failed during policy based graph reasoning.
+#[derive(Getters, Constructor, Debug)]
+pub struct NodeFailure {
+    /// Index of the failing causaloid in the graph.
+    index: usize,
+    /// Error returned by the failing causaloid.
+    error: ReasoningError,
+}
+
+impl NodeFailure {
+    /// Returns the id of the failing causaloid.
+    pub fn causaloid_id(&self) -> Option<IdentificationValue> {
+        self.error.causaloid_id()
+    }
+}
+
+/// Outcome of policy based graph reasoning.
+///
+/// The report contains the overall result, the effect of each evaluated causaloid
+/// in evaluation order, and all causaloids that failed during reasoning.
+/// The overall result is true if at least one causaloid was evaluated
+/// and all evaluated causaloids evaluated to true.
+/// Causaloids skipped due to a failure do not contribute to the result,
+/// therefore check is_complete to determine whether the result covers the entire graph.
+#[derive(Getters, Constructor, Debug)]
+pub struct ReasoningReport {
+    result: bool,
+    /// Index and effect of each evaluated causaloid in evaluation order.
+    evaluated: Vec<(usize, bool)>,
+    failures: Vec<NodeFailure>,
+}
+
+impl ReasoningReport {
+    /// Returns true if no causaloid failed during reasoning.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Returns the indices of all failed causaloids.
+    pub fn failed_indices(&self) -> Vec<usize> {
+        self.failures.iter().map(|f| f.index).collect()
+    }
+
+    /// Returns the effect of the causaloid at the given index
+    /// or None if the causaloid was not evaluated.
+    pub fn effect_of(&self, index: usize) -> Option<bool> {
+        self.evaluated
+            .iter()
+            .find(|(i, _)| *i == index)
+            .map(|(_, effect)| *effect)
+    }
+}
+
+/// Difference between two reasoning reports of the same graph,
+/// for example a factual and a counterfactual run.
+///
+/// The deltas list every causaloid whose effect differs between both runs in
+/// ascending index order. The first divergence is the first differing causaloid
+/// in evaluation order of the first run, followed by causaloids evaluated only
+/// in the second run, and marks where both traces start to diverge.
+#[derive(Getters, Debug, Clone)]
+pub struct ReasoningReportDiff {
+    result: bool,
+    other_result: bool,
+    deltas: Vec<EffectDelta>,
+    first_divergence: Option<EffectDelta>,
+    /// Error messages of causaloids that failed in the first run, by index.
+    failures: Vec<(usize, String)>,
+    /// Error messages of causaloids that failed in the second run, by index.
+    other_failures: Vec<(usize, String)>,
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::{EffectDelta, RootCause};
+
+impl Display for EffectDelta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EffectDelta: index: {}, observed: {:?}, counterfactual: {:?}",
+            self.index, self.observed, self.counterfactual
+        )
+    }
+}
+
+impl Display for RootCause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let verdict = if self.outcome_flipped {
+            "resolves"
+        } else {
+            "does not resolve"
+        };
+
+        write!(
+            f,
+            "RootCause: causaloid {} at index {}: restoring its baseline data {} the anomalous outcome and changes {} causaloid(s)",
+            self.causaloid_id,
+            self.index,
+            verdict,
+            self.deltas.len()
+        )
+    }
+}
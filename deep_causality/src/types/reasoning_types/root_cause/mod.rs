@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality_macros::{Constructor, Getters};
+
+use crate::prelude::IdentificationValue;
+
+mod display;
+
+/// Change of the effect of a single causaloid between an observed and a counterfactual evaluation.
+/// An effect of None means the causaloid was not evaluated.
+#[derive(Getters, Constructor, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct EffectDelta {
+    index: usize,
+    observed: Option<bool>,
+    counterfactual: Option<bool>,
+}
+
+/// Upstream causaloid ranked by its counterfactual contribution to an anomalous outcome.
+///
+/// The counterfactual is computed by restoring the baseline data of only this causaloid
+/// and reasoning over the graph again. The deltas justify the ranking by listing every
+/// causaloid whose effect changed under the counterfactual.
+#[derive(Getters, Constructor, Debug, Clone, PartialEq)]
+pub struct RootCause {
+    index: usize,
+    causaloid_id: IdentificationValue,
+    /// True if restoring the baseline data of this causaloid resolves the anomalous outcome.
+    outcome_flipped: bool,
+    deltas: Vec<EffectDelta>,
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+/// Escapes a label for use inside a quoted Graphviz DOT string.
+/// Backslashes and double quotes are escaped and line breaks become DOT line breaks.
+pub fn escape_dot_label(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+
+    for c in label.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hasher;
+
+use ultragraph::prelude::*;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Hasher that computes stable content fingerprints.
+///
+/// Unlike the std DefaultHasher, the FNV-1a hash is fully specified and unseeded,
+/// therefore the same bytes always yield the same fingerprint across runs and releases.
+/// Integers are hashed in little endian byte order and usize / isize are widened to 64 bit
+/// so that fingerprints are identical across platforms.
+///
+/// The encoding of a value passed through std::hash::Hash is defined by its Hash impl,
+/// which std does not guarantee across Rust releases. Fingerprints that must remain stable
+/// across releases write each field explicitly with write_u64, write_len, write_text,
+/// write_option_u64, and write_option_text.
+#[derive(Debug, Copy, Clone)]
+pub struct FingerprintHasher {
+    state: u64,
+}
+
+impl Default for FingerprintHasher {
+    fn default() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+impl Hasher for FingerprintHasher {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64)
+    }
+}
+
+impl FingerprintHasher {
+    /// Writes a length or count as 64 bit little endian integer.
+    pub fn write_len(&mut self, len: usize) {
+        self.write_u64(len as u64)
+    }
+
+    /// Writes a string as its length followed by its UTF-8 bytes.
+    pub fn write_text(&mut self, text: &str) {
+        self.write_len(text.len());
+        self.write(text.as_bytes())
+    }
+
+    /// Writes an optional integer as tag byte, 0 for None and 1 for Some,
+    /// followed by the value, if any.
+    pub fn write_option_u64(&mut self, value: Option<u64>) {
+        match value {
+            Some(value) => {
+                self.write_u8(1);
+                self.write_u64(value)
+            }
+            None => self.write_u8(0),
+        }
+    }
+
+    /// Writes an optional string as tag byte, 0 for None and 1 for Some,
+    /// followed by the string, if any.
+    pub fn write_option_text(&mut self, text: Option<&str>) {
+        match text {
+            Some(text) => {
+                self.write_u8(1);
+                self.write_text(text)
+            }
+            None => self.write_u8(0),
+        }
+    }
+}
+
+/// Returns the fingerprint of explicitly serialized bytes.
+///
+/// The fingerprint only depends on the given bytes, hence it is stable
+/// across runs, platforms, and releases.
+pub fn fingerprint(bytes: &[u8]) -> u64 {
+    let mut hasher = FingerprintHasher::default();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// Writes the structure of a graph into the hasher.
+///
+/// Nodes are hashed in index order and edges are sorted before hashing
+/// because the underlying storage does not guarantee a stable iteration order.
+/// The content of each node is hashed by the given function.
+pub(crate) fn hash_graph<T, F>(graph: &UltraGraph<T>, state: &mut FingerprintHasher, hash_node: F)
+where
+    F: Fn(&T, &mut FingerprintHasher),
+{
+    let number_nodes = graph.number_nodes();
+    state.write_len(number_nodes);
+    state.write_option_u64(graph.get_root_index().map(|index| index as u64));
+
+    // Node indices may have gaps after removal hence iterate until all nodes have been found.
+    let mut found = 0;
+    let mut index = 0;
+    while found < number_nodes {
+        if let Some(node) = graph.get_node(index) {
+            state.write_len(index);
+            hash_node(node, state);
+            found += 1;
+        }
+        index += 1;
+    }
+
+    let mut edges = graph.get_all_edges();
+    edges.sort_unstable();
+    state.write_len(edges.len());
+    for (source, target) in edges {
+        state.write_len(source);
+        state.write_len(target);
+    }
+}
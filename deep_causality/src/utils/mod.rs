@@ -1,5 +1,9 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
+pub mod dot_utils;
+pub mod fingerprint_utils;
+pub mod json_utils;
 pub mod math_utils;
+pub mod resample_utils;
 pub mod time_utils;
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::ResampleMethod;
+
+/// Returns the times of a regular grid of len points starting at start.
+pub fn grid_times(start: u64, step: u64, len: usize) -> Vec<u64> {
+    (0..len as u64)
+        .map(|i| start.saturating_add(i.saturating_mul(step)))
+        .collect()
+}
+
+/// Resamples a series of (time, value) samples, sorted ascending by time,
+/// onto the grid of len points starting at start with the given step.
+///
+/// Returns one value per grid time, or None where the method yields no value,
+/// i.e. before the first sample, beyond the last sample when interpolating,
+/// or for an empty bucket. Returns an empty vector if step is zero.
+pub fn resample(
+    samples: &[(u64, f64)],
+    start: u64,
+    step: u64,
+    len: usize,
+    method: ResampleMethod,
+) -> Vec<Option<f64>> {
+    if step == 0 {
+        return Vec::new();
+    }
+
+    grid_times(start, step, len)
+        .into_iter()
+        .map(|time| match method {
+            ResampleMethod::ForwardFill => forward_fill(samples, time),
+            ResampleMethod::Linear => interpolate(samples, time),
+            _ => aggregate(bucket(samples, time, step), method),
+        })
+        .collect()
+}
+
+/// Aligns multiple series onto the common grid of len points starting at start.
+///
+/// Returns, for each grid time at which every series has a value, the time
+/// and the values of all series in the order of the given series.
+/// Grid times at which any series lacks a value are skipped.
+pub fn align_series(
+    series: &[&[(u64, f64)]],
+    start: u64,
+    step: u64,
+    len: usize,
+    method: ResampleMethod,
+) -> Vec<(u64, Vec<f64>)> {
+    if step == 0 {
+        return Vec::new();
+    }
+
+    let resampled: Vec<Vec<Option<f64>>> = series
+        .iter()
+        .map(|samples| resample(samples, start, step, len, method))
+        .collect();
+
+    grid_times(start, step, len)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, time)| {
+            let row: Option<Vec<f64>> = resampled.iter().map(|values| values[i]).collect();
+            row.map(|values| (time, values))
+        })
+        .collect()
+}
+
+fn forward_fill(samples: &[(u64, f64)], time: u64) -> Option<f64> {
+    let end = samples.partition_point(|(t, _)| *t <= time);
+    end.checked_sub(1).map(|i| samples[i].1)
+}
+
+fn interpolate(samples: &[(u64, f64)], time: u64) -> Option<f64> {
+    let end = samples.partition_point(|(t, _)| *t <= time);
+    let (t0, v0) = samples[end.checked_sub(1)?];
+    if t0 == time {
+        return Some(v0);
+    }
+
+    let (t1, v1) = samples.get(end)?;
+    let fraction = (time - t0) as f64 / (t1 - t0) as f64;
+    Some(v0 + (v1 - v0) * fraction)
+}
+
+fn bucket(samples: &[(u64, f64)], time: u64, step: u64) -> &[(u64, f64)] {
+    let begin = samples.partition_point(|(t, _)| *t < time);
+    let end = samples.partition_point(|(t, _)| *t < time.saturating_add(step));
+    &samples[begin..end]
+}
+
+fn aggregate(bucket: &[(u64, f64)], method: ResampleMethod) -> Option<f64> {
+    let values = bucket.iter().map(|(_, v)| *v);
+    match method {
+        ResampleMethod::Mean if !bucket.is_empty() => {
+            Some(values.sum::<f64>() / bucket.len() as f64)
+        }
+        ResampleMethod::Min => values.reduce(f64::min),
+        ResampleMethod::Max => values.reduce(f64::max),
+        ResampleMethod::Last => bucket.last().map(|(_, v)| *v),
+        _ => None,
+    }
+}
@@ -1,6 +1,10 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
+use std::collections::{BTreeMap, HashMap};
+
+use crate::prelude::{Temporable, TimeScale};
+
 pub fn time_execution<T, F: FnOnce() -> T>(f: F, f_name: &str) -> T {
     let start = std::time::Instant::now();
     let res = f();
@@ -11,3 +15,66 @@ pub fn time_execution<T, F: FnOnce() -> T>(f: F, f_name: &str) -> T {
     );
     res
 }
+
+/// Groups temporal contextoids by the unit of the coarser scale that contains them,
+/// i.e. millisecond telemetry by second.
+///
+/// Returns a map from the unit of the target scale to the indices of all contained times
+/// in ascending order, or None if any time cannot be downsampled to the target scale.
+pub fn downsample_times<T>(times: &[T], to: TimeScale) -> Option<BTreeMap<u64, Vec<usize>>>
+where
+    T: Temporable<u64>,
+{
+    let mut buckets: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+
+    for (index, time) in times.iter().enumerate() {
+        let unit = time.time_scale().downsample(*time.time_unit(), to)?;
+        buckets.entry(unit).or_default().push(index);
+    }
+
+    Some(buckets)
+}
+
+/// Returns the half-open range of units of the finer scale covered by the temporal contextoid,
+/// or None if it cannot be upsampled to the target scale.
+pub fn upsample_time<T>(time: &T, to: TimeScale) -> Option<(u64, u64)>
+where
+    T: Temporable<u64>,
+{
+    time.time_scale().upsample(*time.time_unit(), to)
+}
+
+/// Aligns fine-grained times with coarse-grained times of possibly mixed scales,
+/// i.e. high-frequency telemetry with hourly business events.
+///
+/// Returns, for each fine time, the index of the first coarse time whose unit contains it,
+/// or None if no coarse time contains it.
+pub fn align_times<F, C>(fine: &[F], coarse: &[C]) -> Vec<Option<usize>>
+where
+    F: Temporable<u64>,
+    C: Temporable<u64>,
+{
+    let mut index: HashMap<(TimeScale, u64), usize> = HashMap::with_capacity(coarse.len());
+    let mut scales: Vec<TimeScale> = Vec::new();
+
+    for (i, time) in coarse.iter().enumerate() {
+        index
+            .entry((time.time_scale(), *time.time_unit()))
+            .or_insert(i);
+        if !scales.contains(&time.time_scale()) {
+            scales.push(time.time_scale());
+        }
+    }
+
+    fine.iter()
+        .map(|time| {
+            scales
+                .iter()
+                .filter_map(|scale| {
+                    let unit = time.time_scale().downsample(*time.time_unit(), *scale)?;
+                    index.get(&(*scale, unit)).copied()
+                })
+                .min()
+        })
+        .collect()
+}
@@ -16,4 +16,6 @@ mod causality_graph_error_tests;
 #[cfg(test)]
 mod context_index_error_tests;
 #[cfg(test)]
+mod reasoning_error_tests;
+#[cfg(test)]
 mod update_error_tests;
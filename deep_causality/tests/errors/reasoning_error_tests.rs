@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::{
+    ActionError, AdjustmentError, BuildError, CausalGraphIndexError, CausalityError,
+    CausalityGraphError, ContextIndexError, ReasoningError, ReasoningErrorKind, UpdateError,
+};
+use std::error::Error;
+
+#[test]
+fn test_reasoning_error_creation() {
+    let error = ReasoningError::new(ReasoningErrorKind::Evaluation, "test error message");
+    assert_eq!(error.kind(), ReasoningErrorKind::Evaluation);
+    assert_eq!(error.code(), 200);
+    assert_eq!(error.message(), "test error message");
+    assert_eq!(error.causaloid_id(), None);
+    assert!(!error.is_retriable());
+}
+
+#[test]
+fn test_reasoning_error_builder() {
+    let error = ReasoningError::new(ReasoningErrorKind::InvalidData, "NaN")
+        .with_causaloid_id(42)
+        .with_retriable(true);
+    assert_eq!(error.causaloid_id(), Some(42));
+    assert!(error.is_retriable());
+}
+
+#[test]
+fn test_reasoning_error_retriable_default() {
+    let error = ReasoningError::new(ReasoningErrorKind::Action, "failed");
+    assert!(error.is_retriable());
+
+    let error = ReasoningError::new(ReasoningErrorKind::GraphStructure, "failed");
+    assert!(!error.is_retriable());
+}
+
+#[test]
+fn test_reasoning_error_display() {
+    let error = ReasoningError::new(ReasoningErrorKind::Evaluation, "test error message");
+    assert_eq!(
+        format!("{}", error),
+        "ReasoningError[E200] Evaluation: test error message"
+    );
+
+    let error = error.with_causaloid_id(7);
+    assert_eq!(
+        format!("{}", error),
+        "ReasoningError[E200] Evaluation: test error message (causaloid: 7)"
+    );
+}
+
+#[test]
+fn test_reasoning_error_source_chain() {
+    let root = CausalityError::new("root cause".to_string());
+    let error =
+        ReasoningError::new(ReasoningErrorKind::Evaluation, "evaluation failed").with_source(root);
+
+    assert!(error.source().is_some());
+
+    let chain = error.chain();
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain[1], "CausalityError: root cause");
+}
+
+#[test]
+fn test_reasoning_error_from_legacy_errors() {
+    let error: ReasoningError = CausalityError::new("legacy".to_string()).into();
+    assert_eq!(error.kind(), ReasoningErrorKind::Unknown);
+    assert_eq!(error.message(), "legacy");
+
+    let error: ReasoningError = CausalityGraphError::new("no root".to_string()).into();
+    assert_eq!(error.kind(), ReasoningErrorKind::GraphStructure);
+
+    let error: ReasoningError = ActionError::new("action".to_string()).into();
+    assert_eq!(error.kind(), ReasoningErrorKind::Action);
+    assert!(error.is_retriable());
+
+    let error: ReasoningError = CausalGraphIndexError::new("index".to_string()).into();
+    assert_eq!(error.kind(), ReasoningErrorKind::Index);
+
+    let error: ReasoningError = ContextIndexError::new("context".to_string()).into();
+    assert_eq!(error.kind(), ReasoningErrorKind::Context);
+
+    let error: ReasoningError = UpdateError::new("update".to_string()).into();
+    assert_eq!(error.kind(), ReasoningErrorKind::Update);
+
+    let error: ReasoningError = AdjustmentError::new("adjustment".to_string()).into();
+    assert_eq!(error.kind(), ReasoningErrorKind::Adjustment);
+
+    let error: ReasoningError = BuildError::new("build".to_string()).into();
+    assert_eq!(error.kind(), ReasoningErrorKind::Build);
+}
+
+#[test]
+fn test_reasoning_error_into_causality_error() {
+    let error = ReasoningError::new(ReasoningErrorKind::Index, "index 3 not found");
+    let legacy: CausalityError = error.into();
+    assert_eq!(legacy.0, "ReasoningError[E400] Index: index 3 not found");
+}
+
+#[test]
+fn test_reasoning_error_is_error() {
+    let error = ReasoningError::new(ReasoningErrorKind::Unknown, "test");
+    let is_error: &dyn Error = &error;
+    assert!(is_error.is::<ReasoningError>());
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::VecDeque;
+
+use dcl_data_structures::grid_type::{ArrayGrid, ArrayType};
+use dcl_data_structures::prelude::PointIndex;
+use deep_causality::prelude::{AdjustableData, BatchAdjustable};
+
+type AdjustmentData = ArrayGrid<i32, 1, 1, 1, 1>;
+
+fn get_1d_array_grid(val: i32) -> AdjustmentData {
+    let ag: AdjustmentData = ArrayGrid::new(ArrayType::Array1D);
+    ag.set(PointIndex::new1d(0), val);
+    ag
+}
+
+#[test]
+fn test_adjust_all() {
+    let mut nodes = VecDeque::from_iter([AdjustableData::new(1, 10), AdjustableData::new(2, 20)]);
+
+    let plus_one = get_1d_array_grid(1);
+    let undo = nodes
+        .adjust_all(&[(2, &plus_one)])
+        .expect("Failed to adjust");
+
+    assert_eq!(nodes[1].data(), &21);
+
+    nodes.undo_adjustments(undo).expect("Failed to undo");
+    assert_eq!(nodes[1].data(), &20);
+}
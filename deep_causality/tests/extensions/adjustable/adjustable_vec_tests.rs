@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use dcl_data_structures::grid_type::{ArrayGrid, ArrayType};
+use dcl_data_structures::prelude::PointIndex;
+use deep_causality::prelude::{AdjustableData, BatchAdjustable};
+
+type AdjustmentData = ArrayGrid<i32, 1, 1, 1, 1>;
+
+fn get_1d_array_grid(val: i32) -> AdjustmentData {
+    let ag: AdjustmentData = ArrayGrid::new(ArrayType::Array1D);
+    ag.set(PointIndex::new1d(0), val);
+    ag
+}
+
+fn get_test_nodes() -> Vec<AdjustableData<i32>> {
+    Vec::from_iter([
+        AdjustableData::new(1, 10),
+        AdjustableData::new(2, 20),
+        AdjustableData::new(3, 30),
+    ])
+}
+
+fn get_data(nodes: &[AdjustableData<i32>]) -> Vec<i32> {
+    nodes.iter().map(|n| *n.data()).collect()
+}
+
+#[test]
+fn test_adjust_all() {
+    let mut nodes = get_test_nodes();
+
+    let plus_one = get_1d_array_grid(1);
+    let plus_five = get_1d_array_grid(5);
+
+    let undo = nodes
+        .adjust_all(&[(1, &plus_one), (3, &plus_five)])
+        .expect("Failed to adjust");
+
+    assert_eq!(get_data(&nodes), vec![11, 20, 35]);
+    assert_eq!(undo.len(), 2);
+    assert_eq!(undo.originals()[0], AdjustableData::new(1, 10));
+
+    let res = nodes.undo_adjustments(undo);
+    assert!(res.is_ok());
+    assert_eq!(get_data(&nodes), vec![10, 20, 30]);
+}
+
+#[test]
+fn test_adjust_all_is_atomic() {
+    let mut nodes = get_test_nodes();
+
+    let plus_one = get_1d_array_grid(1);
+    // Adjusting node 2 by -25 yields a negative number and fails.
+    let minus = get_1d_array_grid(-25);
+
+    let res = nodes.adjust_all(&[(1, &plus_one), (2, &minus), (3, &plus_one)]);
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "AdjustmentError: Adjustment of node 2 failed: Adjustment failed, result is a negative number"
+    );
+
+    // No node was adjusted.
+    assert_eq!(get_data(&nodes), vec![10, 20, 30]);
+}
+
+#[test]
+fn test_adjust_all_err() {
+    let mut nodes = get_test_nodes();
+    let plus_one = get_1d_array_grid(1);
+
+    // Node does not exist
+    let res = nodes.adjust_all(&[(1, &plus_one), (42, &plus_one)]);
+    assert!(res.is_err());
+
+    // Node listed twice
+    let res = nodes.adjust_all(&[(1, &plus_one), (1, &plus_one)]);
+    assert!(res.is_err());
+
+    assert_eq!(get_data(&nodes), vec![10, 20, 30]);
+
+    // Empty batch
+    let undo = nodes
+        .adjust_all::<1, 1, 1, 1>(&[])
+        .expect("Failed to adjust");
+    assert!(undo.is_empty());
+}
+
+#[test]
+fn test_undo_adjustments_err() {
+    let mut nodes = get_test_nodes();
+    let plus_one = get_1d_array_grid(1);
+
+    let undo = nodes
+        .adjust_all(&[(1, &plus_one), (3, &plus_one)])
+        .expect("Failed to adjust");
+
+    // Node 3 no longer exists
+    nodes.pop();
+    let res = nodes.undo_adjustments(undo);
+    assert!(res.is_err());
+
+    // Node 1 keeps the adjusted value.
+    assert_eq!(get_data(&nodes), vec![11, 20]);
+}
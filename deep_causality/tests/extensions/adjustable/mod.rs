@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+#[cfg(test)]
+mod adjustable_vec_deque_tests;
+#[cfg(test)]
+mod adjustable_vec_tests;
@@ -117,3 +117,21 @@ fn test_is_empty() {
     let col = get_test_inf_arr();
     assert!(!col.is_empty());
 }
+
+#[test]
+fn test_posterior_inferable() {
+    let col = get_test_inf_arr();
+    let prior = BetaDistribution::uniform();
+
+    let posterior = col.posterior_inferable(prior);
+    assert_eq!(posterior, BetaDistribution::new(2.0, 2.0).unwrap());
+
+    let posterior = col.posterior_inverse_inferable(prior);
+    assert_eq!(posterior, BetaDistribution::new(2.0, 2.0).unwrap());
+
+    let f = get_test_inferable(3, false);
+    let col = [f.clone(), f.clone(), f];
+    let posterior = col.posterior_inferable(prior);
+    assert_eq!(posterior.mean(), 0.8);
+    assert_eq!(col.posterior_inverse_inferable(prior).mean(), 0.2);
+}
@@ -124,3 +124,16 @@ fn test_is_empty() {
     let col = get_test_inf_vec();
     assert!(!col.is_empty());
 }
+
+#[test]
+fn test_posterior_inferable() {
+    let col = get_test_inf_vec();
+    let prior = BetaDistribution::new(2.0, 8.0).unwrap();
+
+    let posterior = col.posterior_inferable(prior);
+    assert_eq!(posterior.alpha(), 3.0);
+    assert_eq!(posterior.beta(), 9.0);
+
+    let empty: Vec<Inference> = Vec::new();
+    assert_eq!(empty.posterior_inferable(prior), prior);
+}
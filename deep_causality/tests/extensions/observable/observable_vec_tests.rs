@@ -75,3 +75,21 @@ fn test_is_empty() {
     let col = get_test_obs_vec();
     assert!(!col.is_empty());
 }
+
+#[test]
+fn test_observation_statistics() {
+    let observations = get_test_obs_vec();
+    let target_threshold = 10.0;
+    let target_effect = 1.0;
+
+    let mut stats = observations.observation_statistics(target_threshold, target_effect);
+    assert_eq!(5, stats.len());
+    assert_eq!(
+        observations.percent_observation(target_threshold, target_effect),
+        stats.percent_observation()
+    );
+
+    stats.update(&get_test_observation());
+    assert_eq!(6, stats.len());
+    assert_eq!(4.0, stats.number_observation());
+}
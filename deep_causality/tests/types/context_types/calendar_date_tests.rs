@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::{days_in_month, is_leap_year, CalendarDate, TimeScale};
+
+fn date(year: i32, month: u32, day: u32) -> CalendarDate {
+    CalendarDate::new(year, month, day).expect("valid date")
+}
+
+#[test]
+fn test_new() {
+    let d = date(2024, 2, 29);
+    assert_eq!(d.year(), 2024);
+    assert_eq!(d.month(), 2);
+    assert_eq!(d.day(), 29);
+    assert_eq!(d.to_string(), "2024-02-29");
+}
+
+#[test]
+fn test_new_err() {
+    assert!(CalendarDate::new(2023, 2, 29).is_err());
+    assert!(CalendarDate::new(2023, 13, 1).is_err());
+    assert!(CalendarDate::new(2023, 4, 0).is_err());
+
+    let err = CalendarDate::new(2023, 4, 31).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "BuildError: Day 31 is out of range 1..=30 for 2023-04"
+    );
+}
+
+#[test]
+fn test_leap_years() {
+    assert!(is_leap_year(2024));
+    assert!(is_leap_year(2000));
+    assert!(!is_leap_year(1900));
+    assert!(!is_leap_year(2023));
+
+    assert_eq!(days_in_month(2024, 2), 29);
+    assert_eq!(days_in_month(2023, 2), 28);
+    assert_eq!(days_in_month(2023, 13), 0);
+}
+
+#[test]
+fn test_weekday() {
+    assert_eq!(date(1970, 1, 1).weekday(), 4);
+    assert_eq!(date(2024, 1, 1).weekday(), 1);
+    assert_eq!(date(2023, 12, 31).weekday(), 7);
+    assert!(date(2023, 12, 30).is_weekend());
+    assert!(date(2023, 12, 29).is_business_day());
+}
+
+#[test]
+fn test_add_days() {
+    assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+    assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+    assert_eq!(date(1969, 12, 31).add_days(366), date(1971, 1, 1));
+    assert_eq!(date(2024, 1, 1).days_until(&date(2025, 1, 1)), 366);
+    assert_eq!(date(2024, 1, 2).days_until(&date(2024, 1, 1)), -1);
+}
+
+#[test]
+fn test_add_months() {
+    assert_eq!(date(2023, 1, 31).add_months(1), date(2023, 2, 28));
+    assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+    assert_eq!(date(2023, 11, 15).add_months(3), date(2024, 2, 15));
+    assert_eq!(date(2023, 1, 15).add_months(-2), date(2022, 11, 15));
+}
+
+#[test]
+fn test_add_quarters_and_years() {
+    assert_eq!(date(2023, 11, 30).add_quarters(1), date(2024, 2, 29));
+    assert_eq!(date(2023, 5, 31).add_quarters(-1), date(2023, 2, 28));
+    assert_eq!(date(2024, 2, 29).add_years(1), date(2025, 2, 28));
+    assert_eq!(date(2023, 8, 1).quarter(), 3);
+}
+
+#[test]
+fn test_add_business_days() {
+    // Friday
+    let friday = date(2024, 1, 5);
+    assert_eq!(friday.add_business_days(1), date(2024, 1, 8));
+    assert_eq!(friday.add_business_days(5), date(2024, 1, 12));
+    assert_eq!(friday.add_business_days(0), friday);
+    assert_eq!(date(2024, 1, 8).add_business_days(-1), friday);
+
+    // Saturday rolls forward or backward before stepping.
+    let saturday = date(2024, 1, 6);
+    assert_eq!(saturday.add_business_days(0), date(2024, 1, 8));
+    assert_eq!(saturday.add_business_days(1), date(2024, 1, 9));
+    assert_eq!(saturday.add_business_days(-1), date(2024, 1, 4));
+}
+
+#[test]
+fn test_add_time_scale() {
+    let d = date(2024, 1, 31);
+    assert_eq!(d.add(1, TimeScale::Day), Some(date(2024, 2, 1)));
+    assert_eq!(d.add(2, TimeScale::Week), Some(date(2024, 2, 14)));
+    assert_eq!(d.add(1, TimeScale::Month), Some(date(2024, 2, 29)));
+    assert_eq!(d.add(1, TimeScale::Quarter), Some(date(2024, 4, 30)));
+    assert_eq!(d.add(1, TimeScale::Year), Some(date(2025, 1, 31)));
+    assert_eq!(d.add(1, TimeScale::Hour), None);
+    assert_eq!(d.add(1, TimeScale::NoScale), None);
+}
+
+#[test]
+fn test_ordering() {
+    assert!(date(2023, 12, 31) < date(2024, 1, 1));
+    assert!(date(2024, 2, 1) > date(2024, 1, 31));
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+
+fn add_data(ctx: &mut BaseContext, id: u64, value: BaseNumberType) -> usize {
+    let contextoid = Contextoid::new(id, ContextoidType::Datoid(Data::new(id, value)));
+    ctx.add_node(contextoid)
+}
+
+// Root (id 1) -> data 2 -> data 3
+fn get_baseline() -> BaseContext {
+    let mut ctx = get_test_context();
+    let a = add_data(&mut ctx, 2, 20);
+    let b = add_data(&mut ctx, 3, 30);
+
+    ctx.add_edge(0, a, RelationKind::Datial).unwrap();
+    ctx.add_edge(a, b, RelationKind::Temporal).unwrap();
+    ctx
+}
+
+#[test]
+fn test_diff_equal() {
+    let baseline = get_baseline();
+    let delta = baseline.diff(&get_baseline());
+
+    assert!(delta.is_empty());
+    assert_eq!(delta.len(), 0);
+}
+
+#[test]
+fn test_diff() {
+    let baseline = get_baseline();
+
+    let mut other = get_baseline();
+    // Update data 2, remove data 3, add data 4 with an edge from data 2.
+    other.remove_node(2).expect("Failed to remove contextoid");
+    let d = add_data(&mut other, 4, 40);
+    other.add_edge(1, d, RelationKind::Datial).unwrap();
+    other.remove_edge(0, 1).unwrap();
+    other.add_edge(0, 1, RelationKind::Spatial).unwrap();
+
+    let delta = baseline.diff(&other);
+
+    assert_eq!(delta.added_nodes().len(), 1);
+    assert_eq!(delta.added_nodes()[0].id(), 4);
+    assert!(delta.updated_nodes().is_empty());
+    assert_eq!(delta.removed_nodes(), &vec![3]);
+    assert_eq!(
+        delta.added_edges(),
+        &vec![(1, 2, RelationKind::Spatial), (2, 4, RelationKind::Datial)]
+    );
+    assert_eq!(delta.removed_edges(), &vec![(2, 3)]);
+    assert_eq!(delta.len(), 5);
+}
+
+#[test]
+fn test_diff_updated_node() {
+    let baseline = get_baseline();
+
+    let mut other = get_test_context();
+    let a = add_data(&mut other, 2, 99);
+    let b = add_data(&mut other, 3, 30);
+    other.add_edge(0, a, RelationKind::Datial).unwrap();
+    other.add_edge(a, b, RelationKind::Temporal).unwrap();
+
+    let delta = baseline.diff(&other);
+    assert_eq!(delta.len(), 1);
+    assert_eq!(delta.updated_nodes().len(), 1);
+    assert_eq!(delta.updated_nodes()[0].id(), 2);
+}
+
+#[test]
+fn test_merge() {
+    let baseline = get_baseline();
+
+    let mut other = get_baseline();
+    other.remove_node(2).expect("Failed to remove contextoid");
+    let d = add_data(&mut other, 4, 40);
+    other.add_edge(1, d, RelationKind::Datial).unwrap();
+
+    let mut merged = get_baseline();
+    merged.merge(&baseline.diff(&other)).unwrap();
+
+    assert_eq!(merged.node_count(), 3);
+    assert_eq!(merged.edge_count(), 2);
+    assert!(merged.diff(&other).is_empty());
+}
+
+#[test]
+fn test_merge_updated_node() {
+    let mut other = get_test_context();
+    let a = add_data(&mut other, 2, 99);
+    let b = add_data(&mut other, 3, 30);
+    other.add_edge(0, a, RelationKind::Datial).unwrap();
+    other.add_edge(a, b, RelationKind::Temporal).unwrap();
+
+    let mut merged = get_baseline();
+    let delta = merged.diff(&other);
+    merged.merge(&delta).unwrap();
+
+    let node = merged.get_node(1).unwrap();
+    assert_eq!(*node.vertex_type().dataoid().unwrap().data(), 99);
+    assert!(merged.contains_edge(1, 2));
+    assert!(merged.diff(&other).is_empty());
+}
+
+#[test]
+fn test_merge_error() {
+    let baseline = get_baseline();
+
+    // Delta removes contextoid 3, which the empty context does not contain.
+    let mut other = get_baseline();
+    other.remove_node(2).expect("Failed to remove contextoid");
+    let delta = baseline.diff(&other);
+
+    let mut ctx = get_test_context();
+    assert!(ctx.merge(&delta).is_err());
+
+    // Delta adds contextoid 2, which already exists.
+    let delta = get_test_context().diff(&baseline);
+    let mut ctx = get_baseline();
+    assert!(ctx.merge(&delta).is_err());
+}
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::sync::mpsc::Receiver;
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+
+fn data(id: u64, value: BaseNumberType) -> BaseContextoid {
+    Contextoid::new(id, ContextoidType::Datoid(Data::new(id, value)))
+}
+
+fn received(changes: &Receiver<u64>) -> Vec<u64> {
+    changes.try_iter().collect()
+}
+
+#[test]
+fn test_update_node() {
+    let mut ctx = get_test_context();
+    let index = ctx.add_node(data(2, 20));
+    ctx.add_edge(0, index, RelationKind::Datial).unwrap();
+
+    assert!(ctx.update_node(index, data(2, 25)).is_ok());
+    assert_eq!(*ctx.get_data_by_id(2).unwrap().data(), 25);
+    assert!(ctx.contains_edge(0, index));
+
+    // Updating with a new id moves the id lookup.
+    assert!(ctx.update_node(index, data(3, 30)).is_ok());
+    assert!(ctx.get_node_by_id(2).is_none());
+    assert_eq!(ctx.get_node_index_by_id(3), Some(index));
+
+    assert!(ctx.update_node(99, data(4, 40)).is_err());
+}
+
+#[test]
+fn test_subscribe() {
+    let mut ctx = get_test_context();
+    assert_eq!(ctx.number_of_subscribers(), 0);
+
+    let changes = ctx.subscribe();
+    assert_eq!(ctx.number_of_subscribers(), 1);
+
+    let index = ctx.add_node(data(2, 20));
+    ctx.update_node(index, data(2, 25)).unwrap();
+    ctx.update_data_by_id(2, Data::new(2, 30)).unwrap();
+    ctx.update_node(index, data(3, 30)).unwrap();
+    ctx.remove_node(index).unwrap();
+    assert_eq!(received(&changes), vec![2, 2, 2, 2, 3, 3]);
+
+    // Edges are not published.
+    let index = ctx.add_node(data(4, 40));
+    ctx.add_edge(0, index, RelationKind::Datial).unwrap();
+    assert_eq!(received(&changes), vec![4]);
+
+    // Dropped receivers are removed on the next change.
+    let other = ctx.subscribe();
+    assert_eq!(ctx.number_of_subscribers(), 2);
+    drop(changes);
+    ctx.update_data_by_id(4, Data::new(4, 41)).unwrap();
+    assert_eq!(ctx.number_of_subscribers(), 1);
+    assert_eq!(received(&other), vec![4]);
+}
+
+#[test]
+fn test_subscribe_merge() {
+    let mut baseline = get_test_context();
+    baseline.add_node(data(2, 20));
+    baseline.add_node(data(3, 30));
+
+    let mut other = get_test_context();
+    other.add_node(data(2, 25));
+    other.add_node(data(4, 40));
+
+    let delta = baseline.diff(&other);
+    let changes = baseline.subscribe();
+    baseline.merge(&delta).unwrap();
+
+    assert_eq!(received(&changes), vec![3, 2, 4]);
+}
+
+#[test]
+fn test_subscribe_clone() {
+    let mut ctx = get_test_context();
+    let changes = ctx.subscribe();
+
+    // A clone starts without subscribers and does not publish to those of the original.
+    let mut clone = ctx.clone();
+    assert_eq!(clone.number_of_subscribers(), 0);
+    assert_eq!(ctx.number_of_subscribers(), 1);
+
+    let index = clone.add_node(data(2, 20));
+    clone.update_node(index, data(2, 25)).unwrap();
+    clone.remove_node(index).unwrap();
+    assert!(received(&changes).is_empty());
+
+    // The original still publishes to its subscribers.
+    ctx.add_node(data(3, 30));
+    assert_eq!(received(&changes), vec![3]);
+}
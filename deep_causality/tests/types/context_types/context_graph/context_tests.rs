@@ -2,8 +2,9 @@
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
 use deep_causality::prelude::{
-    BaseContext, Context, Contextoid, ContextoidType, ContextuableGraph, Identifiable,
-    RelationKind, Root, Time, TimeScale,
+    BaseContext, Context, Contextoid, ContextoidType, ContextuableGraph,
+    ExtendableContextuableGraph, Fingerprintable, Identifiable, RelationKind, Root, Time,
+    TimeScale,
 };
 
 fn get_context<'l>() -> BaseContext {
@@ -167,6 +168,34 @@ fn test_add_edge() {
     assert!(context.contains_edge(roodidx, t_idx));
 }
 
+#[test]
+fn test_export_dot() {
+    let mut context = get_context();
+
+    let root = Root::new(1);
+    let roodidx = context.add_node(Contextoid::new(1, ContextoidType::Root(root)));
+
+    let tempoid = Time::new(12, TimeScale::Month, 12);
+    let t_idx = context.add_node(Contextoid::new(2, ContextoidType::Tempoid(tempoid)));
+
+    context
+        .add_edge(roodidx, t_idx, RelationKind::Temporal)
+        .expect("Failed to add edge");
+
+    let dot = context.export_dot();
+    assert!(dot.starts_with("digraph \"base context\" {\n"));
+    assert!(dot.contains(&format!(
+        "    n{} [label=\"{}\"];",
+        roodidx,
+        context.get_node(roodidx).unwrap()
+    )));
+    assert!(dot.contains(&format!(
+        "    n{} -> n{} [label=\"Temporal\"];",
+        roodidx, t_idx
+    )));
+    assert!(dot.ends_with("}\n"));
+}
+
 #[test]
 fn test_contains_edge() {
     let id = 1;
@@ -252,3 +281,36 @@ fn test_to_string() {
     let act = context.to_string();
     assert_eq!(exp, act);
 }
+
+#[test]
+fn test_fingerprint() {
+    let mut context = get_context();
+    let mut other = get_context();
+    assert_eq!(context.fingerprint(), other.fingerprint());
+
+    let a = context.add_node(Contextoid::new(1, ContextoidType::Root(Root::new(1))));
+    let b = context.add_node(Contextoid::new(2, ContextoidType::Root(Root::new(2))));
+    let fingerprint_nodes = context.fingerprint();
+    assert_ne!(fingerprint_nodes, other.fingerprint());
+
+    other.add_node(Contextoid::new(1, ContextoidType::Root(Root::new(1))));
+    other.add_node(Contextoid::new(2, ContextoidType::Root(Root::new(2))));
+    assert_eq!(fingerprint_nodes, other.fingerprint());
+
+    context
+        .add_edge(a, b, RelationKind::Temporal)
+        .expect("Failed to add edge");
+    assert_ne!(context.fingerprint(), fingerprint_nodes);
+
+    // Same content, different name
+    let renamed: BaseContext = Context::with_capacity(1, "other context", 10);
+    assert_ne!(renamed.fingerprint(), get_context().fingerprint());
+
+    // Empty extra contexts are counted.
+    let fingerprint_edges = context.fingerprint();
+    context.extra_ctx_add_new(10, false);
+    let fingerprint_one_extra = context.fingerprint();
+    assert_ne!(fingerprint_one_extra, fingerprint_edges);
+    context.extra_ctx_add_new(10, false);
+    assert_ne!(context.fingerprint(), fingerprint_one_extra);
+}
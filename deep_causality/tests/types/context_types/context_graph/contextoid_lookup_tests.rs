@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+
+fn get_context() -> BaseContext {
+    let mut ctx = get_test_context();
+
+    let data = Contextoid::new(2, ContextoidType::Datoid(Data::new(2, 42)));
+    ctx.add_node(data);
+
+    let time = Contextoid::new(
+        3,
+        ContextoidType::Tempoid(Time::new(3, TimeScale::Minute, 7)),
+    );
+    ctx.add_node(time);
+
+    let space = Contextoid::new(4, ContextoidType::Spaceoid(Space::new(4, 1, 2, 3)));
+    ctx.add_node(space);
+
+    ctx
+}
+
+#[test]
+fn test_get_node_by_id() {
+    let ctx = get_context();
+
+    assert_eq!(ctx.get_node_index_by_id(1), Some(0));
+    assert_eq!(ctx.get_node_index_by_id(3), Some(2));
+    assert_eq!(ctx.get_node_by_id(2).unwrap().id(), 2);
+    assert!(ctx.get_node_by_id(99).is_none());
+}
+
+#[test]
+fn test_typed_accessors() {
+    let ctx = get_context();
+
+    assert_eq!(*ctx.get_data_by_id(2).unwrap().data(), 42);
+    assert_eq!(*ctx.get_time_by_id(3).unwrap().time_unit(), 7);
+    assert_eq!(*ctx.get_space_by_id(4).unwrap().x(), 1);
+    assert!(ctx.get_space_time_by_id(4).is_none());
+
+    // Wrong type or unknown id.
+    assert!(ctx.get_data_by_id(3).is_none());
+    assert!(ctx.get_time_by_id(2).is_none());
+    assert!(ctx.get_space_by_id(99).is_none());
+}
+
+#[test]
+fn test_update_data_by_id() {
+    let mut ctx = get_context();
+    ctx.add_edge(0, 1, RelationKind::Datial).unwrap();
+
+    ctx.update_data_by_id(2, Data::new(2, 99)).unwrap();
+    assert_eq!(*ctx.get_data_by_id(2).unwrap().data(), 99);
+    assert_eq!(ctx.get_node_index_by_id(2), Some(1));
+    assert!(ctx.contains_edge(0, 1));
+
+    assert!(ctx.update_data_by_id(3, Data::new(3, 1)).is_err());
+    assert!(ctx.update_data_by_id(99, Data::new(99, 1)).is_err());
+}
+
+#[test]
+fn test_lookup_after_remove() {
+    let mut ctx = get_context();
+
+    ctx.remove_node(1).unwrap();
+    assert!(ctx.get_node_by_id(2).is_none());
+    assert!(ctx.get_data_by_id(2).is_none());
+    assert!(ctx.get_time_by_id(3).is_some());
+}
@@ -1,8 +1,14 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 #[cfg(test)]
+mod context_diff_tests;
+#[cfg(test)]
+mod context_subscription_tests;
+#[cfg(test)]
 mod context_tests;
 #[cfg(test)]
+mod contextoid_lookup_tests;
+#[cfg(test)]
 mod extendable_context_tests;
 #[cfg(test)]
 mod graph_node_tests;
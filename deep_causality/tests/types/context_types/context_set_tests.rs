@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+fn get_context(id: u64, name: &str) -> BaseContext {
+    Context::with_capacity(id, name, 10)
+}
+
+#[test]
+fn test_new() {
+    let set = BaseContextSet::new();
+    assert!(set.is_empty());
+    assert_eq!(set.len(), 0);
+    assert!(set.get("baseline").is_none());
+}
+
+#[test]
+fn test_with_context() {
+    let baseline = get_context(1, "baseline");
+    let forecast = get_context(2, "forecast");
+
+    let set = BaseContextSet::new()
+        .with_context("forecast", &forecast)
+        .with_context("baseline", &baseline);
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains("baseline"));
+    assert!(!set.contains("policy"));
+    assert_eq!(set.get("forecast").unwrap().id(), 2);
+    assert_eq!(set.names(), vec!["baseline", "forecast"]);
+}
+
+#[test]
+fn test_insert() {
+    let first = get_context(1, "first");
+    let second = get_context(2, "second");
+
+    let mut set = BaseContextSet::new();
+    assert!(set.insert("telemetry", &first).is_none());
+
+    let previous = set.insert("telemetry", &second);
+    assert_eq!(previous.unwrap().id(), 1);
+    assert_eq!(set.get("telemetry").unwrap().id(), 2);
+    assert_eq!(set.len(), 1);
+}
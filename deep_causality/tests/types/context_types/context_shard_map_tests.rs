@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+
+// Effect is true if the observation reaches the number of nodes in the context.
+fn causal_fn(obs: NumericalValue, ctx: &BaseContext) -> Result<bool, CausalityError> {
+    if obs.is_nan() {
+        return Err(CausalityError("Observation is NULL/NAN".into()));
+    }
+
+    Ok(obs >= ctx.node_count() as NumericalValue)
+}
+
+fn add_node(ctx: &mut BaseContext, id: u64) {
+    let contextoid = Contextoid::new(id, ContextoidType::Root(Root::new(id)));
+    ctx.add_node(contextoid);
+}
+
+#[test]
+fn test_new() {
+    let shards = ContextShardMap::new(get_test_context());
+
+    assert_eq!(shards.template().id(), 1);
+    assert_eq!(shards.number_materialized(), 0);
+    assert!(!shards.is_materialized(42));
+
+    // Unmaterialized entities read the template.
+    assert_eq!(shards.get_shard(42).node_count(), 1);
+}
+
+#[test]
+fn test_get_shard_mut() {
+    let mut shards = ContextShardMap::new(get_test_context());
+
+    add_node(shards.get_shard_mut(7), 2);
+    assert!(shards.is_materialized(7));
+    assert_eq!(shards.get_shard(7).node_count(), 2);
+
+    // Other entities and the template are unaffected.
+    assert_eq!(shards.get_shard(8).node_count(), 1);
+    assert_eq!(shards.template().node_count(), 1);
+
+    // A second access returns the same materialized context.
+    add_node(shards.get_shard_mut(7), 3);
+    assert_eq!(shards.get_shard(7).node_count(), 3);
+
+    add_node(shards.get_shard_mut(3), 2);
+    assert_eq!(shards.materialized_ids(), vec![3, 7]);
+}
+
+#[test]
+fn test_reset_shard() {
+    let mut shards = ContextShardMap::new(get_test_context());
+    add_node(shards.get_shard_mut(7), 2);
+
+    let removed = shards.reset_shard(7).expect("Shard was materialized");
+    assert_eq!(removed.node_count(), 2);
+    assert!(!shards.is_materialized(7));
+    assert_eq!(shards.get_shard(7).node_count(), 1);
+
+    assert!(shards.reset_shard(7).is_none());
+}
+
+#[test]
+fn test_evaluate_all() {
+    let mut shards = ContextShardMap::new(get_test_context());
+    add_node(shards.get_shard_mut(2), 2);
+
+    let observations = [(1, 1.0), (2, 1.0), (3, NumericalValue::NAN), (4, 0.5)];
+    let evaluation = shards.evaluate_all(&observations, causal_fn);
+
+    // Entity 2 has two nodes in its context, so 1.0 is not enough.
+    assert_eq!(evaluation.effect_of(1), Some(true));
+    assert_eq!(evaluation.effect_of(2), Some(false));
+    assert_eq!(evaluation.effect_of(3), None);
+    assert_eq!(evaluation.effect_of(4), Some(false));
+
+    assert!(!evaluation.is_complete());
+    assert_eq!(evaluation.failures().len(), 1);
+    assert_eq!(
+        evaluation.failures()[&3].to_string(),
+        "CausalityError: Observation is NULL/NAN"
+    );
+
+    assert_eq!(evaluation.effects().len(), 3);
+    assert_eq!(evaluation.number_true(), 1);
+    assert_eq!(evaluation.number_false(), 2);
+    assert!((evaluation.percent_true() - 100.0 / 3.0).abs() < 1e-9);
+
+    assert_eq!(
+        evaluation.to_string(),
+        "ShardEvaluation: evaluated: 3, true: 1, false: 2, failed: 1"
+    );
+}
+
+#[test]
+fn test_evaluate_all_empty() {
+    let shards = ContextShardMap::new(get_test_context());
+    let evaluation = shards.evaluate_all(&[], causal_fn);
+
+    assert!(evaluation.is_complete());
+    assert_eq!(evaluation.percent_true(), 0.0);
+}
@@ -2,11 +2,23 @@
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
 mod adjustable;
+#[cfg(test)]
+mod calendar_date_tests;
 mod context_graph;
+#[cfg(test)]
+mod context_set_tests;
+#[cfg(test)]
+mod context_shard_map_tests;
 mod contextoid;
 mod node_types;
 
 #[cfg(test)]
 mod relation_kind_tests;
 #[cfg(test)]
+mod resample_method_tests;
+#[cfg(test)]
+mod shared_context_tests;
+#[cfg(test)]
 mod time_scale_tests;
+#[cfg(test)]
+mod time_series_context_tests;
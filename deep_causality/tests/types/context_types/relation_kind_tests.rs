@@ -30,3 +30,16 @@ fn test_space_temporal() {
     assert_eq!(rk, RelationKind::SpaceTemporal);
     assert_eq!(rk.to_string(), format!("SpaceTemporal"));
 }
+
+#[test]
+fn test_from_weight() {
+    for rk in [
+        RelationKind::Datial,
+        RelationKind::Temporal,
+        RelationKind::Spatial,
+        RelationKind::SpaceTemporal,
+    ] {
+        assert_eq!(RelationKind::from_weight(rk as u64), Some(rk));
+    }
+    assert_eq!(RelationKind::from_weight(4), None);
+}
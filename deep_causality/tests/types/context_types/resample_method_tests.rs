@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::ResampleMethod;
+
+#[test]
+fn test_is_aggregation() {
+    assert!(!ResampleMethod::ForwardFill.is_aggregation());
+    assert!(!ResampleMethod::Linear.is_aggregation());
+    assert!(ResampleMethod::Mean.is_aggregation());
+    assert!(ResampleMethod::Min.is_aggregation());
+    assert!(ResampleMethod::Max.is_aggregation());
+    assert!(ResampleMethod::Last.is_aggregation());
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", ResampleMethod::ForwardFill), "ForwardFill");
+    assert_eq!(format!("{}", ResampleMethod::Linear), "Linear");
+}
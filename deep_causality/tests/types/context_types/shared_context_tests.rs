@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+
+fn add_node(ctx: &mut BaseContext, id: u64) -> usize {
+    let contextoid = Contextoid::new(id, ContextoidType::Root(Root::new(id)));
+    ctx.add_node(contextoid)
+}
+
+#[test]
+fn test_new() {
+    let shared = SharedContext::new(get_test_context());
+
+    assert_eq!(shared.context().id(), 1);
+    assert_eq!(shared.context().node_count(), 1);
+    assert_eq!(shared.number_snapshots(), 0);
+}
+
+#[test]
+fn test_snapshot_isolation() {
+    let mut shared = SharedContext::new(get_test_context());
+
+    let snapshot = shared.snapshot();
+    assert_eq!(shared.number_snapshots(), 1);
+    assert_eq!(snapshot.node_count(), 1);
+
+    let index = shared.update(|ctx| add_node(ctx, 2));
+    assert_eq!(index, 1);
+
+    // The snapshot keeps the state before the update.
+    assert_eq!(snapshot.node_count(), 1);
+    assert_eq!(shared.context().node_count(), 2);
+    assert_eq!(shared.number_snapshots(), 0);
+
+    let second = shared.snapshot();
+    assert_eq!(second.node_count(), 2);
+
+    let cloned = second.clone();
+    assert_eq!(shared.number_snapshots(), 2);
+    drop(second);
+    drop(cloned);
+    assert_eq!(shared.number_snapshots(), 0);
+}
+
+#[test]
+fn test_snapshot_as_context() {
+    let shared = SharedContext::new(get_test_context());
+    let snapshot = shared.snapshot();
+
+    fn node_count(ctx: &BaseContext) -> usize {
+        ctx.node_count()
+    }
+
+    assert_eq!(node_count(&snapshot), 1);
+}
+
+#[test]
+fn test_into_context() {
+    let mut shared = SharedContext::new(get_test_context());
+    let snapshot = shared.snapshot();
+    shared.update(|ctx| add_node(ctx, 2));
+
+    let context = shared.into_context();
+    assert_eq!(context.node_count(), 2);
+    assert_eq!(snapshot.node_count(), 1);
+}
+
+#[test]
+fn test_update_keeps_subscribers() {
+    let mut ctx = get_test_context();
+    let changes = ctx.subscribe();
+    let mut shared = SharedContext::new(ctx);
+
+    // The copy made for the held snapshot keeps the subscription of the live context.
+    let snapshot = shared.snapshot();
+    shared.update(|ctx| add_node(ctx, 2));
+    assert_eq!(shared.context().number_of_subscribers(), 1);
+    assert_eq!(changes.try_iter().collect::<Vec<u64>>(), vec![2]);
+
+    let snapshot_again = shared.snapshot();
+    let mut ctx = shared.into_context();
+    add_node(&mut ctx, 3);
+    assert_eq!(changes.try_iter().collect::<Vec<u64>>(), vec![3]);
+
+    assert_eq!(snapshot.node_count(), 1);
+    assert_eq!(snapshot_again.node_count(), 2);
+}
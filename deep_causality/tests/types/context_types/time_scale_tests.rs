@@ -9,6 +9,18 @@ fn test_time_scale() {
     assert_eq!(ts, TimeScale::NoScale);
     assert_eq!(ts.to_string(), "NoScale");
 
+    let ts = TimeScale::Nanosecond;
+    assert_eq!(ts, TimeScale::Nanosecond);
+    assert_eq!(ts.to_string(), "Nanosecond");
+
+    let ts = TimeScale::Microsecond;
+    assert_eq!(ts, TimeScale::Microsecond);
+    assert_eq!(ts.to_string(), "Microsecond");
+
+    let ts = TimeScale::Millisecond;
+    assert_eq!(ts, TimeScale::Millisecond);
+    assert_eq!(ts.to_string(), "Millisecond");
+
     let ts = TimeScale::Second;
     assert_eq!(ts, TimeScale::Second);
     assert_eq!(ts.to_string(), "Second");
@@ -41,3 +53,105 @@ fn test_time_scale() {
     assert_eq!(ts, TimeScale::Year);
     assert_eq!(ts.to_string(), "Year");
 }
+
+#[test]
+fn test_time_scale_seconds() {
+    assert_eq!(TimeScale::NoScale.seconds(), None);
+    assert_eq!(TimeScale::Second.seconds(), Some(1));
+    assert_eq!(TimeScale::Hour.seconds(), Some(3_600));
+    assert_eq!(TimeScale::Week.seconds(), Some(604_800));
+    assert_eq!(TimeScale::Month.seconds(), None);
+    assert_eq!(TimeScale::Millisecond.seconds(), None);
+}
+
+#[test]
+fn test_time_scale_nanoseconds() {
+    assert_eq!(TimeScale::NoScale.nanoseconds(), None);
+    assert_eq!(TimeScale::Nanosecond.nanoseconds(), Some(1));
+    assert_eq!(TimeScale::Microsecond.nanoseconds(), Some(1_000));
+    assert_eq!(TimeScale::Millisecond.nanoseconds(), Some(1_000_000));
+    assert_eq!(TimeScale::Second.nanoseconds(), Some(1_000_000_000));
+    assert_eq!(TimeScale::Week.nanoseconds(), Some(604_800_000_000_000));
+    assert_eq!(TimeScale::Year.nanoseconds(), None);
+
+    assert!(TimeScale::Microsecond.is_sub_second());
+    assert!(!TimeScale::Second.is_sub_second());
+    assert!(!TimeScale::NoScale.is_sub_second());
+}
+
+#[test]
+fn test_time_scale_months() {
+    assert_eq!(TimeScale::Day.months(), None);
+    assert_eq!(TimeScale::Month.months(), Some(1));
+    assert_eq!(TimeScale::Quarter.months(), Some(3));
+    assert_eq!(TimeScale::Year.months(), Some(12));
+
+    assert!(TimeScale::Quarter.is_calendar_scale());
+    assert!(!TimeScale::Week.is_calendar_scale());
+}
+
+#[test]
+fn test_time_scale_convert() {
+    assert_eq!(TimeScale::Minute.convert(90.0, TimeScale::Hour), Some(1.5));
+    assert_eq!(TimeScale::Week.convert(2.0, TimeScale::Day), Some(14.0));
+    assert_eq!(TimeScale::Year.convert(1.0, TimeScale::Quarter), Some(4.0));
+    assert_eq!(TimeScale::Month.convert(6.0, TimeScale::Year), Some(0.5));
+
+    assert_eq!(
+        TimeScale::Millisecond.convert(1_500.0, TimeScale::Second),
+        Some(1.5)
+    );
+    assert_eq!(
+        TimeScale::Second.convert(2.0, TimeScale::Microsecond),
+        Some(2_000_000.0)
+    );
+
+    assert_eq!(TimeScale::Day.convert(30.0, TimeScale::Month), None);
+    assert_eq!(TimeScale::NoScale.convert(1.0, TimeScale::Second), None);
+}
+
+#[test]
+fn test_time_scale_downsample() {
+    assert_eq!(
+        TimeScale::Millisecond.downsample(1_500, TimeScale::Second),
+        Some(1)
+    );
+    assert_eq!(
+        TimeScale::Nanosecond.downsample(999, TimeScale::Microsecond),
+        Some(0)
+    );
+    assert_eq!(TimeScale::Minute.downsample(150, TimeScale::Hour), Some(2));
+    assert_eq!(TimeScale::Month.downsample(7, TimeScale::Quarter), Some(2));
+    assert_eq!(TimeScale::Second.downsample(5, TimeScale::Second), Some(5));
+
+    // Finer target scale or scales without a conversion.
+    assert_eq!(
+        TimeScale::Second.downsample(1, TimeScale::Millisecond),
+        None
+    );
+    assert_eq!(TimeScale::Day.downsample(40, TimeScale::Month), None);
+    assert_eq!(TimeScale::NoScale.downsample(1, TimeScale::Second), None);
+}
+
+#[test]
+fn test_time_scale_upsample() {
+    assert_eq!(
+        TimeScale::Second.upsample(1, TimeScale::Millisecond),
+        Some((1_000, 2_000))
+    );
+    assert_eq!(
+        TimeScale::Hour.upsample(2, TimeScale::Minute),
+        Some((120, 180))
+    );
+    assert_eq!(
+        TimeScale::Year.upsample(1, TimeScale::Quarter),
+        Some((4, 8))
+    );
+
+    assert_eq!(TimeScale::Millisecond.upsample(1, TimeScale::Second), None);
+    assert_eq!(TimeScale::Month.upsample(1, TimeScale::Day), None);
+    assert_eq!(
+        TimeScale::Week.upsample(u64::MAX, TimeScale::Nanosecond),
+        None
+    );
+}
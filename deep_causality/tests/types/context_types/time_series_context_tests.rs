@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+
+fn get_time_series_context(
+    window_size: usize,
+) -> TimeSeriesContext<
+    Data<BaseNumberType>,
+    Space<BaseNumberType>,
+    Time<BaseNumberType>,
+    SpaceTime<BaseNumberType>,
+    BaseNumberType,
+> {
+    TimeSeriesContext::new(get_test_context(), window_size).expect("Failed to create context")
+}
+
+#[test]
+fn test_new() {
+    let ts = get_time_series_context(3);
+
+    assert_eq!(ts.window_size(), 3);
+    assert_eq!(ts.context().node_count(), 1);
+    assert!(ts.sensor_ids().is_empty());
+    assert_eq!(ts.number_of_samples(1), 0);
+    assert!(ts.latest(1).is_none());
+
+    let res = TimeSeriesContext::new(get_test_context(), 0);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_push_sample() {
+    let mut ts = get_time_series_context(3);
+
+    ts.push_sample(7, 10, Data::new(100, 1)).unwrap();
+    ts.push_sample(7, 20, Data::new(101, 2)).unwrap();
+    ts.push_sample(8, 20, Data::new(200, 5)).unwrap();
+
+    assert_eq!(ts.sensor_ids(), vec![7, 8]);
+    assert_eq!(ts.number_of_samples(7), 2);
+    assert!(!ts.is_filled(7));
+    assert_eq!(ts.context().node_count(), 4);
+
+    let (time, datoid) = ts.latest(7).unwrap();
+    assert_eq!(time, 20);
+    assert_eq!(*datoid.data(), 2);
+
+    let samples = ts.samples(7);
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].0, 10);
+    assert_eq!(*samples[0].1.data_id(), 100);
+}
+
+#[test]
+fn test_push_sample_evicts_oldest() {
+    let mut ts = get_time_series_context(2);
+
+    ts.push_sample(7, 1, Data::new(1, 10)).unwrap();
+    let second = ts.push_sample(7, 2, Data::new(2, 20)).unwrap();
+    assert!(ts.is_filled(7));
+
+    let third = ts.push_sample(7, 3, Data::new(3, 30)).unwrap();
+    assert_eq!(ts.number_of_samples(7), 2);
+    assert_eq!(ts.indices(7), vec![second, third]);
+    assert_eq!(ts.context().node_count(), 3);
+
+    let values: Vec<BaseNumberType> = ts.samples(7).iter().map(|(_, d)| *d.data()).collect();
+    assert_eq!(values, vec![20, 30]);
+}
+
+#[test]
+fn test_push_sample_out_of_order() {
+    let mut ts = get_time_series_context(2);
+
+    ts.push_sample(7, 5, Data::new(1, 10)).unwrap();
+    let res = ts.push_sample(7, 4, Data::new(2, 20));
+    assert!(res.is_err());
+    assert_eq!(ts.number_of_samples(7), 1);
+
+    // Equal times are accepted.
+    assert!(ts.push_sample(7, 5, Data::new(2, 20)).is_ok());
+}
+
+#[test]
+fn test_aligned_window() {
+    let mut ts = get_time_series_context(5);
+
+    // Sensor 7 samples every 5 units, sensor 8 every 10 units.
+    for (i, time) in [0, 5, 10, 15, 20].iter().enumerate() {
+        ts.push_sample(7, *time, Data::new(100 + i as u64, i as u64))
+            .unwrap();
+    }
+    for (i, time) in [5, 15, 25].iter().enumerate() {
+        ts.push_sample(8, *time, Data::new(200 + i as u64, 10 * i as u64))
+            .unwrap();
+    }
+
+    let value = |d: &Data<BaseNumberType>| *d.data() as f64;
+
+    let rows = ts.aligned_window(&[7, 8], 5, ResampleMethod::ForwardFill, value);
+    assert_eq!(
+        rows,
+        vec![
+            (5, vec![1.0, 0.0]),
+            (10, vec![2.0, 0.0]),
+            (15, vec![3.0, 10.0]),
+            (20, vec![4.0, 10.0]),
+        ]
+    );
+
+    let rows = ts.aligned_window(&[8, 7], 10, ResampleMethod::Linear, value);
+    assert_eq!(rows, vec![(5, vec![0.0, 1.0]), (15, vec![10.0, 3.0])]);
+
+    assert!(ts
+        .aligned_window(&[7, 9], 5, ResampleMethod::ForwardFill, value)
+        .is_empty());
+    assert!(ts
+        .aligned_window(&[], 5, ResampleMethod::ForwardFill, value)
+        .is_empty());
+    assert!(ts
+        .aligned_window(&[7, 8], 0, ResampleMethod::ForwardFill, value)
+        .is_empty());
+}
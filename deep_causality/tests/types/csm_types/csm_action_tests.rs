@@ -1,7 +1,15 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
-use deep_causality::prelude::{ActionError, CausalAction};
+use std::cell::RefCell;
+
+use deep_causality::prelude::{ActionError, ActionStep, CausalAction, CausalState, CSM};
+
+use crate::utils::test_utils;
+
+thread_local! {
+    static EXECUTED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
 
 fn hello_state() -> Result<(), ActionError> {
     println!("Hello State");
@@ -35,3 +43,120 @@ fn test_fire() {
     assert_eq!(*ca.descr(), "Test action that prints Hello State");
     assert_eq!(*ca.version(), 1);
 }
+
+fn executed() -> Vec<&'static str> {
+    EXECUTED.with(|e| e.borrow_mut().drain(..).collect())
+}
+
+fn throttle() -> Result<(), ActionError> {
+    EXECUTED.with(|e| e.borrow_mut().push("throttle"));
+    Ok(())
+}
+
+fn unthrottle() -> Result<(), ActionError> {
+    EXECUTED.with(|e| e.borrow_mut().push("unthrottle"));
+    Ok(())
+}
+
+fn notify() -> Result<(), ActionError> {
+    EXECUTED.with(|e| e.borrow_mut().push("notify"));
+    Ok(())
+}
+
+fn retract() -> Result<(), ActionError> {
+    EXECUTED.with(|e| e.borrow_mut().push("retract"));
+    Err(ActionError("retract failed".to_string()))
+}
+
+fn migrate_ok() -> Result<(), ActionError> {
+    EXECUTED.with(|e| e.borrow_mut().push("migrate"));
+    Ok(())
+}
+
+fn migrate_err() -> Result<(), ActionError> {
+    EXECUTED.with(|e| e.borrow_mut().push("migrate"));
+    Err(ActionError("no capacity".to_string()))
+}
+
+fn get_remediation(migrate: fn() -> Result<(), ActionError>) -> CausalAction {
+    let steps = vec![
+        ActionStep::new(throttle, Some(unthrottle), "throttle"),
+        ActionStep::new(notify, None, "notify"),
+        ActionStep::new(migrate, None, "migrate"),
+    ];
+
+    CausalAction::new_composite(steps, "Remediation", 1)
+}
+
+#[test]
+fn test_action_step() {
+    let step = ActionStep::new(throttle, Some(unthrottle), "throttle");
+    assert_eq!(*step.descr(), "throttle");
+    assert!(step.compensation().is_some());
+    assert!((step.action())().is_ok());
+    assert_eq!(executed(), vec!["throttle"]);
+}
+
+#[test]
+fn test_composite_fire() {
+    let ca = get_test_action();
+    assert!(!ca.is_composite());
+    assert!(ca.steps().is_empty());
+
+    let ca = get_remediation(migrate_ok);
+    assert!(ca.is_composite());
+    assert_eq!(ca.steps().len(), 3);
+    assert_eq!(*ca.descr(), "Remediation");
+
+    assert!(ca.fire().is_ok());
+    assert_eq!(executed(), vec!["throttle", "notify", "migrate"]);
+}
+
+#[test]
+fn test_composite_fire_rolls_back() {
+    let ca = get_remediation(migrate_err);
+
+    let res = ca.fire();
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "ActionError: Step 2 (migrate) of Remediation failed: no capacity"
+    );
+    // Compensations run in reverse order; notify has none.
+    assert_eq!(
+        executed(),
+        vec!["throttle", "notify", "migrate", "unthrottle"]
+    );
+}
+
+#[test]
+fn test_composite_fire_failed_compensation() {
+    let steps = vec![
+        ActionStep::new(notify, Some(retract), "notify"),
+        ActionStep::new(migrate_err, None, "migrate"),
+    ];
+    let ca = CausalAction::new_composite(steps, "Remediation", 1);
+
+    let res = ca.fire();
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "ActionError: Step 1 (migrate) of Remediation failed: no capacity; compensation of notify failed: retract failed"
+    );
+    assert_eq!(executed(), vec!["notify", "migrate", "retract"]);
+}
+
+#[test]
+fn test_composite_in_csm() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let cs = CausalState::new(1, 1, 0.89, causaloid);
+    let ca = get_remediation(migrate_err);
+
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.eval_all_states().is_err());
+    assert_eq!(
+        executed(),
+        vec!["throttle", "notify", "migrate", "unthrottle"]
+    );
+}
@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+use deep_causality::prelude::{
+    ActionError, ActionPolicy, CausalAction, CausalState, StateArbitration, SuppressedAction,
+    SuppressionReason, CSM,
+};
+
+use crate::utils::test_utils;
+
+thread_local! {
+    static FIRED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+fn open_valve() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("open"));
+    Ok(())
+}
+
+fn close_valve() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("close"));
+    Ok(())
+}
+
+fn alert() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("alert"));
+    Ok(())
+}
+
+fn failing_alert() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("failed alert"));
+    Err(ActionError("alert failed".to_string()))
+}
+
+fn fired() -> Vec<&'static str> {
+    FIRED.with(|f| f.borrow_mut().drain(..).collect())
+}
+
+const VALVE: u64 = 7;
+
+#[test]
+fn test_state_arbitration() {
+    let arbitration = StateArbitration::new();
+    assert_eq!(arbitration, StateArbitration::default());
+    assert_eq!(arbitration.priority(), 0);
+    assert_eq!(arbitration.group(), None);
+    assert_eq!(arbitration.rate_limit(), None);
+
+    let arbitration = StateArbitration::new()
+        .with_priority(5)
+        .with_group(VALVE)
+        .with_rate_limit(2, Duration::from_secs(1));
+    assert_eq!(arbitration.priority(), 5);
+    assert_eq!(arbitration.group(), Some(VALVE));
+    assert_eq!(arbitration.rate_limit(), Some((2, Duration::from_secs(1))));
+
+    let suppressed = SuppressedAction::new(
+        3,
+        SuppressionReason::Excluded {
+            group: VALVE,
+            winner: 1,
+        },
+    );
+    assert_eq!(
+        format!("{}", suppressed),
+        "SuppressedAction: state: 3, excluded by state 1 in group 7"
+    );
+
+    let suppressed = SuppressedAction::new(
+        3,
+        SuppressionReason::RateLimited {
+            max_fires: 2,
+            window: Duration::from_secs(1),
+        },
+    );
+    assert_eq!(
+        format!("{}", suppressed),
+        "SuppressedAction: state: 3, rate limited to 2 per 1s"
+    );
+}
+
+#[test]
+fn test_mutual_exclusion_by_priority() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_open = CausalState::new(1, 1, data, causaloid);
+    let cs_close = CausalState::new(2, 1, data, causaloid);
+    let cs_alert = CausalState::new(3, 1, data, causaloid);
+    let ca_open = CausalAction::new(open_valve, "Open valve", 1);
+    let ca_close = CausalAction::new(close_valve, "Close valve", 1);
+    let ca_alert = CausalAction::new(alert, "Alert", 1);
+
+    let state_actions = &[
+        (&cs_open, &ca_open),
+        (&cs_close, &ca_close),
+        (&cs_alert, &ca_alert),
+    ];
+    let csm = CSM::new(state_actions);
+
+    // Without arbitration, conflicting actions both fire.
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), vec!["open", "close", "alert"]);
+
+    let open = StateArbitration::new().with_group(VALVE);
+    let close = StateArbitration::new().with_group(VALVE).with_priority(10);
+    assert!(csm.set_arbitration(1, open).is_ok());
+    assert!(csm.set_arbitration(2, close).is_ok());
+    assert_eq!(csm.get_arbitration(2), close);
+    assert_eq!(csm.get_arbitration(3), StateArbitration::default());
+
+    // Closing the valve has the higher priority; the alert is not in the group.
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), vec!["close", "alert"]);
+    assert_eq!(
+        csm.suppressed_actions(),
+        vec![SuppressedAction::new(
+            1,
+            SuppressionReason::Excluded {
+                group: VALVE,
+                winner: 2
+            }
+        )]
+    );
+
+    csm.clear_suppressed_actions();
+    assert!(csm.suppressed_actions().is_empty());
+
+    // On equal priority, the state evaluated first wins.
+    assert!(csm.set_arbitration(2, open).is_ok());
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), vec!["open", "alert"]);
+
+    assert!(csm.set_arbitration(42, open).is_err());
+}
+
+#[test]
+fn test_rate_limit() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_open = CausalState::new(1, 1, data, causaloid);
+    let cs_close = CausalState::new(2, 1, data, causaloid);
+    let ca_open = CausalAction::new(open_valve, "Open valve", 1);
+    let ca_close = CausalAction::new(close_valve, "Close valve", 1);
+
+    let state_actions = &[(&cs_open, &ca_open), (&cs_close, &ca_close)];
+    let csm = CSM::new(state_actions);
+
+    let window = Duration::from_secs(60);
+    let close = StateArbitration::new()
+        .with_group(VALVE)
+        .with_priority(10)
+        .with_rate_limit(2, window);
+    assert!(csm
+        .set_arbitration(1, StateArbitration::new().with_group(VALVE))
+        .is_ok());
+    assert!(csm.set_arbitration(2, close).is_ok());
+
+    // The rate limit applies to single state evaluation as well.
+    assert!(csm.eval_single_state(2, data).is_ok());
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), vec!["close", "close"]);
+
+    // Once rate limited, closing no longer blocks the group.
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), vec!["open"]);
+    assert_eq!(
+        csm.suppressed_actions(),
+        vec![
+            SuppressedAction::new(
+                1,
+                SuppressionReason::Excluded {
+                    group: VALVE,
+                    winner: 2
+                }
+            ),
+            SuppressedAction::new(
+                2,
+                SuppressionReason::RateLimited {
+                    max_fires: 2,
+                    window
+                }
+            ),
+        ]
+    );
+
+    // Settings of removed states are dropped.
+    assert!(csm.remove_single_state(2).is_ok());
+    assert!(csm.add_single_state(2, (&cs_close, &ca_close)).is_ok());
+    assert_eq!(csm.get_arbitration(2), StateArbitration::default());
+}
+
+#[test]
+fn test_suppressed_capacity() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_open = CausalState::new(1, 1, data, causaloid);
+    let cs_close = CausalState::new(2, 1, data, causaloid);
+    let ca_open = CausalAction::new(open_valve, "Open valve", 1);
+    let ca_close = CausalAction::new(close_valve, "Close valve", 1);
+
+    let state_actions = &[(&cs_open, &ca_open), (&cs_close, &ca_close)];
+    let csm = CSM::new(state_actions);
+    assert_eq!(csm.suppressed_capacity(), 1024);
+    assert!(csm.set_suppressed_capacity(0).is_err());
+
+    let open = StateArbitration::new().with_group(VALVE);
+    assert!(csm.set_arbitration(1, open).is_ok());
+    assert!(csm.set_arbitration(2, open.with_priority(10)).is_ok());
+
+    for _ in 0..3 {
+        assert!(csm.eval_all_states().is_ok());
+    }
+    assert_eq!(fired(), vec!["close", "close", "close"]);
+    assert_eq!(csm.suppressed_actions().len(), 3);
+
+    // Shrinking the capacity drops the oldest records.
+    assert!(csm.set_suppressed_capacity(2).is_ok());
+    assert_eq!(csm.suppressed_capacity(), 2);
+    assert_eq!(csm.suppressed_actions().len(), 2);
+
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), vec!["close"]);
+    assert_eq!(csm.suppressed_actions().len(), 2);
+}
+
+#[test]
+fn test_rate_limit_counts_fired_actions_only() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs = CausalState::new(1, 1, data, causaloid);
+    let ca = CausalAction::new(failing_alert, "Failing alert", 1);
+
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+    let policy = ActionPolicy::new().with_escalation(false);
+    assert!(csm.set_action_policy(1, policy).is_ok());
+    let limit = StateArbitration::new().with_rate_limit(1, Duration::from_secs(60));
+    assert!(csm.set_arbitration(1, limit).is_ok());
+
+    // Failed actions do not count towards the rate limit.
+    for _ in 0..3 {
+        assert!(csm.eval_all_states().is_ok());
+    }
+    assert_eq!(
+        fired(),
+        vec!["failed alert", "failed alert", "failed alert"]
+    );
+    assert_eq!(csm.action_failures().len(), 3);
+    assert!(csm.suppressed_actions().is_empty());
+}
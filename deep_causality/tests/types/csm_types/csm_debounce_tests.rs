@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cell::RefCell;
+use std::thread;
+use std::time::Duration;
+
+use deep_causality::prelude::{
+    ActionError, ActionPolicy, CausalAction, CausalState, StateDebounce, CSM,
+};
+
+use crate::utils::test_utils;
+
+thread_local! {
+    static FIRED: RefCell<u32> = const { RefCell::new(0) };
+    static FAILURES_LEFT: RefCell<u32> = const { RefCell::new(0) };
+}
+
+fn alert() -> Result<(), ActionError> {
+    FIRED.with(|f| *f.borrow_mut() += 1);
+    Ok(())
+}
+
+// Fails as long as FAILURES_LEFT is positive.
+fn flaky_alert() -> Result<(), ActionError> {
+    let failed = FAILURES_LEFT.with(|n| {
+        let mut n = n.borrow_mut();
+        let failed = *n > 0;
+        *n = n.saturating_sub(1);
+        failed
+    });
+    if failed {
+        return Err(ActionError("alert failed".to_string()));
+    }
+    alert()
+}
+
+fn fired() -> u32 {
+    FIRED.with(|f| std::mem::take(&mut *f.borrow_mut()))
+}
+
+const HIGH: f64 = 0.89;
+const LOW: f64 = 0.1;
+
+#[test]
+fn test_state_debounce() {
+    let debounce = StateDebounce::new();
+    assert_eq!(debounce, StateDebounce::default());
+    assert_eq!(debounce.rise(), 1);
+    assert_eq!(debounce.release(), 1);
+    assert_eq!(debounce.cooldown(), Duration::ZERO);
+
+    let debounce = StateDebounce::new()
+        .with_rise(3)
+        .with_release(0)
+        .with_cooldown(Duration::from_secs(60));
+    assert_eq!(debounce.rise(), 3);
+    assert_eq!(debounce.release(), 1);
+    assert_eq!(debounce.cooldown(), Duration::from_secs(60));
+    assert_eq!(
+        format!("{}", debounce),
+        "StateDebounce: rise: 3 release: 1 cooldown: 60s"
+    );
+
+    let causaloid = &test_utils::get_test_causaloid();
+    let cs = CausalState::new(1, 1, HIGH, causaloid);
+    assert_eq!(*cs.debounce(), StateDebounce::default());
+    let cs = cs.with_debounce(debounce);
+    assert_eq!(*cs.debounce(), debounce);
+}
+
+#[test]
+fn test_rise_and_release() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let debounce = StateDebounce::new().with_rise(3).with_release(2);
+    let cs = CausalState::new(1, 1, HIGH, causaloid).with_debounce(debounce);
+    let ca = CausalAction::new(alert, "Alert", 1);
+
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+
+    // A single spike does not activate the state.
+    assert!(csm.eval_single_state(1, HIGH).is_ok());
+    assert!(csm.eval_single_state(1, LOW).is_ok());
+    assert!(csm.eval_single_state(1, HIGH).is_ok());
+    assert!(csm.eval_single_state(1, HIGH).is_ok());
+    assert!(!csm.is_state_active(1));
+    assert_eq!(fired(), 0);
+
+    // The third consecutive true result activates the state.
+    assert!(csm.eval_single_state(1, HIGH).is_ok());
+    assert!(csm.is_state_active(1));
+    assert_eq!(fired(), 1);
+
+    // A single dip does not release the state, which keeps firing.
+    assert!(csm.eval_single_state(1, LOW).is_ok());
+    assert!(csm.is_state_active(1));
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), 2);
+
+    // The state fires until the second consecutive false result releases it.
+    assert!(csm.eval_single_state(1, LOW).is_ok());
+    assert!(csm.eval_single_state(1, LOW).is_ok());
+    assert!(!csm.is_state_active(1));
+    assert_eq!(fired(), 1);
+
+    // Reset discards the progress.
+    assert!(csm.eval_single_state(1, HIGH).is_ok());
+    assert!(csm.eval_single_state(1, HIGH).is_ok());
+    csm.reset_debounce(1);
+    assert!(csm.eval_single_state(1, HIGH).is_ok());
+    assert!(!csm.is_state_active(1));
+    assert_eq!(fired(), 0);
+}
+
+#[test]
+fn test_cooldown() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let debounce = StateDebounce::new().with_cooldown(Duration::from_millis(50));
+    let cs = CausalState::new(1, 1, HIGH, causaloid).with_debounce(debounce);
+    let ca = CausalAction::new(alert, "Alert", 1);
+
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+
+    for _ in 0..5 {
+        assert!(csm.eval_all_states().is_ok());
+    }
+    assert_eq!(fired(), 1);
+
+    thread::sleep(Duration::from_millis(60));
+    assert!(csm.eval_all_states().is_ok());
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), 1);
+}
+
+#[test]
+fn test_default_fires_on_every_true() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let cs = CausalState::new(1, 1, HIGH, causaloid);
+    let ca = CausalAction::new(alert, "Alert", 1);
+
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.eval_single_state(1, HIGH).is_ok());
+    assert!(csm.is_state_active(1));
+    assert!(csm.eval_single_state(1, LOW).is_ok());
+    assert!(!csm.is_state_active(1));
+    assert!(csm.eval_single_state(1, HIGH).is_ok());
+    assert!(csm.is_state_active(1));
+    assert_eq!(fired(), 2);
+}
+
+#[test]
+fn test_cooldown_starts_after_firing() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let debounce = StateDebounce::new().with_cooldown(Duration::from_secs(60));
+    let cs = CausalState::new(1, 1, HIGH, causaloid).with_debounce(debounce);
+    let ca = CausalAction::new(flaky_alert, "Flaky alert", 1);
+
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+    let policy = ActionPolicy::new().with_escalation(false);
+    assert!(csm.set_action_policy(1, policy).is_ok());
+
+    // A failed action does not start the cooldown, hence the next evaluation fires.
+    FAILURES_LEFT.with(|n| *n.borrow_mut() = 1);
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), 0);
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), 1);
+
+    // The successful firing starts the cooldown.
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), 0);
+}
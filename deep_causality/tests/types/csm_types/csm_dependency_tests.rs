@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cell::RefCell;
+
+use deep_causality::prelude::{
+    ActionError, CausalAction, CausalState, CausalityError, Causaloid, NumericalValue, CSM,
+};
+
+use crate::utils::test_utils;
+
+thread_local! {
+    static FIRED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    static LOAD_SHED: RefCell<bool> = const { RefCell::new(false) };
+}
+
+fn shed_load() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("shed load"));
+    LOAD_SHED.with(|l| *l.borrow_mut() = true);
+    Ok(())
+}
+
+fn alert() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("alert"));
+    Ok(())
+}
+
+fn log() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("log"));
+    Ok(())
+}
+
+fn fired() -> Vec<&'static str> {
+    FIRED.with(|f| f.borrow_mut().drain(..).collect())
+}
+
+#[test]
+fn test_eval_all_states_respects_dependencies() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    // The alert has the lowest id and would be evaluated first without dependencies.
+    let cs_alert = CausalState::new(1, 1, data, causaloid);
+    let cs_log = CausalState::new(2, 1, data, causaloid);
+    let cs_shed = CausalState::new(3, 1, data, causaloid);
+    let ca_alert = CausalAction::new(alert, "Alert", 1);
+    let ca_log = CausalAction::new(log, "Log", 1);
+    let ca_shed = CausalAction::new(shed_load, "Shed load", 1);
+
+    let state_actions = &[
+        (&cs_alert, &ca_alert),
+        (&cs_log, &ca_log),
+        (&cs_shed, &ca_shed),
+    ];
+    let csm = CSM::new(state_actions);
+
+    assert_eq!(csm.evaluation_order().unwrap(), vec![1, 2, 3]);
+
+    // Shed load before alert.
+    let res = csm.add_dependency(1, 3);
+    assert!(res.is_ok());
+    assert_eq!(csm.get_dependencies(1), vec![3]);
+    assert_eq!(csm.evaluation_order().unwrap(), vec![2, 3, 1]);
+
+    fired();
+    let res = csm.eval_all_states();
+    assert!(res.is_ok());
+    assert_eq!(fired(), vec!["log", "shed load", "alert"]);
+
+    // Log after alert.
+    let res = csm.add_dependency(2, 1);
+    assert!(res.is_ok());
+    assert_eq!(csm.evaluation_order().unwrap(), vec![3, 1, 2]);
+
+    let res = csm.eval_all_states();
+    assert!(res.is_ok());
+    assert_eq!(fired(), vec!["shed load", "alert", "log"]);
+}
+
+#[test]
+fn test_add_dependency_err() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs1 = CausalState::new(1, 1, data, causaloid);
+    let cs2 = CausalState::new(2, 1, data, causaloid);
+    let cs3 = CausalState::new(3, 1, data, causaloid);
+    let ca = CausalAction::new(log, "Log", 1);
+
+    let state_actions = &[(&cs1, &ca), (&cs2, &ca), (&cs3, &ca)];
+    let csm = CSM::new(state_actions);
+
+    // Unknown state
+    assert!(csm.add_dependency(1, 42).is_err());
+    assert!(csm.add_dependency(42, 1).is_err());
+
+    // Self dependency
+    assert!(csm.add_dependency(1, 1).is_err());
+
+    // Duplicate dependency
+    assert!(csm.add_dependency(1, 2).is_ok());
+    assert!(csm.add_dependency(1, 2).is_err());
+
+    // Direct and transitive cycles
+    assert!(csm.add_dependency(2, 1).is_err());
+    assert!(csm.add_dependency(2, 3).is_ok());
+    let res = csm.add_dependency(3, 1);
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "UpdateError: Dependency of state 3 on state 1 would create a cycle"
+    );
+
+    assert_eq!(csm.evaluation_order().unwrap(), vec![3, 2, 1]);
+}
+
+#[test]
+fn test_remove_dependency() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs1 = CausalState::new(1, 1, data, causaloid);
+    let cs2 = CausalState::new(2, 1, data, causaloid);
+    let ca = CausalAction::new(log, "Log", 1);
+
+    let state_actions = &[(&cs1, &ca), (&cs2, &ca)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.remove_dependency(1, 2).is_err());
+
+    assert!(csm.add_dependency(1, 2).is_ok());
+    assert_eq!(csm.evaluation_order().unwrap(), vec![2, 1]);
+
+    assert!(csm.remove_dependency(1, 2).is_ok());
+    assert!(csm.get_dependencies(1).is_empty());
+    assert_eq!(csm.evaluation_order().unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn test_remove_state_removes_dependencies() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs1 = CausalState::new(1, 1, data, causaloid);
+    let cs2 = CausalState::new(2, 1, data, causaloid);
+    let cs3 = CausalState::new(3, 1, data, causaloid);
+    let ca = CausalAction::new(log, "Log", 1);
+
+    let state_actions = &[(&cs1, &ca), (&cs2, &ca), (&cs3, &ca)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.add_dependency(1, 2).is_ok());
+    assert!(csm.add_dependency(3, 2).is_ok());
+    assert!(csm.add_dependency(3, 1).is_ok());
+
+    assert!(csm.remove_single_state(2).is_ok());
+    assert!(csm.get_dependencies(1).is_empty());
+    assert_eq!(csm.get_dependencies(3), vec![1]);
+    assert_eq!(csm.evaluation_order().unwrap(), vec![1, 3]);
+
+    // update_all_states keeps dependencies between remaining states only.
+    let state_actions = &[(&cs1, &ca), (&cs3, &ca)];
+    csm.update_all_states(state_actions);
+    assert_eq!(csm.get_dependencies(3), vec![1]);
+
+    let state_actions = &[(&cs2, &ca), (&cs3, &ca)];
+    csm.update_all_states(state_actions);
+    assert!(csm.get_dependencies(3).is_empty());
+    assert!(csm.eval_all_states().is_ok());
+}
+
+#[test]
+fn test_dependent_state_evaluates_before_actions_fire() {
+    fn load_shed(_obs: NumericalValue) -> Result<bool, CausalityError> {
+        Ok(LOAD_SHED.with(|l| *l.borrow()))
+    }
+
+    let causaloid = &test_utils::get_test_causaloid();
+    let causaloid_shed = &Causaloid::new(2, load_shed, "tests whether load was shed");
+    let data = 0.89f64;
+
+    let cs_shed = CausalState::new(1, 1, data, causaloid);
+    let cs_alert = CausalState::new(2, 1, data, causaloid_shed);
+    let ca_shed = CausalAction::new(shed_load, "Shed load", 1);
+    let ca_alert = CausalAction::new(alert, "Alert", 1);
+
+    let state_actions = &[(&cs_shed, &ca_shed), (&cs_alert, &ca_alert)];
+    let csm = CSM::new(state_actions);
+    assert!(csm.add_dependency(2, 1).is_ok());
+
+    // All states evaluate before any action fires,
+    // hence the alert does not observe the load shed within the same cycle.
+    LOAD_SHED.with(|l| *l.borrow_mut() = false);
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), vec!["shed load"]);
+
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), vec!["shed load", "alert"]);
+}
+
+#[test]
+fn test_evaluation_error_fires_preceding_actions() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let causaloid_err = &test_utils::get_test_error_causaloid();
+    let data = 0.89f64;
+
+    let cs_shed = CausalState::new(1, 1, data, causaloid);
+    let cs_err = CausalState::new(2, 1, data, causaloid_err);
+    let cs_alert = CausalState::new(3, 1, data, causaloid);
+    let ca_shed = CausalAction::new(shed_load, "Shed load", 1);
+    let ca_alert = CausalAction::new(alert, "Alert", 1);
+
+    let state_actions = &[
+        (&cs_shed, &ca_shed),
+        (&cs_err, &ca_alert),
+        (&cs_alert, &ca_alert),
+    ];
+    let csm = CSM::new(state_actions);
+    assert_eq!(csm.evaluation_order().unwrap(), vec![1, 2, 3]);
+
+    // The action of the state evaluated before the error fires,
+    // the state after the error is not evaluated.
+    fired();
+    assert!(csm.eval_all_states().is_err());
+    assert_eq!(fired(), vec!["shed load"]);
+}
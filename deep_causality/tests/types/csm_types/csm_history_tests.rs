@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::time::Duration;
+
+use deep_causality::prelude::{
+    ActionError, ActionOutcome, ActionPolicy, CSMRecord, CausalAction, CausalState,
+    StateArbitration, CSM,
+};
+
+use crate::utils::test_utils;
+
+fn alert() -> Result<(), ActionError> {
+    Ok(())
+}
+
+fn fail() -> Result<(), ActionError> {
+    Err(ActionError("failed".to_string()))
+}
+
+// Returns the records without timestamps for comparison.
+fn entries(history: Vec<CSMRecord>) -> Vec<(usize, f64, Option<bool>, ActionOutcome)> {
+    history
+        .iter()
+        .map(|r| (*r.state_id(), *r.input(), *r.verdict(), *r.outcome()))
+        .collect()
+}
+
+#[test]
+fn test_csm_record() {
+    let record = CSMRecord::new(1700000000000, 3, 0.89, Some(true), ActionOutcome::Fired);
+    assert_eq!(*record.timestamp(), 1700000000000);
+    assert_eq!(
+        format!("{}", record),
+        "CSMRecord: timestamp: 1700000000000 state: 3 input: 0.89 verdict: true outcome: fired"
+    );
+
+    let record = CSMRecord::new(0, 3, f64::NAN, None, ActionOutcome::NotFired);
+    assert_eq!(
+        format!("{}", record),
+        "CSMRecord: timestamp: 0 state: 3 input: NaN verdict: error outcome: not_fired"
+    );
+
+    assert_eq!(format!("{}", ActionOutcome::Suppressed), "suppressed");
+    assert_eq!(format!("{}", ActionOutcome::Failed), "failed");
+}
+
+#[test]
+fn test_history_disabled_by_default() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let cs = CausalState::new(1, 1, 0.89, causaloid);
+    let ca = CausalAction::new(alert, "Alert", 1);
+
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+
+    assert!(!csm.is_history_enabled());
+    assert!(csm.eval_all_states().is_ok());
+    assert!(csm.history().is_empty());
+    assert_eq!(csm.history_to_json(), "[]");
+
+    assert!(csm.enable_history(0).is_err());
+}
+
+#[test]
+fn test_history() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let cs_alert = CausalState::new(1, 1, 0.89, causaloid);
+    let cs_quiet = CausalState::new(2, 1, 0.1, causaloid);
+    let cs_fail = CausalState::new(3, 1, 0.89, causaloid);
+    let ca_alert = CausalAction::new(alert, "Alert", 1);
+    let ca_fail = CausalAction::new(fail, "Fail", 1);
+
+    let state_actions = &[
+        (&cs_alert, &ca_alert),
+        (&cs_quiet, &ca_alert),
+        (&cs_fail, &ca_fail),
+    ];
+    let csm = CSM::new(state_actions);
+    let policy = ActionPolicy::new().with_escalation(false);
+    assert!(csm.set_action_policy(3, policy).is_ok());
+
+    assert!(csm.enable_history(10).is_ok());
+    assert!(csm.is_history_enabled());
+
+    assert!(csm.eval_all_states().is_ok());
+    assert!(csm.eval_single_state(2, 0.7).is_ok());
+    // A negative input is an evaluation error.
+    assert!(csm.eval_single_state(1, -1.0).is_err());
+
+    assert_eq!(
+        entries(csm.history()),
+        vec![
+            (1, 0.89, Some(true), ActionOutcome::Fired),
+            (2, 0.1, Some(false), ActionOutcome::NotFired),
+            (3, 0.89, Some(true), ActionOutcome::Failed),
+            (2, 0.7, Some(true), ActionOutcome::Fired),
+            (1, -1.0, None, ActionOutcome::NotFired),
+        ]
+    );
+    assert!(csm.history().iter().all(|r| *r.timestamp() > 0));
+
+    // The history keeps only the most recent records.
+    assert!(csm.enable_history(2).is_ok());
+    assert_eq!(
+        entries(csm.history()),
+        vec![
+            (2, 0.7, Some(true), ActionOutcome::Fired),
+            (1, -1.0, None, ActionOutcome::NotFired),
+        ]
+    );
+    assert!(csm.eval_single_state(2, 0.1).is_ok());
+    assert_eq!(csm.history().len(), 2);
+    assert_eq!(*csm.history()[1].verdict(), Some(false));
+
+    csm.clear_history();
+    assert!(csm.history().is_empty());
+    assert!(csm.is_history_enabled());
+
+    csm.disable_history();
+    assert!(!csm.is_history_enabled());
+    assert!(csm.eval_all_states().is_ok());
+    assert!(csm.history().is_empty());
+}
+
+#[test]
+fn test_history_suppressed_and_escalated() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let cs1 = CausalState::new(1, 1, 0.89, causaloid);
+    let cs2 = CausalState::new(2, 1, 0.89, causaloid);
+    let cs3 = CausalState::new(3, 1, 0.89, causaloid);
+    let ca_alert = CausalAction::new(alert, "Alert", 1);
+    let ca_fail = CausalAction::new(fail, "Fail", 1);
+
+    let state_actions = &[(&cs1, &ca_alert), (&cs2, &ca_fail), (&cs3, &ca_alert)];
+    let csm = CSM::new(state_actions);
+    assert!(csm.enable_history(10).is_ok());
+
+    let limited = StateArbitration::new().with_rate_limit(0, Duration::from_secs(60));
+    assert!(csm.set_arbitration(1, limited).is_ok());
+
+    // State 2 escalates its failure, so state 3 never fires.
+    assert!(csm.eval_all_states().is_err());
+    assert_eq!(
+        entries(csm.history()),
+        vec![
+            (1, 0.89, Some(true), ActionOutcome::Suppressed),
+            (2, 0.89, Some(true), ActionOutcome::Failed),
+            (3, 0.89, Some(true), ActionOutcome::NotFired),
+        ]
+    );
+}
+
+#[test]
+fn test_history_to_json() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let cs = CausalState::new(1, 1, 0.5, causaloid);
+    let ca = CausalAction::new(alert, "Alert", 1);
+
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+    assert!(csm.enable_history(10).is_ok());
+
+    assert!(csm.eval_all_states().is_ok());
+    assert!(csm.eval_single_state(1, f64::NAN).is_err());
+
+    let history = csm.history();
+    let expected = format!(
+        "[{{\"timestamp\":{},\"state_id\":1,\"input\":0.5,\"verdict\":false,\"outcome\":\"not_fired\"}},\
+{{\"timestamp\":{},\"state_id\":1,\"input\":null,\"verdict\":null,\"outcome\":\"not_fired\"}}]",
+        history[0].timestamp(),
+        history[1].timestamp()
+    );
+    assert_eq!(csm.history_to_json(), expected);
+}
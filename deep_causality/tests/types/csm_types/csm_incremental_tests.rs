@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cell::RefCell;
+use std::thread;
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils;
+
+thread_local! {
+    static FIRED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+fn temperature() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("temperature"));
+    Ok(())
+}
+
+fn pressure() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("pressure"));
+    Ok(())
+}
+
+fn alert() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("alert"));
+    Ok(())
+}
+
+fn fired() -> Vec<&'static str> {
+    FIRED.with(|f| f.borrow_mut().drain(..).collect())
+}
+
+fn add_data(ctx: &mut BaseContext, id: u64, value: BaseNumberType) -> usize {
+    let contextoid = Contextoid::new(id, ContextoidType::Datoid(Data::new(id, value)));
+    ctx.add_node(contextoid)
+}
+
+#[test]
+fn test_watch_contextoid() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs1 = CausalState::new(1, 1, data, causaloid);
+    let cs2 = CausalState::new(2, 1, data, causaloid);
+    let ca = CausalAction::new(alert, "Alert", 1);
+
+    let state_actions = &[(&cs1, &ca), (&cs2, &ca)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.watch_contextoid(1, 20).is_ok());
+    assert!(csm.watch_contextoid(1, 10).is_ok());
+    assert!(csm.watch_contextoid(2, 10).is_ok());
+    assert_eq!(csm.watched_contextoids(1), vec![10, 20]);
+    assert_eq!(csm.watched_contextoids(2), vec![10]);
+
+    assert!(csm.watch_contextoid(1, 10).is_err());
+    assert!(csm.watch_contextoid(42, 10).is_err());
+
+    assert!(csm.unwatch_contextoid(1, 10).is_ok());
+    assert!(csm.unwatch_contextoid(1, 10).is_err());
+    assert!(csm.unwatch_contextoid(1, 99).is_err());
+    assert_eq!(csm.watched_contextoids(1), vec![20]);
+
+    // Watched contextoids of removed states are dropped.
+    assert!(csm.remove_single_state(2).is_ok());
+    assert!(csm.watched_contextoids(2).is_empty());
+    assert!(csm.affected_states(&[10]).unwrap().is_empty());
+
+    let state_actions = &[(&cs2, &ca)];
+    csm.update_all_states(state_actions);
+    assert!(csm.watched_contextoids(1).is_empty());
+}
+
+#[test]
+fn test_eval_changed_states() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_temp = CausalState::new(1, 1, data, causaloid);
+    let cs_pressure = CausalState::new(2, 1, data, causaloid);
+    let cs_alert = CausalState::new(3, 1, data, causaloid);
+    let ca_temp = CausalAction::new(temperature, "Temperature", 1);
+    let ca_pressure = CausalAction::new(pressure, "Pressure", 1);
+    let ca_alert = CausalAction::new(alert, "Alert", 1);
+
+    let state_actions = &[
+        (&cs_temp, &ca_temp),
+        (&cs_pressure, &ca_pressure),
+        (&cs_alert, &ca_alert),
+    ];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.watch_contextoid(1, 10).is_ok());
+    assert!(csm.watch_contextoid(2, 20).is_ok());
+    // The alert is evaluated after the temperature.
+    assert!(csm.add_dependency(3, 1).is_ok());
+
+    // Nothing watches contextoid 30.
+    assert!(csm.eval_changed_states(&[30]).unwrap().is_empty());
+    assert!(fired().is_empty());
+
+    assert_eq!(csm.eval_changed_states(&[20]).unwrap(), vec![2]);
+    assert_eq!(fired(), vec!["pressure"]);
+
+    // The alert depends on the temperature and is re-evaluated as well.
+    assert_eq!(csm.affected_states(&[10]).unwrap(), vec![1, 3]);
+    assert_eq!(csm.eval_changed_states(&[10]).unwrap(), vec![1, 3]);
+    assert_eq!(fired(), vec!["temperature", "alert"]);
+
+    assert_eq!(
+        csm.eval_changed_states(&[20, 10, 20]).unwrap(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(fired(), vec!["temperature", "pressure", "alert"]);
+}
+
+#[test]
+fn test_eval_context_delta() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_temp = CausalState::new(1, 1, data, causaloid);
+    let cs_pressure = CausalState::new(2, 1, data, causaloid);
+    let ca_temp = CausalAction::new(temperature, "Temperature", 1);
+    let ca_pressure = CausalAction::new(pressure, "Pressure", 1);
+
+    let state_actions = &[(&cs_temp, &ca_temp), (&cs_pressure, &ca_pressure)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.watch_contextoid(1, 2).is_ok());
+    assert!(csm.watch_contextoid(2, 3).is_ok());
+
+    let mut baseline = test_utils::get_test_context();
+    add_data(&mut baseline, 2, 20);
+    add_data(&mut baseline, 3, 30);
+
+    // Only the contextoid with id 2 changes.
+    let mut current = test_utils::get_test_context();
+    add_data(&mut current, 2, 25);
+    add_data(&mut current, 3, 30);
+
+    let delta = baseline.diff(&current);
+    assert_eq!(csm.eval_context_delta(&delta).unwrap(), vec![1]);
+    assert_eq!(fired(), vec!["temperature"]);
+
+    let delta = baseline.diff(&baseline);
+    assert!(csm.eval_context_delta(&delta).unwrap().is_empty());
+    assert!(fired().is_empty());
+}
+
+#[test]
+fn test_eval_pending_changes() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_temp = CausalState::new(1, 1, data, causaloid);
+    let cs_pressure = CausalState::new(2, 1, data, causaloid);
+    let ca_temp = CausalAction::new(temperature, "Temperature", 1);
+    let ca_pressure = CausalAction::new(pressure, "Pressure", 1);
+
+    let state_actions = &[(&cs_temp, &ca_temp), (&cs_pressure, &ca_pressure)];
+    let csm = CSM::new(state_actions);
+    assert!(csm.watch_contextoid(1, 2).is_ok());
+    assert!(csm.watch_contextoid(2, 3).is_ok());
+
+    let mut context = test_utils::get_test_context();
+    add_data(&mut context, 2, 20);
+    add_data(&mut context, 3, 30);
+
+    let changes = context.subscribe();
+    assert!(csm.eval_pending_changes(&changes).unwrap().is_empty());
+
+    context.update_data_by_id(2, Data::new(2, 21)).unwrap();
+    context.update_data_by_id(2, Data::new(2, 22)).unwrap();
+    assert_eq!(csm.eval_pending_changes(&changes).unwrap(), vec![1]);
+    assert_eq!(fired(), vec!["temperature"]);
+
+    assert!(csm.eval_pending_changes(&changes).unwrap().is_empty());
+    assert!(fired().is_empty());
+}
+
+#[test]
+fn test_run_on_changes() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_temp = CausalState::new(1, 1, data, causaloid);
+    let ca_temp = CausalAction::new(temperature, "Temperature", 1);
+
+    let state_actions = &[(&cs_temp, &ca_temp)];
+    let csm = CSM::new(state_actions);
+    assert!(csm.enable_history(10).is_ok());
+    assert!(csm.watch_contextoid(1, 2).is_ok());
+
+    let mut context = test_utils::get_test_context();
+    add_data(&mut context, 2, 20);
+    let changes = context.subscribe();
+
+    // The context updates on another thread; the loop ends when the context is dropped.
+    let producer = thread::spawn(move || {
+        for value in 21..24 {
+            context.update_data_by_id(2, Data::new(2, value)).unwrap();
+        }
+        add_data(&mut context, 5, 50);
+    });
+
+    assert!(csm.run_on_changes(&changes).is_ok());
+    producer.join().unwrap();
+
+    // Each batch evaluates the state once; how updates batch depends on timing.
+    let evaluations = csm.history().len();
+    assert!((1..=3).contains(&evaluations));
+    assert_eq!(fired().len(), evaluations);
+}
@@ -0,0 +1,268 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+use deep_causality::prelude::{
+    ActionError, ActionFailure, ActionPolicy, BaseCSM, BaseCausalState, CausalAction, CausalState,
+    CSM,
+};
+
+use crate::utils::test_utils;
+
+thread_local! {
+    static FIRED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    static FAILURES_LEFT: RefCell<u32> = const { RefCell::new(0) };
+    static CSM_UNDER_TEST: RefCell<Option<CSMUnderTest>> = const { RefCell::new(None) };
+}
+
+type CSMUnderTest = (
+    &'static BaseCSM<'static>,
+    &'static BaseCausalState<'static>,
+    &'static CausalAction,
+);
+
+// Fails once and then updates its own state in the CSM that fired it.
+fn flaky_updating_csm() -> Result<(), ActionError> {
+    flaky()?;
+
+    CSM_UNDER_TEST.with(|c| {
+        let (csm, state, action) = c.borrow().expect("CSM not set");
+        csm.update_single_state(*state.id(), (state, action))
+            .map_err(|e| ActionError(e.0))
+    })
+}
+
+// Fails as long as FAILURES_LEFT is positive.
+fn flaky() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("flaky"));
+    FAILURES_LEFT.with(|n| {
+        let mut n = n.borrow_mut();
+        if *n > 0 {
+            *n -= 1;
+            Err(ActionError("flaky failed".to_string()))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+fn fallback() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("fallback"));
+    Ok(())
+}
+
+fn fail_times(n: u32) {
+    FAILURES_LEFT.with(|f| *f.borrow_mut() = n);
+}
+
+fn fired() -> Vec<&'static str> {
+    FIRED.with(|f| f.borrow_mut().drain(..).collect())
+}
+
+#[test]
+fn test_action_policy() {
+    let ca_fallback = CausalAction::new(fallback, "Fallback", 1);
+
+    let policy = ActionPolicy::new();
+    assert_eq!(policy.max_retries(), 0);
+    assert_eq!(policy.initial_backoff(), Duration::ZERO);
+    assert_eq!(policy.backoff_factor(), 2);
+    assert!(policy.fallback().is_none());
+    assert!(policy.escalate());
+
+    let policy = ActionPolicy::new()
+        .with_retries(3, Duration::from_millis(10))
+        .with_backoff_factor(3)
+        .with_fallback(&ca_fallback)
+        .with_escalation(false);
+    assert_eq!(policy.max_retries(), 3);
+    assert_eq!(*policy.fallback().unwrap().descr(), "Fallback");
+    assert!(!policy.escalate());
+
+    assert_eq!(policy.backoff(1), Duration::from_millis(10));
+    assert_eq!(policy.backoff(2), Duration::from_millis(30));
+    assert_eq!(policy.backoff(3), Duration::from_millis(90));
+}
+
+#[test]
+fn test_retry_succeeds() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs = CausalState::new(1, 1, data, causaloid);
+    let ca = CausalAction::new(flaky, "Flaky", 1);
+    let state_actions = &[(&cs, &ca)];
+
+    let policy = ActionPolicy::new().with_retries(2, Duration::from_millis(1));
+    let csm = CSM::new_with_policies(state_actions, &[(1, policy)]).unwrap();
+    assert_eq!(csm.get_action_policy(1).max_retries(), 2);
+
+    fail_times(2);
+    assert!(csm.eval_single_state(1, data).is_ok());
+    assert_eq!(fired(), vec!["flaky", "flaky", "flaky"]);
+
+    fail_times(3);
+    assert!(csm.eval_all_states().is_err());
+    assert_eq!(fired(), vec!["flaky", "flaky", "flaky"]);
+    assert!(csm.action_failures().is_empty());
+}
+
+#[test]
+fn test_default_policy_escalates() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs = CausalState::new(1, 1, data, causaloid);
+    let ca = CausalAction::new(flaky, "Flaky", 1);
+    let state_actions = &[(&cs, &ca)];
+    let csm = CSM::new(state_actions);
+
+    fail_times(1);
+    assert!(csm.eval_single_state(1, data).is_err());
+    assert_eq!(fired(), vec!["flaky"]);
+}
+
+#[test]
+fn test_fallback_without_escalation() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs = CausalState::new(1, 1, data, causaloid);
+    let ca = CausalAction::new(flaky, "Flaky", 1);
+    let ca_fallback = CausalAction::new(fallback, "Fallback", 1);
+    let state_actions = &[(&cs, &ca)];
+
+    let csm = CSM::new(state_actions);
+    let policy = ActionPolicy::new()
+        .with_retries(1, Duration::ZERO)
+        .with_fallback(&ca_fallback)
+        .with_escalation(false);
+    assert!(csm.set_action_policy(1, policy).is_ok());
+
+    fail_times(2);
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), vec!["flaky", "flaky", "fallback"]);
+
+    let failures = csm.action_failures();
+    assert_eq!(
+        failures,
+        vec![ActionFailure::new(1, 2, true, "flaky failed".to_string())]
+    );
+    assert_eq!(
+        format!("{}", failures[0]),
+        "ActionFailure: state: 1, attempts: 2, fallback fired: true, error: flaky failed"
+    );
+
+    csm.clear_action_failures();
+    assert!(csm.action_failures().is_empty());
+}
+
+#[test]
+fn test_action_failures_capacity() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs = CausalState::new(1, 1, data, causaloid);
+    let ca = CausalAction::new(flaky, "Flaky", 1);
+    let state_actions = &[(&cs, &ca)];
+
+    let csm = CSM::new(state_actions);
+    let policy = ActionPolicy::new().with_escalation(false);
+    assert!(csm.set_action_policy(1, policy).is_ok());
+    assert_eq!(csm.action_failures_capacity(), 1024);
+    assert!(csm.set_action_failures_capacity(0).is_err());
+
+    fail_times(3);
+    for _ in 0..3 {
+        assert!(csm.eval_all_states().is_ok());
+    }
+    assert_eq!(csm.action_failures().len(), 3);
+
+    // Shrinking the capacity drops the oldest records.
+    assert!(csm.set_action_failures_capacity(2).is_ok());
+    assert_eq!(csm.action_failures_capacity(), 2);
+    assert_eq!(csm.action_failures().len(), 2);
+
+    fail_times(1);
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(csm.action_failures().len(), 2);
+    fired();
+}
+
+#[test]
+fn test_fallback_with_escalation() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs = CausalState::new(1, 1, data, causaloid);
+    let ca = CausalAction::new(flaky, "Flaky", 1);
+    let ca_fallback = CausalAction::new(fallback, "Fallback", 1);
+    let state_actions = &[(&cs, &ca)];
+
+    let policy = ActionPolicy::new().with_fallback(&ca_fallback);
+    let csm = CSM::new_with_policies(state_actions, &[(1, policy)]).unwrap();
+
+    fail_times(1);
+    assert!(csm.eval_single_state(1, data).is_err());
+    assert_eq!(fired(), vec!["flaky", "fallback"]);
+    assert!(csm.action_failures().is_empty());
+}
+
+#[test]
+fn test_policy_errors() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs1 = CausalState::new(1, 1, data, causaloid);
+    let cs2 = CausalState::new(2, 1, data, causaloid);
+    let ca = CausalAction::new(flaky, "Flaky", 1);
+    let state_actions = &[(&cs1, &ca), (&cs2, &ca)];
+
+    let policy = ActionPolicy::new().with_retries(3, Duration::ZERO);
+    assert!(CSM::new_with_policies(state_actions, &[(42, policy)]).is_err());
+
+    let csm = CSM::new_with_policies(state_actions, &[(1, policy), (2, policy)]).unwrap();
+    assert!(csm.set_action_policy(42, policy).is_err());
+
+    // Policies of removed states are dropped.
+    assert!(csm.remove_single_state(1).is_ok());
+    assert!(csm.add_single_state(1, (&cs1, &ca)).is_ok());
+    assert_eq!(csm.get_action_policy(1).max_retries(), 0);
+
+    let state_actions = &[(&cs1, &ca)];
+    csm.update_all_states(state_actions);
+    assert!(csm.add_single_state(2, (&cs2, &ca)).is_ok());
+    assert_eq!(csm.get_action_policy(2).max_retries(), 0);
+}
+
+#[test]
+fn test_retrying_action_updates_csm() {
+    let causaloid: &'static _ = Box::leak(Box::new(test_utils::get_test_causaloid()));
+    let data = 0.89f64;
+
+    let cs: &'static _ = Box::leak(Box::new(CausalState::new(1, 1, data, causaloid)));
+    let ca: &'static _ = Box::leak(Box::new(CausalAction::new(
+        flaky_updating_csm,
+        "Flaky updating CSM",
+        1,
+    )));
+    let state_actions: &'static _ = Box::leak(Box::new([(cs, ca)]));
+
+    let policy = ActionPolicy::new().with_retries(1, Duration::ZERO);
+    let csm: &'static BaseCSM<'static> = Box::leak(Box::new(
+        CSM::new_with_policies(state_actions, &[(1, policy)]).unwrap(),
+    ));
+    CSM_UNDER_TEST.with(|c| *c.borrow_mut() = Some((csm, cs, ca)));
+
+    // The state actions are not borrowed while the action fires.
+    fail_times(1);
+    assert!(csm.eval_single_state(1, data).is_ok());
+    assert_eq!(fired(), vec!["flaky", "flaky"]);
+
+    fail_times(1);
+    assert!(csm.eval_all_states().is_ok());
+    assert_eq!(fired(), vec!["flaky", "flaky"]);
+    assert!(csm.action_failures().is_empty());
+}
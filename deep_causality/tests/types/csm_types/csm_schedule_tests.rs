@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use deep_causality::prelude::{ActionError, CSMTrigger, CausalAction, CausalState, CSM};
+
+use crate::utils::test_utils;
+
+thread_local! {
+    static FIRED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+static STOP: AtomicBool = AtomicBool::new(false);
+
+fn alert() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("alert"));
+    Ok(())
+}
+
+fn log() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("log"));
+    Ok(())
+}
+
+fn stop() -> Result<(), ActionError> {
+    STOP.store(true, Ordering::Release);
+    Ok(())
+}
+
+fn fail() -> Result<(), ActionError> {
+    Err(ActionError("failed".to_string()))
+}
+
+fn fired() -> Vec<&'static str> {
+    FIRED.with(|f| f.borrow_mut().drain(..).collect())
+}
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn test_csm_trigger() {
+    let trigger = CSMTrigger::Interval(ms(10));
+    assert_eq!(trigger.period(), ms(10));
+    assert_eq!(trigger.first_due(ms(3)), ms(13));
+    assert_eq!(trigger.next_due(ms(13), ms(13)), ms(23));
+    // Missed evaluations are skipped.
+    assert_eq!(trigger.next_due(ms(13), ms(45)), ms(53));
+    assert_eq!(trigger.next_due(ms(13), ms(5)), ms(13));
+
+    let trigger = CSMTrigger::Aligned {
+        period: ms(10),
+        offset: ms(2),
+    };
+    assert_eq!(trigger.period(), ms(10));
+    assert_eq!(trigger.first_due(ms(0)), ms(2));
+    assert_eq!(trigger.first_due(ms(2)), ms(2));
+    assert_eq!(trigger.first_due(ms(3)), ms(12));
+    assert_eq!(trigger.first_due(ms(12)), ms(12));
+    assert_eq!(trigger.next_due(ms(12), ms(12)), ms(22));
+
+    assert_eq!(
+        format!("{}", trigger),
+        "CSMTrigger: every 10ms at offset 2ms"
+    );
+    assert_eq!(
+        format!("{}", CSMTrigger::Interval(ms(10))),
+        "CSMTrigger: every 10ms"
+    );
+}
+
+#[test]
+fn test_eval_on_interval() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_alert = CausalState::new(1, 1, data, causaloid);
+    let cs_log = CausalState::new(2, 1, data, causaloid);
+    let ca_alert = CausalAction::new(alert, "Alert", 1);
+    let ca_log = CausalAction::new(log, "Log", 1);
+
+    let state_actions = &[(&cs_alert, &ca_alert), (&cs_log, &ca_log)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.eval_on_interval(1, ms(10)).is_ok());
+    assert!(csm.eval_on_schedule(2, ms(25), ms(5)).is_ok());
+    assert_eq!(csm.scheduled_states(), vec![1, 2]);
+    assert_eq!(csm.get_schedule(1), Some(CSMTrigger::Interval(ms(10))));
+
+    // Nothing is evaluated before the schedule starts.
+    assert!(!csm.is_schedule_running());
+    assert_eq!(csm.next_due_time(), None);
+    assert!(csm.eval_due_states(ms(100)).unwrap().is_empty());
+    assert!(fired().is_empty());
+
+    csm.start_schedule(ms(0));
+    assert!(csm.is_schedule_running());
+    assert_eq!(csm.next_due_time(), Some(ms(5)));
+
+    assert_eq!(csm.eval_due_states(ms(5)).unwrap(), vec![2]);
+    assert_eq!(fired(), vec!["log"]);
+
+    assert!(csm.eval_due_states(ms(9)).unwrap().is_empty());
+
+    assert_eq!(csm.eval_due_states(ms(10)).unwrap(), vec![1]);
+    assert_eq!(fired(), vec!["alert"]);
+    assert_eq!(csm.next_due_time(), Some(ms(20)));
+
+    // Both states are due after a long pause but are evaluated only once.
+    assert_eq!(csm.eval_due_states(ms(45)).unwrap(), vec![1, 2]);
+    assert_eq!(fired(), vec!["alert", "log"]);
+    assert_eq!(csm.next_due_time(), Some(ms(50)));
+
+    csm.stop_schedule();
+    assert!(!csm.is_schedule_running());
+    assert!(csm.eval_due_states(ms(100)).unwrap().is_empty());
+    assert!(fired().is_empty());
+}
+
+#[test]
+fn test_eval_due_states_respects_dependencies() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_alert = CausalState::new(1, 1, data, causaloid);
+    let cs_log = CausalState::new(2, 1, data, causaloid);
+    let ca_alert = CausalAction::new(alert, "Alert", 1);
+    let ca_log = CausalAction::new(log, "Log", 1);
+
+    let state_actions = &[(&cs_alert, &ca_alert), (&cs_log, &ca_log)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.add_dependency(1, 2).is_ok());
+    assert!(csm.eval_on_interval(1, ms(10)).is_ok());
+    assert!(csm.eval_on_interval(2, ms(10)).is_ok());
+
+    csm.start_schedule(ms(0));
+    assert_eq!(csm.eval_due_states(ms(10)).unwrap(), vec![2, 1]);
+    assert_eq!(fired(), vec!["log", "alert"]);
+}
+
+#[test]
+fn test_schedule_errors() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_alert = CausalState::new(1, 1, data, causaloid);
+    let ca_alert = CausalAction::new(alert, "Alert", 1);
+
+    let state_actions = &[(&cs_alert, &ca_alert)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.eval_on_interval(42, ms(10)).is_err());
+    assert!(csm.eval_on_interval(1, Duration::ZERO).is_err());
+    assert!(csm.eval_on_schedule(1, ms(10), ms(10)).is_err());
+    assert!(csm.remove_schedule(1).is_err());
+
+    assert!(csm.eval_on_interval(1, ms(10)).is_ok());
+    assert!(csm.remove_schedule(1).is_ok());
+    assert_eq!(csm.get_schedule(1), None);
+    assert!(csm.scheduled_states().is_empty());
+}
+
+#[test]
+fn test_schedule_add_while_running_and_remove_state() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_alert = CausalState::new(1, 1, data, causaloid);
+    let cs_log = CausalState::new(2, 1, data, causaloid);
+    let ca_alert = CausalAction::new(alert, "Alert", 1);
+    let ca_log = CausalAction::new(log, "Log", 1);
+
+    let state_actions = &[(&cs_alert, &ca_alert), (&cs_log, &ca_log)];
+    let csm = CSM::new(state_actions);
+
+    csm.start_schedule(ms(0));
+    assert!(csm.eval_due_states(ms(30)).unwrap().is_empty());
+
+    // A trigger added while running becomes due relative to the last tick.
+    assert!(csm.eval_on_interval(1, ms(10)).is_ok());
+    assert!(csm.eval_on_interval(2, ms(10)).is_ok());
+    assert_eq!(csm.next_due_time(), Some(ms(40)));
+
+    assert!(csm.remove_single_state(2).is_ok());
+    assert_eq!(csm.scheduled_states(), vec![1]);
+
+    assert_eq!(csm.eval_due_states(ms(40)).unwrap(), vec![1]);
+    assert_eq!(fired(), vec!["alert"]);
+
+    let state_actions = &[(&cs_log, &ca_log)];
+    csm.update_all_states(state_actions);
+    assert!(csm.scheduled_states().is_empty());
+}
+
+#[test]
+fn test_eval_due_states_err() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_fail = CausalState::new(1, 1, data, causaloid);
+    let ca_fail = CausalAction::new(fail, "Fail", 1);
+
+    let state_actions = &[(&cs_fail, &ca_fail)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.eval_on_interval(1, ms(10)).is_ok());
+    csm.start_schedule(ms(0));
+    assert!(csm.eval_due_states(ms(10)).is_err());
+
+    // The failed state was rescheduled.
+    assert_eq!(csm.next_due_time(), Some(ms(20)));
+}
+
+#[test]
+fn test_run_schedule() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_stop = CausalState::new(1, 1, data, causaloid);
+    let ca_stop = CausalAction::new(stop, "Stop", 1);
+
+    let state_actions = &[(&cs_stop, &ca_stop)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm.eval_on_interval(1, ms(1)).is_ok());
+
+    // The action sets the stop flag, which ends the loop.
+    let res = csm.run_schedule(&STOP);
+    assert!(res.is_ok());
+    assert!(STOP.load(Ordering::Acquire));
+    assert!(!csm.is_schedule_running());
+}
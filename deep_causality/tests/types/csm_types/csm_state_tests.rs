@@ -3,7 +3,7 @@
 
 use deep_causality::prelude::{Causable, CausalState, Identifiable};
 
-use crate::utils::test_utils;
+use crate::utils::{test_utils, test_utils_async};
 
 #[test]
 fn test_new() {
@@ -101,3 +101,21 @@ fn test_to_string() {
     let actual = cs.to_string();
     assert_eq!(actual, expected)
 }
+
+#[test]
+fn test_eval_async() {
+    let id = 42;
+    let version = 1;
+    let data = 0.93f64;
+    let causaloid = &test_utils_async::get_test_async_causaloid();
+
+    let cs = CausalState::new(id, version, data, causaloid);
+
+    let res = test_utils_async::block_on(cs.eval_async());
+    assert!(res.unwrap());
+
+    let res = test_utils_async::block_on(cs.eval_with_data_async(&0.23));
+    assert!(!res.unwrap());
+
+    assert!(cs.eval().is_err());
+}
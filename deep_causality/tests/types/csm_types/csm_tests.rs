@@ -3,7 +3,7 @@
 
 use deep_causality::prelude::{ActionError, CausalAction, CausalState, CSM};
 
-use crate::utils::test_utils;
+use crate::utils::{test_utils, test_utils_async};
 
 fn state_action() -> Result<(), ActionError> {
     println!("Detected something and acted upon");
@@ -227,6 +227,33 @@ fn eval_single_state_err_not_found() {
     assert!(res.is_err())
 }
 
+#[test]
+fn eval_single_state_async() {
+    let id = 42;
+    let version = 1;
+    let data = 0.23f64;
+    let causaloid = &test_utils_async::get_test_async_causaloid();
+
+    let cs = CausalState::new(id, version, data, causaloid);
+    let ca = get_test_action();
+    let state_action = &[(&cs, &ca)];
+    let csm = CSM::new(state_action);
+
+    let res = test_utils_async::block_on(csm.eval_single_state_async(id, 0.89));
+    assert!(res.is_ok());
+    assert!(causaloid.active());
+
+    // Sync evaluation of an async causaloid fails.
+    let res = csm.eval_single_state(id, 0.89);
+    assert!(res.is_err());
+
+    let res = test_utils_async::block_on(csm.eval_single_state_async(id, f64::NAN));
+    assert!(res.is_err());
+
+    let res = test_utils_async::block_on(csm.eval_single_state_async(23, data));
+    assert!(res.is_err());
+}
+
 #[test]
 fn eval_all_states() {
     let id = 42;
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+use deep_causality::prelude::{
+    ActionError, ActionOutcome, CausalAction, CausalState, StateArbitration, Watchdog, CSM,
+};
+
+use crate::utils::test_utils;
+
+thread_local! {
+    static FIRED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+fn temperature_dead() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("temperature dead"));
+    Ok(())
+}
+
+fn pressure_dead() -> Result<(), ActionError> {
+    FIRED.with(|f| f.borrow_mut().push("pressure dead"));
+    Ok(())
+}
+
+fn fired() -> Vec<&'static str> {
+    FIRED.with(|f| f.borrow_mut().drain(..).collect())
+}
+
+fn secs(secs: u64) -> Duration {
+    Duration::from_secs(secs)
+}
+
+const TEMPERATURE: u64 = 10;
+const PRESSURE: u64 = 20;
+
+#[test]
+fn test_watchdog() {
+    let watchdog = Watchdog::new(TEMPERATURE, secs(5));
+    assert_eq!(*watchdog.datoid_id(), TEMPERATURE);
+    assert_eq!(*watchdog.deadline(), secs(5));
+    assert_eq!(format!("{}", watchdog), "Watchdog: datoid: 10 deadline: 5s");
+}
+
+#[test]
+fn test_check_watchdogs() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_temp = CausalState::new(1, 1, data, causaloid);
+    let cs_pressure = CausalState::new(2, 1, data, causaloid);
+    let ca_temp = CausalAction::new(temperature_dead, "Temperature dead", 1);
+    let ca_pressure = CausalAction::new(pressure_dead, "Pressure dead", 1);
+
+    let state_actions = &[(&cs_temp, &ca_temp), (&cs_pressure, &ca_pressure)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm
+        .add_watchdog(1, Watchdog::new(TEMPERATURE, secs(5)))
+        .is_ok());
+    assert!(csm
+        .add_watchdog(2, Watchdog::new(PRESSURE, secs(10)))
+        .is_ok());
+    assert_eq!(
+        csm.get_watchdog(1),
+        Some(Watchdog::new(TEMPERATURE, secs(5)))
+    );
+
+    // The first check starts all deadlines that have not seen a heartbeat.
+    assert!(csm.check_watchdogs(secs(0)).unwrap().is_empty());
+
+    csm.heartbeat(TEMPERATURE, secs(3));
+    assert!(csm.check_watchdogs(secs(7)).unwrap().is_empty());
+
+    assert_eq!(csm.check_watchdogs(secs(8)).unwrap(), vec![1]);
+    assert!(csm.is_watchdog_tripped(1));
+    assert_eq!(fired(), vec!["temperature dead"]);
+
+    // A tripped watchdog fires only once per missed deadline.
+    assert_eq!(csm.check_watchdogs(secs(10)).unwrap(), vec![2]);
+    assert_eq!(fired(), vec!["pressure dead"]);
+    assert!(csm.check_watchdogs(secs(60)).unwrap().is_empty());
+
+    // A heartbeat re-arms the watchdog.
+    csm.heartbeat(TEMPERATURE, secs(61));
+    assert!(!csm.is_watchdog_tripped(1));
+    assert!(csm.is_watchdog_tripped(2));
+    assert_eq!(csm.check_watchdogs(secs(66)).unwrap(), vec![1]);
+    assert_eq!(fired(), vec!["temperature dead"]);
+}
+
+#[test]
+fn test_watchdog_history_and_arbitration() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_temp = CausalState::new(1, 1, data, causaloid);
+    let cs_pressure = CausalState::new(2, 1, data, causaloid);
+    let ca_temp = CausalAction::new(temperature_dead, "Temperature dead", 1);
+    let ca_pressure = CausalAction::new(pressure_dead, "Pressure dead", 1);
+
+    let state_actions = &[(&cs_temp, &ca_temp), (&cs_pressure, &ca_pressure)];
+    let csm = CSM::new(state_actions);
+    assert!(csm.enable_history(10).is_ok());
+
+    assert!(csm
+        .add_watchdog(1, Watchdog::new(TEMPERATURE, secs(5)))
+        .is_ok());
+    assert!(csm
+        .add_watchdog(2, Watchdog::new(TEMPERATURE, secs(5)))
+        .is_ok());
+    assert!(csm
+        .set_arbitration(1, StateArbitration::new().with_group(1))
+        .is_ok());
+    assert!(csm
+        .set_arbitration(2, StateArbitration::new().with_group(1).with_priority(1))
+        .is_ok());
+
+    csm.heartbeat(TEMPERATURE, secs(0));
+    assert_eq!(csm.check_watchdogs(secs(6)).unwrap(), vec![1, 2]);
+    assert_eq!(fired(), vec!["pressure dead"]);
+
+    let history = csm.history();
+    assert_eq!(history.len(), 2);
+    assert_eq!(*history[0].input(), 6.0);
+    assert_eq!(*history[0].outcome(), ActionOutcome::Suppressed);
+    assert_eq!(*history[1].outcome(), ActionOutcome::Fired);
+}
+
+#[test]
+fn test_watchdog_errors() {
+    let causaloid = &test_utils::get_test_causaloid();
+    let data = 0.89f64;
+
+    let cs_temp = CausalState::new(1, 1, data, causaloid);
+    let ca_temp = CausalAction::new(temperature_dead, "Temperature dead", 1);
+
+    let state_actions = &[(&cs_temp, &ca_temp)];
+    let csm = CSM::new(state_actions);
+
+    assert!(csm
+        .add_watchdog(42, Watchdog::new(TEMPERATURE, secs(5)))
+        .is_err());
+    assert!(csm
+        .add_watchdog(1, Watchdog::new(TEMPERATURE, Duration::ZERO))
+        .is_err());
+    assert!(csm.remove_watchdog(1).is_err());
+
+    assert!(csm
+        .add_watchdog(1, Watchdog::new(TEMPERATURE, secs(5)))
+        .is_ok());
+    assert!(csm.remove_watchdog(1).is_ok());
+    assert_eq!(csm.get_watchdog(1), None);
+
+    // Watchdogs of removed states are dropped.
+    assert!(csm
+        .add_watchdog(1, Watchdog::new(TEMPERATURE, secs(5)))
+        .is_ok());
+    assert!(csm.remove_single_state(1).is_ok());
+    assert_eq!(csm.get_watchdog(1), None);
+}
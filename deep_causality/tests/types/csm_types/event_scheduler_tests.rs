@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils;
+
+#[derive(Debug, Clone, PartialEq)]
+enum QueueEvent {
+    Arrival(u64),
+    Departure(u64),
+}
+
+#[test]
+fn test_new() {
+    let scheduler: DiscreteEventScheduler<QueueEvent> =
+        DiscreteEventScheduler::new(TimeScale::Minute);
+
+    assert_eq!(scheduler.now(), 0);
+    assert_eq!(scheduler.time_scale(), TimeScale::Minute);
+    assert!(scheduler.is_empty());
+    assert_eq!(scheduler.len(), 0);
+    assert_eq!(scheduler.number_processed(), 0);
+    assert_eq!(scheduler.next_event_time(), None);
+}
+
+#[test]
+fn test_schedule_order() {
+    let mut scheduler = DiscreteEventScheduler::new(TimeScale::Minute);
+
+    scheduler.schedule_at(5, QueueEvent::Arrival(2)).unwrap();
+    scheduler.schedule_at(1, QueueEvent::Arrival(1)).unwrap();
+    scheduler.schedule_at(5, QueueEvent::Departure(1)).unwrap();
+    assert_eq!(scheduler.len(), 3);
+    assert_eq!(scheduler.next_event_time(), Some(1));
+
+    assert_eq!(
+        scheduler.next_event_until(10),
+        Some((1, QueueEvent::Arrival(1)))
+    );
+    assert_eq!(scheduler.now(), 1);
+
+    // Same time: order of scheduling.
+    assert_eq!(
+        scheduler.next_event_until(10),
+        Some((5, QueueEvent::Arrival(2)))
+    );
+    assert_eq!(scheduler.next_event_until(4), None);
+    assert_eq!(
+        scheduler.next_event_until(10),
+        Some((5, QueueEvent::Departure(1)))
+    );
+    assert_eq!(scheduler.next_event_until(10), None);
+    assert_eq!(scheduler.number_processed(), 3);
+}
+
+#[test]
+fn test_schedule_in_the_past() {
+    let mut scheduler = DiscreteEventScheduler::new(TimeScale::Minute);
+    scheduler.schedule_at(3, QueueEvent::Arrival(1)).unwrap();
+    scheduler.next_event_until(3);
+
+    assert!(scheduler.schedule_at(2, QueueEvent::Arrival(2)).is_err());
+
+    scheduler.schedule_in(2, QueueEvent::Departure(1));
+    assert_eq!(scheduler.next_event_time(), Some(5));
+}
+
+#[test]
+fn test_run_until() {
+    // Single server queue: arrivals every 2 minutes, service takes 3 minutes.
+    let mut scheduler = DiscreteEventScheduler::new(TimeScale::Minute);
+    scheduler.schedule_at(0, QueueEvent::Arrival(1)).unwrap();
+
+    let mut departures = Vec::new();
+    let processed = scheduler
+        .run_until(10, |scheduler, time, event| {
+            match event {
+                QueueEvent::Arrival(customer) => {
+                    scheduler.schedule_in(2, QueueEvent::Arrival(customer + 1));
+                    scheduler.schedule_in(3, QueueEvent::Departure(customer));
+                }
+                QueueEvent::Departure(customer) => departures.push((time, customer)),
+            }
+            Ok(())
+        })
+        .unwrap();
+
+    // Arrivals at 0, 2, 4, 6, 8, 10 and departures at 3, 5, 7, 9
+    assert_eq!(processed, 10);
+    assert_eq!(departures, vec![(3, 1), (5, 2), (7, 3), (9, 4)]);
+    assert_eq!(scheduler.now(), 10);
+    assert_eq!(scheduler.next_event_time(), Some(11));
+
+    let time = scheduler.current_time(7);
+    assert_eq!(*time.time_unit(), 10);
+    assert_eq!(time.time_scale(), TimeScale::Minute);
+
+    // Virtual time advances to the end time even without events.
+    scheduler.run_until(11, |_, _, _| Ok(())).unwrap();
+    assert_eq!(scheduler.now(), 11);
+}
+
+#[test]
+fn test_run_until_error() {
+    let mut scheduler = DiscreteEventScheduler::new(TimeScale::Minute);
+    scheduler.schedule_at(1, QueueEvent::Arrival(1)).unwrap();
+    scheduler.schedule_at(2, QueueEvent::Arrival(2)).unwrap();
+
+    let res = scheduler.run_until(10, |_, _, _| Err(ActionError("failed".into())));
+    assert!(res.is_err());
+    assert_eq!(scheduler.now(), 1);
+    assert_eq!(scheduler.len(), 1);
+}
+
+static NUMBER_FIRED: AtomicUsize = AtomicUsize::new(0);
+
+fn count_action() -> Result<(), ActionError> {
+    NUMBER_FIRED.fetch_add(1, Ordering::SeqCst);
+    Ok(())
+}
+
+#[test]
+fn test_run_csm_until() {
+    let id = 42;
+    let causaloid = &test_utils::get_test_causaloid();
+    let cs = CausalState::new(id, 1, 0.0, causaloid);
+    let ca = CausalAction::new(count_action, "Counts fired actions", 1);
+    let state_action = &[(&cs, &ca)];
+    let csm = CSM::new(state_action);
+
+    let mut scheduler = DiscreteEventScheduler::new(TimeScale::Second);
+    scheduler
+        .schedule_at(1, StateEvaluation::new(id, 0.2))
+        .unwrap();
+    scheduler
+        .schedule_at(2, StateEvaluation::new(id, 0.9))
+        .unwrap();
+    scheduler
+        .schedule_at(3, StateEvaluation::new(id, 0.8))
+        .unwrap();
+    scheduler
+        .schedule_at(20, StateEvaluation::new(id, 0.8))
+        .unwrap();
+
+    let processed = scheduler.run_csm_until(&csm, 10).unwrap();
+    assert_eq!(processed, 3);
+    assert_eq!(NUMBER_FIRED.load(Ordering::SeqCst), 2);
+
+    scheduler
+        .schedule_at(30, StateEvaluation::new(23, 0.8))
+        .unwrap();
+    assert!(scheduler.run_csm_until(&csm, 30).is_err());
+}
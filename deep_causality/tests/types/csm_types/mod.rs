@@ -4,6 +4,24 @@
 #[cfg(test)]
 mod csm_action_tests;
 #[cfg(test)]
+mod csm_arbitration_tests;
+#[cfg(test)]
+mod csm_debounce_tests;
+#[cfg(test)]
+mod csm_dependency_tests;
+#[cfg(test)]
+mod csm_history_tests;
+#[cfg(test)]
+mod csm_incremental_tests;
+#[cfg(test)]
+mod csm_policy_tests;
+#[cfg(test)]
+mod csm_schedule_tests;
+#[cfg(test)]
 mod csm_state_tests;
 #[cfg(test)]
 mod csm_tests;
+#[cfg(test)]
+mod csm_watchdog_tests;
+#[cfg(test)]
+mod event_scheduler_tests;
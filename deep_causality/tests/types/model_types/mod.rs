@@ -3,3 +3,5 @@
 
 #[cfg(test)]
 mod model_tests;
+#[cfg(test)]
+mod model_version_tests;
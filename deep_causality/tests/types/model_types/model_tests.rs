@@ -1,10 +1,13 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
-use deep_causality::prelude::Identifiable;
+use deep_causality::prelude::{
+    Assumable, BaseCausaloid, Causaloid, Fingerprintable, Identifiable, Inference, ModelVersion,
+};
 use deep_causality::types::model_types::Model;
 
 use crate::utils::test_utils::*;
+use crate::utils::test_utils_graph::*;
 
 #[test]
 fn test_new() {
@@ -133,3 +136,236 @@ fn test_context() {
     assert!(model.context().is_some());
     assert_eq!(model.context().unwrap().id(), id);
 }
+
+#[test]
+fn test_fingerprint() {
+    let causaloid = &get_test_causaloid();
+    let binding = get_test_context();
+    let context = Some(&binding);
+
+    let model = Model::new(
+        1,
+        "John Doe",
+        "This is a test model",
+        None,
+        causaloid,
+        context,
+    );
+    let same = Model::new(
+        1,
+        "John Doe",
+        "This is a test model",
+        None,
+        causaloid,
+        context,
+    );
+    assert_eq!(model.fingerprint(), same.fingerprint());
+
+    let other = Model::new(
+        1,
+        "Jane Doe",
+        "This is a test model",
+        None,
+        causaloid,
+        context,
+    );
+    assert_ne!(model.fingerprint(), other.fingerprint());
+
+    let no_context = Model::new(1, "John Doe", "This is a test model", None, causaloid, None);
+    assert_ne!(model.fingerprint(), no_context.fingerprint());
+}
+
+#[test]
+fn test_generate_model_card() {
+    let causaloid = &get_test_causaloid();
+    let binding = get_test_context();
+    let context = Some(&binding);
+
+    let a1 = get_test_assumption();
+    let a2 = get_test_assumption();
+    a1.verify_assumption(&[1.0]);
+    let assumptions = vec![&a1, &a2];
+
+    let model = Model::new(
+        1,
+        "John Doe",
+        "This is a test model",
+        Some(&assumptions),
+        causaloid,
+        context,
+    );
+
+    let card = model.generate_model_card();
+    assert_eq!(*card.model_id(), 1);
+    assert_eq!(card.author(), "John Doe");
+    assert_eq!(*card.fingerprint(), model.fingerprint());
+    assert_eq!(card.causal_structure(), "Singleton");
+    assert_eq!(*card.number_causaloids(), 1);
+    assert_eq!(*card.number_causal_edges(), 0);
+    assert_eq!(
+        *card.supported_queries(),
+        vec!["verify_single_cause".to_string(), "explain".to_string()]
+    );
+
+    let context_card = card.context().as_ref().unwrap();
+    assert_eq!(context_card.name(), "Test-Context");
+    assert_eq!(*context_card.number_nodes(), 1);
+    assert_eq!(*context_card.fingerprint(), binding.fingerprint());
+
+    assert_eq!(card.assumptions().len(), 2);
+    assert_eq!(card.number_assumptions_tested(), 1);
+    assert_eq!(card.number_assumptions_valid(), 1);
+    assert_eq!(card.percent_assumptions_valid(), Some(100.0));
+}
+
+#[test]
+fn test_generate_model_card_graph() {
+    let (graph, _) = get_small_multi_cause_graph_and_data();
+    let causaloid: BaseCausaloid = Causaloid::from_causal_graph(2, &graph, "multi cause graph");
+
+    let model = Model::new(1, "John Doe", "Graph model", None, &causaloid, None);
+
+    let card = model.generate_model_card();
+    assert_eq!(*card.causaloid_id(), 2);
+    assert_eq!(card.causal_structure(), "Graph");
+    assert_eq!(*card.number_causaloids(), 4);
+    assert_eq!(*card.number_causal_edges(), 4);
+    assert!(card.context().is_none());
+    assert!(card.assumptions().is_empty());
+    assert_eq!(card.percent_assumptions_valid(), None);
+    assert!(card
+        .supported_queries()
+        .contains(&"reason_all_causes".to_string()));
+}
+
+#[test]
+fn test_model_card_to_json() {
+    let causaloid = &get_test_causaloid();
+    let model = Model::new(7, "John \"JD\" Doe", "Test", None, causaloid, None);
+
+    let card = model.generate_model_card();
+    let expected = format!(
+        "{{\"model_id\":7,\"author\":\"John \\\"JD\\\" Doe\",\"description\":\"Test\",\"fingerprint\":{},\"causaloid_id\":1,\"causaloid_description\":\"{}\",\"causal_structure\":\"Singleton\",\"number_causaloids\":1,\"number_causal_edges\":0,\"context\":null,\"assumptions\":[],\"percent_assumptions_valid\":null,\"supported_queries\":[\"verify_single_cause\",\"explain\"]}}",
+        model.fingerprint(),
+        causaloid.description()
+    );
+    assert_eq!(card.to_json(), expected);
+}
+
+#[test]
+fn test_model_card_to_markdown() {
+    let causaloid = &get_test_causaloid();
+    let binding = get_test_context();
+    let a1 = get_test_assumption();
+    let assumptions = vec![&a1];
+
+    let model = Model::new(
+        1,
+        "John Doe",
+        "This is a test model",
+        Some(&assumptions),
+        causaloid,
+        Some(&binding),
+    );
+
+    let md = model.generate_model_card().to_markdown();
+    assert!(md.starts_with("# Model Card: This is a test model\n"));
+    assert!(md.contains("| Author | John Doe |"));
+    assert!(md.contains("| Structure | Singleton |"));
+    assert!(md.contains("| Name | Test-Context |"));
+    assert!(md.contains("| 1 | Test assumption that data are there | false | false |"));
+    assert!(md.contains("No assumption has been tested."));
+    assert!(md.ends_with("* verify_single_cause\n* explain\n"));
+
+    a1.verify_assumption(&[1.0]);
+    let md = model.generate_model_card().to_markdown();
+    assert!(md.contains("Valid: 1 of 1 tested assumptions (100.0%)."));
+}
+
+#[test]
+fn test_version_and_creation() {
+    let causaloid = &get_test_causaloid();
+    let inferences = get_test_inf_vec();
+    let inferences: Vec<&Inference> = inferences.iter().collect();
+
+    let model = Model::new(1, "John Doe", "Test", None, causaloid, None);
+    assert_eq!(*model.version(), ModelVersion::new(0, 1, 0));
+    assert!(model.created_at().is_none());
+    assert!(model.inferences().is_none());
+
+    let model = model
+        .with_version(ModelVersion::new(2, 1, 0))
+        .with_created_at(1_700_000_000_000)
+        .with_inferences(&inferences);
+    assert_eq!(*model.version(), ModelVersion::new(2, 1, 0));
+    assert_eq!(*model.created_at(), Some(1_700_000_000_000));
+    assert_eq!(model.inferences().unwrap().len(), 2);
+    assert_eq!(model.causaloid_hash(), causaloid.fingerprint());
+}
+
+#[test]
+fn test_provenance() {
+    let causaloid = &get_test_causaloid();
+    let binding = get_test_context();
+    let a1 = get_test_assumption();
+    a1.verify_assumption(&[1.0]);
+    let assumptions = vec![&a1];
+    let inferences = get_test_inf_vec();
+    let inferences: Vec<&Inference> = inferences.iter().collect();
+
+    let model = Model::new(
+        1,
+        "John Doe",
+        "This is a test model",
+        Some(&assumptions),
+        causaloid,
+        Some(&binding),
+    )
+    .with_version(ModelVersion::new(1, 0, 0))
+    .with_created_at(42)
+    .with_inferences(&inferences);
+
+    let provenance = model.provenance();
+    assert_eq!(*provenance.model_id(), 1);
+    assert_eq!(*provenance.version(), ModelVersion::new(1, 0, 0));
+    assert_eq!(*provenance.created_at(), Some(42));
+    assert_eq!(provenance.library_version(), env!("CARGO_PKG_VERSION"));
+    assert_eq!(*provenance.fingerprint(), model.fingerprint());
+    assert_eq!(*provenance.causaloid_hash(), causaloid.fingerprint());
+    assert_eq!(*provenance.context_hash(), Some(binding.fingerprint()));
+    assert_eq!(provenance.assumptions().len(), 1);
+    assert!(*provenance.assumptions()[0].valid());
+    assert_eq!(provenance.inferences().len(), 2);
+
+    assert!(model.verify_provenance(&provenance));
+
+    let newer = model.clone().with_version(ModelVersion::new(1, 1, 0));
+    assert!(!newer.verify_provenance(&provenance));
+
+    let other = Model::new(1, "Jane Doe", "This is a test model", None, causaloid, None)
+        .with_version(ModelVersion::new(1, 0, 0));
+    assert!(!other.verify_provenance(&provenance));
+}
+
+#[test]
+fn test_provenance_to_json() {
+    let causaloid = &get_test_causaloid();
+    let model = Model::new(7, "John Doe", "Test", None, causaloid, None);
+
+    let provenance = model.provenance();
+    let expected = format!(
+        "{{\"model_id\":7,\"version\":\"0.1.0\",\"author\":\"John Doe\",\"description\":\"Test\",\"created_at\":null,\"library_version\":\"{}\",\"fingerprint\":{},\"causaloid_id\":1,\"causaloid_hash\":{},\"context_hash\":null,\"assumptions\":[],\"inferences\":[]}}",
+        env!("CARGO_PKG_VERSION"),
+        model.fingerprint(),
+        causaloid.fingerprint()
+    );
+    assert_eq!(provenance.to_json(), expected);
+
+    let inference = Inference::new(3, "q".to_string(), 0.5, 0.1, 1.0, 1.0);
+    let inferences = vec![&inference];
+    let model = model.with_inferences(&inferences);
+    assert!(model
+        .provenance()
+        .to_json()
+        .ends_with("\"inferences\":[{\"id\":3,\"question\":\"q\",\"observation\":0.5,\"threshold\":0.1,\"effect\":1,\"target\":1}]}"));
+}
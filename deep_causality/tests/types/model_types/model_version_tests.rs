@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::str::FromStr;
+
+use deep_causality::prelude::ModelVersion;
+
+#[test]
+fn test_new() {
+    let version = ModelVersion::new(1, 2, 3);
+    assert_eq!(*version.major(), 1);
+    assert_eq!(*version.minor(), 2);
+    assert_eq!(*version.patch(), 3);
+    assert_eq!(ModelVersion::default(), ModelVersion::new(0, 1, 0));
+}
+
+#[test]
+fn test_ordering() {
+    assert!(ModelVersion::new(1, 2, 3) < ModelVersion::new(1, 10, 0));
+    assert!(ModelVersion::new(2, 0, 0) > ModelVersion::new(1, 99, 99));
+}
+
+#[test]
+fn test_is_compatible_with() {
+    let version = ModelVersion::new(1, 2, 3);
+    assert!(version.is_compatible_with(&ModelVersion::new(1, 5, 0)));
+    assert!(!version.is_compatible_with(&ModelVersion::new(2, 0, 0)));
+
+    let version = ModelVersion::new(0, 2, 0);
+    assert!(version.is_compatible_with(&ModelVersion::new(0, 2, 7)));
+    assert!(!version.is_compatible_with(&ModelVersion::new(0, 3, 0)));
+}
+
+#[test]
+fn test_from_str() {
+    let version = ModelVersion::from_str("1.2.3").unwrap();
+    assert_eq!(version, ModelVersion::new(1, 2, 3));
+    assert_eq!(version.to_string(), "1.2.3");
+
+    assert!(ModelVersion::from_str("1.2").is_err());
+    assert!(ModelVersion::from_str("1.x.3").is_err());
+}
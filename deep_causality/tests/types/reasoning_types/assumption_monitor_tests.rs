@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+use crate::utils::test_utils_graph::*;
+
+fn all_below_one(data: &[NumericalValue]) -> bool {
+    data.iter().all(|x| *x < 1.0)
+}
+
+fn get_bounded_assumption() -> Assumption {
+    Assumption::new(2, "Data are below one".to_string(), all_below_one)
+}
+
+#[test]
+fn test_new() {
+    let monitor = AssumptionMonitor::new();
+    assert_eq!(monitor.number_assumptions(), 0);
+    assert_eq!(monitor.number_evaluations(), 0);
+    assert!(!monitor.halt_on_violation());
+    assert!(monitor.violations().is_empty());
+
+    let monitor = AssumptionMonitor::new().with_halt_on_violation(true);
+    assert!(monitor.halt_on_violation());
+}
+
+#[test]
+fn test_attach_detach() {
+    let a1 = get_test_assumption();
+    let a2 = get_bounded_assumption();
+
+    let mut monitor = AssumptionMonitor::new();
+    monitor.attach(&a1);
+    monitor.attach_to_causaloid(1, &a2);
+    monitor.attach_to_causaloid(2, &a2);
+    assert_eq!(monitor.number_assumptions(), 3);
+
+    assert_eq!(monitor.detach(2), 2);
+    assert_eq!(monitor.detach(2), 0);
+    assert_eq!(monitor.number_assumptions(), 1);
+}
+
+#[test]
+fn test_verify() {
+    let a1 = get_test_assumption();
+    let a2 = get_bounded_assumption();
+
+    let mut monitor = AssumptionMonitor::new();
+    monitor.attach(&a1);
+    monitor.attach_to_causaloid(1, &a2);
+
+    let violations = monitor.verify(&[0.2, 0.5], None);
+    assert!(violations.is_empty());
+    assert!(a1.assumption_valid());
+    assert!(a2.assumption_valid());
+
+    // Only the observation of causaloid 1 is evidence for the attached assumption.
+    let violations = monitor.verify(&[5.0, 0.5], None);
+    assert!(violations.is_empty());
+
+    let violations = monitor.verify(&[0.5, 5.0], None);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(*violations[0].assumption_id(), 2);
+    assert_eq!(*violations[0].scope(), AssumptionScope::Causaloid(1));
+    assert_eq!(*violations[0].evaluation(), 3);
+    assert!(!a2.assumption_valid());
+
+    // Causaloid without data is skipped whereas the graph assumption fails on empty data.
+    let violations = monitor.verify(&[], None);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(*violations[0].scope(), AssumptionScope::Graph);
+
+    assert_eq!(monitor.number_evaluations(), 4);
+    assert_eq!(monitor.violations().len(), 2);
+    assert_eq!(monitor.violations_of(2).len(), 1);
+
+    monitor.clear_violations();
+    assert!(monitor.violations().is_empty());
+}
+
+#[test]
+fn test_verify_with_data_index() {
+    let a2 = get_bounded_assumption();
+
+    let mut monitor = AssumptionMonitor::new();
+    monitor.attach_to_causaloid(0, &a2);
+
+    let mut data_index = HashMap::new();
+    data_index.insert(0, 1);
+
+    let violations = monitor.verify(&[0.5, 5.0], Some(&data_index));
+    assert_eq!(violations.len(), 1);
+}
+
+#[test]
+fn test_reason_all_causes() {
+    let (graph, data) = get_small_multi_cause_graph_and_data();
+    let a2 = get_bounded_assumption();
+
+    let mut monitor = AssumptionMonitor::new();
+    monitor.attach(&a2);
+
+    let res = monitor.reason_all_causes(&graph, &data, None);
+    assert!(res.unwrap());
+    assert!(monitor.violations().is_empty());
+
+    let mut data = data;
+    data[2] = 1.5;
+    let res = monitor.reason_all_causes(&graph, &data, None);
+    assert!(res.unwrap());
+    assert_eq!(monitor.violations().len(), 1);
+
+    let mut monitor = monitor.with_halt_on_violation(true);
+    let res = monitor.reason_all_causes(&graph, &data, None);
+    assert!(res.is_err());
+    assert_eq!(monitor.violations().len(), 2);
+}
+
+#[test]
+fn test_reason_all_causes_with_policy() {
+    let (graph, data) = get_small_multi_cause_graph_and_data();
+    let a2 = get_bounded_assumption();
+
+    let mut monitor = AssumptionMonitor::new().with_halt_on_violation(true);
+    monitor.attach_to_causaloid(3, &a2);
+
+    let report = monitor
+        .reason_all_causes_with_policy(&graph, &data, None, ReasoningPolicy::SkipAndLog)
+        .unwrap();
+    assert!(*report.result());
+
+    let mut data = data;
+    data[3] = 2.0;
+    let res =
+        monitor.reason_all_causes_with_policy(&graph, &data, None, ReasoningPolicy::SkipAndLog);
+    assert!(res.is_err());
+    assert_eq!(monitor.violations_of(2).len(), 1);
+}
+
+#[test]
+fn test_violation_display() {
+    let violation = AssumptionViolation::new(2, "bounded".to_string(), AssumptionScope::Graph, 1);
+    assert_eq!(
+        violation.to_string(),
+        "AssumptionViolation: id: 2, scope: Graph, evaluation: 1, description: bounded"
+    );
+}
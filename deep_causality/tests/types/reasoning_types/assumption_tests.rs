@@ -56,6 +56,18 @@ fn test_assumption_valid() {
     assert!(valid);
 }
 
+#[test]
+fn test_assumption_reverified_invalid() {
+    let assumption = get_test_assumption();
+
+    assert!(assumption.verify_assumption(&get_test_num_array()));
+    assert!(assumption.assumption_valid());
+
+    assert!(!assumption.verify_assumption(&[]));
+    assert!(assumption.assumption_tested());
+    assert!(!assumption.assumption_valid());
+}
+
 #[test]
 fn test_assumption_id() {
     let id = 1;
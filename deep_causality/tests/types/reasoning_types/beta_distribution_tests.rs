@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::BetaDistribution;
+
+#[test]
+fn test_new() {
+    let beta = BetaDistribution::new(2.0, 3.0).unwrap();
+    assert_eq!(beta.alpha(), 2.0);
+    assert_eq!(beta.beta(), 3.0);
+
+    assert!(BetaDistribution::new(0.0, 1.0).is_err());
+    assert!(BetaDistribution::new(1.0, -1.0).is_err());
+    assert!(BetaDistribution::new(f64::NAN, 1.0).is_err());
+    assert!(BetaDistribution::new(1.0, f64::INFINITY).is_err());
+
+    assert_eq!(BetaDistribution::default(), BetaDistribution::uniform());
+}
+
+#[test]
+fn test_moments() {
+    let uniform = BetaDistribution::uniform();
+    assert_eq!(uniform.mean(), 0.5);
+    assert_eq!(uniform.variance(), 1.0 / 12.0);
+    assert_eq!(uniform.mode(), None);
+    assert_eq!(uniform.concentration(), 2.0);
+
+    let beta = BetaDistribution::new(3.0, 2.0).unwrap();
+    assert_eq!(beta.mean(), 0.6);
+    assert_eq!(beta.variance(), 0.04);
+    assert_eq!(beta.mode(), Some(2.0 / 3.0));
+}
+
+#[test]
+fn test_update() {
+    let mut beta = BetaDistribution::uniform();
+    beta.update(true);
+    beta.update(true);
+    beta.update(false);
+    assert_eq!(beta, BetaDistribution::new(3.0, 2.0).unwrap());
+
+    let posterior = BetaDistribution::uniform().posterior(2, 1);
+    assert_eq!(posterior, beta);
+
+    // The uncertainty shrinks as evidence accumulates.
+    let strong = BetaDistribution::uniform().posterior(200, 100);
+    assert!(strong.variance() < beta.variance());
+}
+
+#[test]
+fn test_display() {
+    let beta = BetaDistribution::new(3.0, 2.5).unwrap();
+    assert_eq!(beta.to_string(), "Beta(3, 2.5)");
+}
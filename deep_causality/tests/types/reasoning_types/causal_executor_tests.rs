@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::channel;
+use std::sync::Arc;
+use std::time::Duration;
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils_graph;
+
+#[test]
+fn test_new() {
+    let executor = CausalExecutor::new(4).unwrap();
+    assert_eq!(executor.number_workers(), 4);
+    assert_eq!(executor.number_queued(), 0);
+}
+
+#[test]
+fn test_new_err_zero_workers() {
+    let res = CausalExecutor::new(0);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_set_model_limit() {
+    let executor = CausalExecutor::new(2).unwrap();
+    assert_eq!(executor.model_limit(1), None);
+
+    executor.set_model_limit(1, 3).unwrap();
+    assert_eq!(executor.model_limit(1), Some(3));
+
+    assert!(executor.set_model_limit(1, 0).is_err());
+    assert_eq!(executor.model_limit(1), Some(3));
+
+    executor.remove_model_limit(1);
+    assert_eq!(executor.model_limit(1), None);
+}
+
+#[test]
+fn test_submit() {
+    let executor = CausalExecutor::new(2).unwrap();
+
+    let handles: Vec<JobHandle<usize>> =
+        (0..8).map(|i| executor.submit(1, move || i * 2)).collect();
+
+    let results: Vec<usize> = handles.into_iter().map(|h| h.wait().unwrap()).collect();
+    assert_eq!(results, vec![0, 2, 4, 6, 8, 10, 12, 14]);
+}
+
+#[test]
+fn test_submit_reasoning() {
+    let (g, data) = test_utils_graph::get_small_multi_cause_graph_and_data();
+    let graph = Arc::new(g);
+
+    let executor = CausalExecutor::new(2).unwrap();
+    let handle = executor.submit_reasoning(
+        1,
+        Arc::clone(&graph),
+        data.to_vec(),
+        ReasoningPolicy::FailFast,
+    );
+
+    let report = handle.wait().unwrap().unwrap();
+    assert!(report.is_complete());
+
+    let expected = graph
+        .reason_all_causes_with_policy(&data, None, ReasoningPolicy::FailFast)
+        .unwrap();
+    assert_eq!(report.is_complete(), expected.is_complete());
+    assert_eq!(report.effect_of(0), expected.effect_of(0));
+}
+
+#[test]
+fn test_model_limit_is_respected() {
+    let executor = CausalExecutor::new(4).unwrap();
+    executor.set_model_limit(7, 1).unwrap();
+
+    // Each job reports its start and then blocks until released.
+    let (started_sender, started) = channel();
+    let mut releases = Vec::new();
+    let mut handles = Vec::new();
+    for i in 0..3 {
+        let (release, released) = channel::<()>();
+        let started_sender = started_sender.clone();
+        handles.push(executor.submit(7, move || {
+            started_sender.send(i).unwrap();
+            released.recv().unwrap();
+            i
+        }));
+        releases.push(release);
+    }
+
+    // Jobs start one after another although workers are idle.
+    for (i, release) in releases.into_iter().enumerate() {
+        assert_eq!(started.recv().unwrap(), i);
+        assert_eq!(executor.number_running(7), 1);
+        assert_eq!(executor.number_queued(), 2 - i);
+        release.send(()).unwrap();
+    }
+
+    let results: Vec<usize> = handles.into_iter().map(|h| h.wait().unwrap()).collect();
+    assert_eq!(results, vec![0, 1, 2]);
+    assert_eq!(executor.number_running(7), 0);
+}
+
+#[test]
+fn test_limited_model_does_not_block_other_models() {
+    let executor = CausalExecutor::new(2).unwrap();
+    executor.set_model_limit(1, 1).unwrap();
+
+    let (started_sender, started) = channel();
+    let (release, released) = channel::<()>();
+    let blocking = executor.submit(1, move || {
+        started_sender.send(()).unwrap();
+        released.recv().unwrap();
+    });
+    started.recv().unwrap();
+
+    let queued = executor.submit(1, || 1);
+    let other = executor.submit(2, || 2);
+
+    // The job of model 2 runs on the idle worker
+    // while the second job of model 1 waits for the first one.
+    assert_eq!(other.wait(), Some(2));
+    assert_eq!(queued.try_wait(), None);
+    assert_eq!(executor.number_queued(), 1);
+
+    release.send(()).unwrap();
+    blocking.wait().unwrap();
+    assert_eq!(queued.wait(), Some(1));
+}
+
+#[test]
+fn test_panicking_job() {
+    let executor = CausalExecutor::new(1).unwrap();
+
+    let failed: JobHandle<usize> = executor.submit(1, || panic!("job failed"));
+    assert_eq!(failed.wait(), None);
+
+    // The worker survives the panic.
+    let ok = executor.submit(1, || 42);
+    assert_eq!(ok.wait(), Some(42));
+    assert_eq!(executor.number_running(1), 0);
+}
+
+#[test]
+fn test_shutdown_runs_queued_jobs() {
+    let executor = CausalExecutor::new(1).unwrap();
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    for _ in 0..5 {
+        let counter = Arc::clone(&counter);
+        executor.submit(1, move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+
+    executor.shutdown();
+    assert_eq!(counter.load(Ordering::SeqCst), 5);
+}
+
+#[test]
+fn test_try_wait() {
+    let executor = CausalExecutor::new(1).unwrap();
+
+    let (release, released) = channel::<()>();
+    let handle = executor.submit(1, move || {
+        released.recv().unwrap();
+        3
+    });
+
+    // The job is blocked hence no result is available yet.
+    assert_eq!(handle.try_wait(), None);
+    assert_eq!(handle.wait_timeout(Duration::from_millis(1)), None);
+
+    release.send(()).unwrap();
+
+    // The single worker runs jobs in order, hence the first job
+    // has delivered its result once the second one completes.
+    assert_eq!(executor.submit(1, || ()).wait(), Some(()));
+    assert_eq!(handle.try_wait(), Some(3));
+}
@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils;
+
+fn get_causaloid<'l>(id: IdentificationValue) -> BaseCausaloid<'l> {
+    let description = "tests whether data exceeds threshold of 0.55";
+
+    fn causal_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+        Ok(obs.ge(&0.55))
+    }
+
+    Causaloid::new(id, causal_fn, description)
+}
+
+// Builds the graph:
+// root -> a -> target
+// root -> b
+// and, if redundant, b -> target
+fn get_graph<'l>(redundant: bool) -> BaseCausalGraph<'l> {
+    let mut g = CausaloidGraph::new();
+
+    let root_index = g.add_root_causaloid(get_causaloid(0));
+    let idx_a = g.add_causaloid(get_causaloid(1));
+    let idx_b = g.add_causaloid(get_causaloid(2));
+    let idx_target = g.add_causaloid(get_causaloid(3));
+
+    g.add_edge(root_index, idx_a).expect("Failed to add edge");
+    g.add_edge(root_index, idx_b).expect("Failed to add edge");
+    g.add_edge(idx_a, idx_target).expect("Failed to add edge");
+    if redundant {
+        g.add_edge(idx_b, idx_target).expect("Failed to add edge");
+    }
+
+    g
+}
+
+// Builds the chain root -> input_1 -> ... -> input_n -> target,
+// in which every input is necessary for the effect of the target.
+// Returns the graph, the indices of the inputs, and the index of the target.
+fn get_chain_graph<'l>(n: usize) -> (BaseCausalGraph<'l>, Vec<usize>, usize) {
+    let mut g = CausaloidGraph::new();
+
+    let mut previous = g.add_root_causaloid(get_causaloid(0));
+    let mut inputs = Vec::with_capacity(n);
+    for id in 1..=n {
+        let index = g.add_causaloid(get_causaloid(id as IdentificationValue));
+        g.add_edge(previous, index).expect("Failed to add edge");
+        inputs.push(index);
+        previous = index;
+    }
+
+    let target = g.add_causaloid(get_causaloid(n as IdentificationValue + 1));
+    g.add_edge(previous, target).expect("Failed to add edge");
+
+    (g, inputs, target)
+}
+
+const DATA: [f64; 4] = [0.9, 0.9, 0.9, 0.9];
+const BASELINE: [f64; 4] = [0.1, 0.1, 0.1, 0.1];
+
+#[test]
+fn test_leave_one_out_attribution() {
+    let g = get_graph(false);
+
+    let attributions = g
+        .leave_one_out_attribution(3, &[1, 2], &DATA, &BASELINE, None)
+        .expect("Failed to compute attribution");
+
+    assert_eq!(attributions.len(), 2);
+    assert_eq!(*attributions[0].index(), 1);
+    assert_eq!(*attributions[0].value(), 1.0);
+    assert_eq!(*attributions[1].value(), 0.0);
+
+    // Redundant inputs are not necessary on their own.
+    let g = get_graph(true);
+    let attributions = g
+        .leave_one_out_attribution(3, &[1, 2], &DATA, &BASELINE, None)
+        .expect("Failed to compute attribution");
+
+    assert_eq!(*attributions[0].value(), 0.0);
+    assert_eq!(*attributions[1].value(), 0.0);
+
+    // Target does not exist
+    assert!(g
+        .leave_one_out_attribution(42, &[1], &DATA, &BASELINE, None)
+        .is_err());
+}
+
+#[test]
+fn test_leave_one_out_attribution_many_inputs() {
+    let n = 70;
+    let (g, inputs, target) = get_chain_graph(n);
+    let data = vec![0.9; n + 2];
+    let baseline = vec![0.1; n + 2];
+
+    let attributions = g
+        .leave_one_out_attribution(target, &inputs, &data, &baseline, None)
+        .expect("Failed to compute attribution");
+
+    // Every input is necessary for the effect.
+    assert_eq!(attributions.len(), n);
+    assert!(attributions.iter().all(|a| *a.value() == 1.0));
+}
+
+#[test]
+fn test_shapley_attribution_single_path() {
+    let g = get_graph(false);
+
+    let attributions = g
+        .shapley_attribution(3, &[1, 2], &DATA, &BASELINE, None)
+        .expect("Failed to compute attribution");
+
+    assert_eq!(attributions.len(), 2);
+    assert_eq!(*attributions[0].index(), 1);
+    assert_eq!(*attributions[0].causaloid_id(), 1);
+    assert_eq!(*attributions[0].value(), 1.0);
+    assert_eq!(*attributions[1].index(), 2);
+    assert_eq!(*attributions[1].value(), 0.0);
+}
+
+#[test]
+fn test_shapley_attribution_redundant_paths() {
+    let g = get_graph(true);
+
+    let attributions = g
+        .shapley_attribution(3, &[1, 2], &DATA, &BASELINE, None)
+        .expect("Failed to compute attribution");
+
+    // Either input alone suffices, hence both share the effect equally.
+    assert_eq!(*attributions[0].value(), 0.5);
+    assert_eq!(*attributions[1].value(), 0.5);
+
+    // Including the root, which is necessary for every path.
+    let attributions = g
+        .shapley_attribution(3, &[0, 1, 2], &DATA, &BASELINE, None)
+        .expect("Failed to compute attribution");
+
+    let sum: f64 = attributions.iter().map(|a| *a.value()).sum();
+    assert!((sum - 1.0).abs() < 1e-12);
+    assert!(*attributions[0].value() > *attributions[1].value());
+    assert!((*attributions[1].value() - *attributions[2].value()).abs() < 1e-12);
+}
+
+#[test]
+fn test_sampled_shapley_attribution() {
+    let g = get_graph(true);
+
+    let attributions = g
+        .sampled_shapley_attribution(3, &[1, 2], &DATA, &BASELINE, None, 200, 42)
+        .expect("Failed to compute attribution");
+
+    let sum: f64 = attributions.iter().map(|a| *a.value()).sum();
+    assert!((sum - 1.0).abs() < 1e-12);
+    assert!((*attributions[0].value() - 0.5).abs() < 0.15);
+
+    // The same seed yields the same attribution.
+    let again = g
+        .sampled_shapley_attribution(3, &[1, 2], &DATA, &BASELINE, None, 200, 42)
+        .expect("Failed to compute attribution");
+    assert_eq!(attributions, again);
+
+    // Seeds zero and one drive distinct samplers.
+    let seed_zero = g
+        .sampled_shapley_attribution(3, &[1, 2], &DATA, &BASELINE, None, 200, 0)
+        .expect("Failed to compute attribution");
+    let seed_one = g
+        .sampled_shapley_attribution(3, &[1, 2], &DATA, &BASELINE, None, 200, 1)
+        .expect("Failed to compute attribution");
+    assert_ne!(seed_zero, seed_one);
+
+    let res = g.sampled_shapley_attribution(3, &[1, 2], &DATA, &BASELINE, None, 0, 42);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_sampled_shapley_attribution_many_inputs() {
+    let n = 70;
+    let (g, inputs, target) = get_chain_graph(n);
+    let data = vec![0.9; n + 2];
+    let baseline = vec![0.1; n + 2];
+
+    let attributions = g
+        .sampled_shapley_attribution(target, &inputs, &data, &baseline, None, 10, 42)
+        .expect("Failed to compute attribution");
+
+    assert_eq!(attributions.len(), n);
+    // Every input is necessary, hence the last input of each permutation
+    // receives the entire effect.
+    let sum: f64 = attributions.iter().map(|a| *a.value()).sum();
+    assert!((sum - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_shapley_attribution_err() {
+    let g = get_graph(false);
+
+    // Target does not exist
+    assert!(g
+        .shapley_attribution(42, &[1], &DATA, &BASELINE, None)
+        .is_err());
+    // No inputs
+    assert!(g
+        .shapley_attribution(3, &[], &DATA, &BASELINE, None)
+        .is_err());
+    // Input does not exist
+    assert!(g
+        .shapley_attribution(3, &[42], &DATA, &BASELINE, None)
+        .is_err());
+    // Duplicate input
+    assert!(g
+        .shapley_attribution(3, &[1, 1], &DATA, &BASELINE, None)
+        .is_err());
+    // Data and baseline differ in length
+    assert!(g.shapley_attribution(3, &[1], &DATA, &[0.1], None).is_err());
+    // No data for input
+    assert!(g
+        .shapley_attribution(3, &[1], &[0.9], &[0.1], None)
+        .is_err());
+
+    // Too many inputs for exact computation
+    let mut g: BaseCausalGraph = CausaloidGraph::new();
+    let root = g.add_root_causaloid(test_utils::get_test_causaloid());
+    let inputs: Vec<usize> = (0..=MAX_EXACT_SHAPLEY_INPUTS)
+        .map(|_| g.add_causaloid(test_utils::get_test_causaloid()))
+        .collect();
+    let data = [0.9, 0.9];
+    let baseline = [0.1, 0.1];
+    assert!(g
+        .shapley_attribution(root, &inputs, &data, &baseline, None)
+        .is_err());
+
+    // No root
+    let g: BaseCausalGraph = CausaloidGraph::new();
+    assert!(g
+        .shapley_attribution(0, &[1], &DATA, &BASELINE, None)
+        .is_err());
+}
+
+#[test]
+fn test_attribution_display() {
+    let attribution = Attribution::new(1, 2, 0.5);
+    assert_eq!(
+        format!("{}", attribution),
+        "Attribution: index: 1, causaloid_id: 2, value: 0.5"
+    );
+}
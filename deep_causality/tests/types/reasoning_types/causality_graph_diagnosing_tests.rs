@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+fn get_causaloid<'l>(id: IdentificationValue) -> BaseCausaloid<'l> {
+    let description = "tests whether data exceeds threshold of 0.55";
+
+    fn causal_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+        if obs.is_nan() {
+            return Err(CausalityError("Observation is NULL/NAN".into()));
+        }
+
+        Ok(obs.ge(&0.55))
+    }
+
+    Causaloid::new(id, causal_fn, description)
+}
+
+// Builds the graph:
+// root -> a -> target
+// root -> b
+fn get_graph<'l>() -> BaseCausalGraph<'l> {
+    let mut g = CausaloidGraph::new();
+
+    let root_index = g.add_root_causaloid(get_causaloid(0));
+    let idx_a = g.add_causaloid(get_causaloid(1));
+    let idx_b = g.add_causaloid(get_causaloid(2));
+    let idx_target = g.add_causaloid(get_causaloid(3));
+
+    g.add_edge(root_index, idx_a).expect("Failed to add edge");
+    g.add_edge(root_index, idx_b).expect("Failed to add edge");
+    g.add_edge(idx_a, idx_target).expect("Failed to add edge");
+
+    g
+}
+
+#[test]
+fn test_rank_root_causes() {
+    let g = get_graph();
+    let data = [0.9, 0.9, 0.9, 0.9];
+    let baseline = [0.1, 0.1, 0.1, 0.1];
+
+    let ranking = g
+        .rank_root_causes(3, &data, &baseline, None, true)
+        .expect("Failed to rank root causes");
+
+    // Only upstream causaloids of the target are ranked i.e. neither b nor the target.
+    assert_eq!(ranking.len(), 2);
+
+    let indices: Vec<usize> = ranking.iter().map(|r| *r.index()).collect();
+    assert_eq!(indices, vec![0, 1]);
+
+    // Restoring the root disables the entire graph.
+    let root = &ranking[0];
+    assert!(*root.outcome_flipped());
+    assert_eq!(*root.causaloid_id(), 0);
+    assert_eq!(root.deltas().len(), 4);
+
+    // Restoring a disables a and the target.
+    let a = &ranking[1];
+    assert!(*a.outcome_flipped());
+    assert_eq!(
+        a.deltas(),
+        &vec![
+            EffectDelta::new(1, Some(true), Some(false)),
+            EffectDelta::new(3, Some(true), None),
+        ]
+    );
+
+    assert_eq!(
+        format!("{}", a),
+        "RootCause: causaloid 1 at index 1: restoring its baseline data resolves the anomalous outcome and changes 2 causaloid(s)"
+    );
+}
+
+#[test]
+fn test_rank_root_causes_redundant_paths() {
+    let mut g = get_graph();
+    // The target is now reachable through both a and b.
+    g.add_edge(2, 3).expect("Failed to add edge");
+
+    let data = [0.9, 0.9, 0.9, 0.9];
+    let baseline = [0.1, 0.1, 0.1, 0.1];
+
+    let ranking = g
+        .rank_root_causes(3, &data, &baseline, None, true)
+        .expect("Failed to rank root causes");
+
+    let indices: Vec<usize> = ranking.iter().map(|r| *r.index()).collect();
+    assert_eq!(indices, vec![0, 1, 2]);
+
+    // Neither a nor b alone resolves the anomalous outcome.
+    assert!(*ranking[0].outcome_flipped());
+    assert!(!*ranking[1].outcome_flipped());
+    assert!(!*ranking[2].outcome_flipped());
+    assert_eq!(
+        ranking[2].deltas(),
+        &vec![EffectDelta::new(2, Some(true), Some(false))]
+    );
+}
+
+#[test]
+fn test_rank_root_causes_skips_unchanged_data() {
+    let g = get_graph();
+    let data = [0.9, 0.9, 0.1, 0.9];
+    let baseline = [0.1, 0.9, 0.1, 0.1];
+
+    let ranking = g
+        .rank_root_causes(3, &data, &baseline, None, true)
+        .expect("Failed to rank root causes");
+
+    // Only the root deviates from the baseline.
+    assert_eq!(ranking.len(), 1);
+    assert_eq!(*ranking[0].index(), 0);
+    assert!(*ranking[0].outcome_flipped());
+}
+
+#[test]
+fn test_rank_root_causes_err() {
+    let g = get_graph();
+    let data = [0.9, 0.9, 0.9, 0.9];
+    let baseline = [0.1, 0.1, 0.1, 0.1];
+
+    // Target does not exist
+    assert!(g
+        .rank_root_causes(42, &data, &baseline, None, true)
+        .is_err());
+
+    // Data and baseline differ in length
+    assert!(g.rank_root_causes(3, &data, &[0.1], None, true).is_err());
+
+    // Target does not show the anomalous effect
+    assert!(g
+        .rank_root_causes(3, &data, &baseline, None, false)
+        .is_err());
+
+    // No root
+    let g: BaseCausalGraph = CausaloidGraph::new();
+    assert!(g.rank_root_causes(0, &data, &baseline, None, true).is_err());
+}
@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use deep_causality::prelude::*;
+
+fn get_causaloid<'l>(id: IdentificationValue) -> BaseCausaloid<'l> {
+    let description = "tests whether data exceeds threshold of 0.55";
+
+    fn causal_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+        if obs.is_nan() {
+            return Err(CausalityError("Observation is NULL/NAN".into()));
+        }
+
+        Ok(obs.ge(&0.55))
+    }
+
+    Causaloid::new(id, causal_fn, description)
+}
+
+// Builds the graph:
+//  root
+//  / \
+//  A B
+//  \ /
+//   C
+fn get_graph<'l>() -> BaseCausalGraph<'l> {
+    let mut g = CausaloidGraph::new();
+
+    let root_index = g.add_root_causaloid(get_causaloid(0));
+    let idx_a = g.add_causaloid(get_causaloid(1));
+    let idx_b = g.add_causaloid(get_causaloid(2));
+    let idx_c = g.add_causaloid(get_causaloid(3));
+
+    g.add_edge(root_index, idx_a).expect("Failed to add edge");
+    g.add_edge(root_index, idx_b).expect("Failed to add edge");
+    g.add_edge(idx_a, idx_c).expect("Failed to add edge");
+    g.add_edge(idx_b, idx_c).expect("Failed to add edge");
+
+    g
+}
+
+#[test]
+fn test_evaluate_incremental() {
+    let g = get_graph();
+    let data = [0.99; 4];
+    let mut cache = EvaluationCache::new();
+
+    let res = g.evaluate_incremental(&data, None, &mut cache).unwrap();
+    assert!(res);
+    assert_eq!(cache.number_evaluated(), 4);
+    assert_eq!(cache.number_cached(), 4);
+
+    // Same data: everything comes from the cache.
+    let res = g.evaluate_incremental(&data, None, &mut cache).unwrap();
+    assert!(res);
+    assert_eq!(cache.number_evaluated(), 0);
+
+    // One changed observation re-evaluates only its causaloid.
+    let mut data = data;
+    data[2] = 0.1;
+    let res = g.evaluate_incremental(&data, None, &mut cache).unwrap();
+    assert!(!res);
+    assert_eq!(cache.number_evaluated(), 1);
+    assert_eq!(cache.effect(2), Some(false));
+
+    data[2] = 0.99;
+    let res = g.evaluate_incremental(&data, None, &mut cache).unwrap();
+    assert!(res);
+    assert_eq!(cache.number_evaluated(), 1);
+    assert_eq!(cache.effect(2), Some(true));
+}
+
+#[test]
+fn test_evaluate_incremental_matches_full_reasoning() {
+    let g = get_graph();
+    let data = [0.99; 4];
+    let mut cache = EvaluationCache::new();
+
+    let mut data = data;
+    for value in [0.99, 0.1, 0.6, 0.2, 0.9] {
+        data[3] = value;
+        let full = g.reason_all_causes(&data, None).unwrap();
+        let incremental = g.evaluate_incremental(&data, None, &mut cache).unwrap();
+        assert_eq!(full, incremental);
+    }
+}
+
+#[test]
+fn test_evaluate_incremental_mark_dirty() {
+    let g = get_graph();
+    let data = [0.99; 4];
+    let mut cache = EvaluationCache::new();
+
+    g.evaluate_incremental(&data, None, &mut cache).unwrap();
+
+    cache.mark_dirty(1);
+    assert!(cache.is_dirty(1));
+
+    g.evaluate_incremental(&data, None, &mut cache).unwrap();
+    assert_eq!(cache.number_evaluated(), 1);
+    assert!(!cache.is_dirty(1));
+}
+
+#[test]
+fn test_evaluate_incremental_clear() {
+    let g = get_graph();
+    let data = [0.99; 4];
+    let mut cache = EvaluationCache::new();
+
+    g.evaluate_incremental(&data, None, &mut cache).unwrap();
+    assert!(cache.is_cached(0));
+
+    cache.clear();
+    assert!(!cache.is_cached(0));
+    assert_eq!(cache.number_cached(), 0);
+
+    g.evaluate_incremental(&data, None, &mut cache).unwrap();
+    assert_eq!(cache.number_evaluated(), 4);
+}
+
+#[test]
+fn test_evaluate_incremental_with_data_index() {
+    let mut g: BaseCausalGraph = CausaloidGraph::new();
+    let root_index = g.add_root_causaloid(get_causaloid(0));
+    let index = g.add_causaloid(get_causaloid(1));
+    g.add_edge(root_index, index).unwrap();
+
+    let data_index = HashMap::from([(0, 1), (1, 0)]);
+    let mut cache = EvaluationCache::new();
+
+    let data = [0.1, 0.99];
+    let res = g
+        .evaluate_incremental(&data, Some(&data_index), &mut cache)
+        .unwrap();
+    assert!(!res);
+    assert_eq!(cache.effect(root_index), Some(true));
+    assert_eq!(cache.effect(index), Some(false));
+}
+
+#[test]
+fn test_evaluate_incremental_data_index_changed() {
+    // Sub graph: root -> A
+    let mut sub_graph: BaseCausalGraph = CausaloidGraph::new();
+    let sub_root = sub_graph.add_root_causaloid(get_causaloid(0));
+    let sub_index = sub_graph.add_causaloid(get_causaloid(1));
+    sub_graph.add_edge(sub_root, sub_index).unwrap();
+
+    let mut g: BaseCausalGraph = CausaloidGraph::new();
+    let root_index = g.add_root_causaloid(Causaloid::from_causal_graph(7, &sub_graph, "sub graph"));
+
+    let data = [0.99, 0.1];
+    let mut cache = EvaluationCache::new();
+    assert!(!g.evaluate_incremental(&data, None, &mut cache).unwrap());
+    assert_eq!(cache.effect(root_index), Some(false));
+
+    // Same data, but a different data index re-evaluates the sub graph.
+    let data_index = HashMap::from([(0, 0), (1, 0)]);
+    assert!(g
+        .evaluate_incremental(&data, Some(&data_index), &mut cache)
+        .unwrap());
+    assert_eq!(cache.number_evaluated(), 1);
+    assert_eq!(cache.effect(root_index), Some(true));
+
+    // Unchanged data and data index come from the cache.
+    assert!(g
+        .evaluate_incremental(&data, Some(&data_index), &mut cache)
+        .unwrap());
+    assert_eq!(cache.number_evaluated(), 0);
+
+    // Dropping the data index re-evaluates the sub graph as well.
+    assert!(!g.evaluate_incremental(&data, None, &mut cache).unwrap());
+    assert_eq!(cache.number_evaluated(), 1);
+}
+
+#[test]
+fn test_evaluate_incremental_error() {
+    let g: BaseCausalGraph = CausaloidGraph::new();
+    let mut cache = EvaluationCache::new();
+    assert!(g.evaluate_incremental(&[0.99], None, &mut cache).is_err());
+
+    let g = get_graph();
+    assert!(g.evaluate_incremental(&[], None, &mut cache).is_err());
+
+    // Data too short for all causaloids.
+    assert!(g.evaluate_incremental(&[0.99], None, &mut cache).is_err());
+}
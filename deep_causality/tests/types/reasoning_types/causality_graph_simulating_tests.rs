@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use deep_causality::prelude::*;
+
+fn get_causaloid<'l>(id: IdentificationValue) -> BaseCausaloid<'l> {
+    let description = "tests whether data exceeds threshold of 0.55";
+
+    fn causal_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+        if obs.is_nan() {
+            return Err(CausalityError("Observation is NULL/NAN".into()));
+        }
+
+        Ok(obs.ge(&0.55))
+    }
+
+    Causaloid::new(id, causal_fn, description)
+}
+
+// Builds the graph:
+// root -> treatment -> outcome
+fn get_graph<'l>() -> BaseCausalGraph<'l> {
+    let mut g = CausaloidGraph::new();
+
+    let root_index = g.add_root_causaloid(get_causaloid(0));
+    let idx_treatment = g.add_causaloid(get_causaloid(1));
+    let idx_outcome = g.add_causaloid(get_causaloid(2));
+
+    g.add_edge(root_index, idx_treatment)
+        .expect("Failed to add edge");
+    g.add_edge(idx_treatment, idx_outcome)
+        .expect("Failed to add edge");
+
+    g
+}
+
+// Outcome data decay by half per step; everything else stays as is.
+fn decay_outcome(_step: usize, data: &mut [NumericalValue], _report: &ReasoningReport) {
+    data[2] *= 0.5;
+}
+
+#[test]
+fn test_intervention_schedule() {
+    let mut schedule = InterventionSchedule::new(2);
+    assert_eq!(schedule.number_steps(), 2);
+    assert!(schedule.interventions_at(1).is_empty());
+
+    schedule.add_intervention(3, 1, 0.9);
+    assert_eq!(schedule.number_steps(), 4);
+    assert_eq!(schedule.interventions_at(3), &[Intervention::new(1, 0.9)]);
+    assert!(schedule.interventions_at(7).is_empty());
+
+    assert_eq!(
+        schedule.to_string(),
+        "InterventionSchedule: steps: 4, step 3: [do(index: 1, value: 0.9)]"
+    );
+
+    assert!(InterventionSchedule::default().is_empty());
+}
+
+#[test]
+fn test_simulate_without_interventions() {
+    let g = get_graph();
+    let schedule = InterventionSchedule::new(3);
+    let data = [0.9, 0.9, 0.9];
+
+    let reports = g
+        .simulate_intervention_schedule(&schedule, &data, None, decay_outcome)
+        .expect("Failed to simulate");
+
+    // Outcome data: 0.9, 0.45, 0.225
+    let outcomes: Vec<Option<bool>> = reports.iter().map(|r| r.effect_of(2)).collect();
+    assert_eq!(outcomes, vec![Some(true), Some(false), Some(false)]);
+}
+
+#[test]
+fn test_simulate_sequential_treatment() {
+    let g = get_graph();
+    let data = [0.9, 0.1, 0.9];
+
+    // Treatment is off at step 0, switched on at step 1, and off again at step 2.
+    let mut schedule = InterventionSchedule::new(3);
+    schedule.add_intervention(1, 1, 0.9);
+    schedule.add_intervention(2, 1, 0.1);
+
+    let reports = g
+        .simulate_intervention_schedule(&schedule, &data, None, |_, _, _| {})
+        .expect("Failed to simulate");
+
+    let results: Vec<bool> = reports.iter().map(|r| *r.result()).collect();
+    assert_eq!(results, vec![false, true, false]);
+
+    // Without treatment, reasoning stops at the treatment and the outcome is not evaluated.
+    assert_eq!(reports[0].effect_of(1), Some(false));
+    assert_eq!(reports[0].effect_of(2), None);
+    assert_eq!(reports[1].effect_of(2), Some(true));
+}
+
+#[test]
+fn test_simulate_transition_sees_report() {
+    let g = get_graph();
+    let data = [0.9, 0.9, 0.9];
+    let schedule = InterventionSchedule::new(3);
+
+    let mut steps = Vec::new();
+    // Turns the treatment off once the outcome has been observed.
+    let transition = |step: usize, data: &mut [NumericalValue], report: &ReasoningReport| {
+        steps.push(step);
+        if report.effect_of(2) == Some(true) {
+            data[1] = 0.0;
+        }
+    };
+
+    let reports = g
+        .simulate_intervention_schedule(&schedule, &data, None, transition)
+        .expect("Failed to simulate");
+
+    // The transition is not called after the last step.
+    assert_eq!(steps, vec![0, 1]);
+    assert!(*reports[0].result());
+    assert!(!*reports[1].result());
+}
+
+#[test]
+fn test_simulate_with_data_index() {
+    let g = get_graph();
+    // Data are stored in reverse order.
+    let data = [0.1, 0.9, 0.9];
+    let mut data_index = HashMap::new();
+    data_index.insert(0, 2);
+    data_index.insert(1, 1);
+    data_index.insert(2, 0);
+
+    let mut schedule = InterventionSchedule::new(1);
+    schedule.add_intervention(2, 2, 0.9);
+
+    let reports = g
+        .simulate_intervention_schedule(&schedule, &data, Some(&data_index), |_, _, _| {})
+        .expect("Failed to simulate");
+
+    assert!(!*reports[0].result());
+    assert!(*reports[2].result());
+}
+
+#[test]
+fn test_simulate_err() {
+    let g = get_graph();
+    let data = [0.9, 0.9, 0.9];
+
+    let mut schedule = InterventionSchedule::new(1);
+    schedule.add_intervention(0, 42, 0.9);
+    let res = g.simulate_intervention_schedule(&schedule, &data, None, |_, _, _| {});
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "CausalityGraphError: Intervention at step 0 targets unknown causaloid at index 42"
+    );
+
+    let short_data = [0.9, 0.9];
+    let mut schedule = InterventionSchedule::new(1);
+    schedule.add_intervention(0, 2, 0.9);
+    let res = g.simulate_intervention_schedule(&schedule, &short_data, None, |_, _, _| {});
+    assert!(res.is_err());
+
+    let empty: BaseCausalGraph = CausaloidGraph::new();
+    let res = empty.simulate_intervention_schedule(&schedule, &data, None, |_, _, _| {});
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_sweep_axis_values() {
+    let axis = SweepAxis::new(1, 0.0, 1.0, 5);
+    assert_eq!(axis.values(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+    let axis = SweepAxis::new(1, 0.3, 1.0, 1);
+    assert_eq!(axis.values(), vec![0.3]);
+
+    let axis = SweepAxis::new(1, 0.3, 1.0, 0);
+    assert!(axis.values().is_empty());
+    assert_eq!(
+        format!("{}", SweepAxis::new(1, 0.0, 1.0, 5)),
+        "SweepAxis: index: 1, from 0 to 1 in 5 steps"
+    );
+}
+
+#[test]
+fn test_sweep_intervention() {
+    let g = get_graph();
+    let data = [0.9, 0.9, 0.9];
+
+    let axis = SweepAxis::new(1, 0.0, 1.0, 5);
+    let sweep = g.sweep_intervention(&axis, &data, None).unwrap();
+
+    assert_eq!(sweep.shape(), vec![5]);
+    assert_eq!(sweep.outcomes(), &vec![false, false, false, true, true]);
+    assert_eq!(sweep.outcome(&[3]), Some(true));
+    assert_eq!(sweep.outcome(&[5]), None);
+    assert_eq!(sweep.outcome(&[0, 0]), None);
+    assert_eq!(sweep.to_numerical(), vec![0.0, 0.0, 0.0, 1.0, 1.0]);
+    assert_eq!(sweep.percent_true(), 40.0);
+    assert_eq!(
+        format!("{}", sweep),
+        "InterventionSweep: shape: [5], true: 40.00%"
+    );
+}
+
+#[test]
+fn test_sweep_intervention_pair() {
+    let g = get_graph();
+    let data = [0.9, 0.9, 0.9];
+
+    let x = SweepAxis::new(1, 0.0, 1.0, 3);
+    let y = SweepAxis::new(2, 0.5, 0.6, 2);
+    let sweep = g.sweep_intervention_pair(&x, &y, &data, None).unwrap();
+
+    assert_eq!(sweep.shape(), vec![3, 2]);
+    // Only treatment and outcome both above the threshold yield true.
+    assert_eq!(
+        sweep.outcomes(),
+        &vec![false, false, false, false, false, true]
+    );
+    assert_eq!(sweep.outcome(&[2, 1]), Some(true));
+    assert_eq!(sweep.outcome(&[2, 0]), Some(false));
+    assert_eq!(sweep.outcome(&[3, 0]), None);
+}
+
+#[test]
+fn test_sweep_intervention_with_data_index() {
+    let g = get_graph();
+    // Data sorted in reverse order of the causaloids.
+    let data_index = HashMap::from([(0, 2), (1, 1), (2, 0)]);
+    let data = [0.9, 0.9, 0.9];
+
+    let axis = SweepAxis::new(2, 0.0, 1.0, 2);
+    let sweep = g
+        .sweep_intervention(&axis, &data, Some(&data_index))
+        .unwrap();
+    assert_eq!(sweep.outcomes(), &vec![false, true]);
+}
+
+#[test]
+fn test_sweep_intervention_error() {
+    let g = get_graph();
+    let data = [0.9, 0.9, 0.9];
+
+    let axis = SweepAxis::new(1, 0.0, 1.0, 0);
+    assert!(g.sweep_intervention(&axis, &data, None).is_err());
+
+    let axis = SweepAxis::new(7, 0.0, 1.0, 2);
+    assert!(g.sweep_intervention(&axis, &data, None).is_err());
+
+    let axis = SweepAxis::new(2, 0.0, 1.0, 2);
+    assert!(g.sweep_intervention(&axis, &data[..2], None).is_err());
+
+    let g: BaseCausalGraph = CausaloidGraph::new();
+    let axis = SweepAxis::new(0, 0.0, 1.0, 2);
+    assert!(g.sweep_intervention(&axis, &data, None).is_err());
+}
@@ -4,7 +4,7 @@
 use deep_causality::prelude::*;
 use ultragraph::prelude::*;
 
-use crate::utils::test_utils;
+use crate::utils::{test_utils, test_utils_graph};
 
 // Custom type alias
 type CustomCausaloidGraph = CausaloidGraph<
@@ -37,6 +37,38 @@ fn test_new_with_capacity() {
     assert_eq!(g.number_edges(), 0);
 }
 
+#[test]
+fn test_new_simple() {
+    let mut g: SimpleCausalGraph = CausaloidGraph::new();
+    let causaloid: SimpleCausaloid = test_utils::get_test_causaloid();
+    let root_index = g.add_root_causaloid(causaloid);
+
+    assert_eq!(g.number_nodes(), 1);
+    assert!(g.reason_all_causes(&[0.0, 0.99], None).unwrap());
+    assert!(g.get_causaloid(root_index).unwrap().is_active());
+}
+
+#[test]
+fn test_export_dot() {
+    let (g, _) = test_utils_graph::get_small_multi_cause_graph_and_data();
+
+    let expected = "digraph CausaloidGraph {
+    n0 [label=\"1: tests whether data exceeds threshold of 0.55\", peripheries=2];
+    n1 [label=\"1: tests whether data exceeds threshold of 0.55\"];
+    n2 [label=\"1: tests whether data exceeds threshold of 0.55\"];
+    n3 [label=\"1: tests whether data exceeds threshold of 0.55\"];
+    n0 -> n1;
+    n0 -> n2;
+    n1 -> n3;
+    n2 -> n3;
+}
+";
+    assert_eq!(g.export_dot(), expected);
+
+    let g: BaseCausalGraph = CausaloidGraph::new();
+    assert_eq!(g.export_dot(), "digraph CausaloidGraph {\n}\n");
+}
+
 #[test]
 fn test_default() {
     let g: CustomCausaloidGraph = CausaloidGraph::default();
@@ -436,3 +468,50 @@ fn test_get_graph() {
     assert_eq!(graph.number_edges(), 0);
     assert_eq!(graph.number_nodes(), 0);
 }
+
+#[test]
+fn test_fingerprint() {
+    let mut g = get_causal_graph();
+    let mut other = get_causal_graph();
+    assert_eq!(g.fingerprint(), other.fingerprint());
+
+    let root_index = g.add_root_causaloid(test_utils::get_test_causaloid());
+    let idx_a = g.add_causaloid(test_utils::get_test_causaloid());
+    let fingerprint_nodes = g.fingerprint();
+    assert_ne!(fingerprint_nodes, other.fingerprint());
+
+    other.add_root_causaloid(test_utils::get_test_causaloid());
+    other.add_causaloid(test_utils::get_test_causaloid());
+    assert_eq!(fingerprint_nodes, other.fingerprint());
+
+    g.add_edge(root_index, idx_a).expect("Failed to add edge");
+    let fingerprint_edges = g.fingerprint();
+    assert_ne!(fingerprint_edges, fingerprint_nodes);
+
+    // Reasoning changes the activation state but not the fingerprint
+    let data = [0.0, 0.99];
+    g.reason_all_causes(&data, None).expect("Failed to reason");
+    assert_eq!(g.fingerprint(), fingerprint_edges);
+
+    g.remove_edge(root_index, idx_a)
+        .expect("Failed to remove edge");
+    assert_eq!(g.fingerprint(), fingerprint_nodes);
+}
+
+#[test]
+fn test_fingerprint_golden() {
+    // Fingerprints are stored in audit logs and must never change for the same content.
+    let causaloid = test_utils::get_test_causaloid();
+    assert_eq!(causaloid.fingerprint(), 0x465b_48b6_10b8_a0cf);
+
+    // The function tag distinguishes causal functions with the same id and description.
+    let tagged = test_utils::get_test_causaloid().with_function_tag("threshold@1.0");
+    assert_eq!(tagged.function_tag(), Some("threshold@1.0"));
+    assert_eq!(tagged.fingerprint(), 0x58d4_c34b_069a_43e1);
+
+    let mut g = get_causal_graph();
+    let root_index = g.add_root_causaloid(test_utils::get_test_causaloid());
+    let idx_a = g.add_causaloid(test_utils::get_test_causaloid());
+    g.add_edge(root_index, idx_a).expect("Failed to add edge");
+    assert_eq!(g.fingerprint(), 0x8a87_dd50_6f0d_8217);
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils_graph;
+
+fn causal_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+    Ok(obs >= 0.55)
+}
+
+fn get_causaloid<'l>(id: IdentificationValue) -> BaseCausaloid<'l> {
+    Causaloid::new(
+        id,
+        causal_fn,
+        "tests whether data exceeds threshold of 0.55",
+    )
+}
+
+// Builds the graph:
+// root -> a -> c
+// root -> b -> c
+fn get_valid_graph<'l>() -> (BaseCausalGraph<'l>, [usize; 4]) {
+    let mut g = CausaloidGraph::new();
+
+    let root = g.add_root_causaloid(get_causaloid(0));
+    let a = g.add_causaloid(get_causaloid(1));
+    let b = g.add_causaloid(get_causaloid(2));
+    let c = g.add_causaloid(get_causaloid(3));
+
+    g.add_edge(root, a).expect("Failed to add edge");
+    g.add_edge(root, b).expect("Failed to add edge");
+    g.add_edge(a, c).expect("Failed to add edge");
+    g.add_edge(b, c).expect("Failed to add edge");
+
+    (g, [root, a, b, c])
+}
+
+#[test]
+fn test_validate_valid() {
+    let (g, _) = get_valid_graph();
+
+    let report = g.validate();
+    assert!(report.is_valid());
+    assert_eq!(report.number_issues(), 0);
+    assert_eq!(report.to_string(), "GraphValidationReport: valid");
+
+    let g: BaseCausalGraph = CausaloidGraph::new();
+    assert!(g.validate().is_valid());
+}
+
+#[test]
+fn test_validate_cycle() {
+    let (mut g, [root, a, _, c]) = get_valid_graph();
+    g.add_edge(c, a).expect("Failed to add edge");
+
+    let report = g.validate();
+    assert!(!report.is_valid());
+    assert_eq!(report.cycles().len(), 1);
+    let mut cycle = report.cycles()[0].clone();
+    cycle.sort();
+    assert_eq!(cycle, vec![a, c]);
+    assert!(report.unreachable().is_empty());
+
+    g.add_edge(root, root).expect("Failed to add edge");
+    let report = g.validate();
+    assert_eq!(report.cycles().len(), 2);
+    assert!(report.cycles().contains(&vec![root]));
+}
+
+#[test]
+fn test_validate_unreachable_and_extra_roots() {
+    let (mut g, _) = get_valid_graph();
+
+    // d -> e is disconnected from the root.
+    let d = g.add_causaloid(get_causaloid(4));
+    let e = g.add_causaloid(get_causaloid(5));
+    g.add_edge(d, e).expect("Failed to add edge");
+
+    let report = g.validate();
+    assert_eq!(*report.unreachable(), vec![d, e]);
+    assert_eq!(*report.extra_roots(), vec![d]);
+    assert_eq!(report.number_issues(), 3);
+    assert!(!*report.missing_root());
+}
+
+#[test]
+fn test_validate_missing_root() {
+    let mut g: BaseCausalGraph = CausaloidGraph::new();
+    let a = g.add_causaloid(get_causaloid(1));
+
+    let report = g.validate();
+    assert!(*report.missing_root());
+    assert_eq!(*report.unreachable(), vec![a]);
+}
+
+#[test]
+fn test_validate_id_collisions() {
+    // All causaloids of the shared test graph have the same id.
+    let (g, _) = test_utils_graph::get_small_multi_cause_graph_and_data();
+
+    let report = g.validate();
+    assert!(report.cycles().is_empty());
+    assert!(report.unreachable().is_empty());
+    assert_eq!(*report.id_collisions(), vec![(1, vec![0, 1, 2, 3])]);
+    assert_eq!(
+        report.to_string(),
+        "GraphValidationReport: 1 issue(s); id collisions: [(1, [0, 1, 2, 3])]"
+    );
+}
+
+#[test]
+fn test_validate_after_removal() {
+    let (mut g, [_, a, b, c]) = get_valid_graph();
+    g.remove_causaloid(a).expect("Failed to remove causaloid");
+
+    let report = g.validate();
+    assert!(report.dangling_edges().is_empty());
+    assert!(report.unreachable().is_empty());
+    assert!(g.contains_edge(b, c));
+}
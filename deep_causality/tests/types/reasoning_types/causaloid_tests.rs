@@ -47,6 +47,56 @@ fn test_new() {
     assert!(causaloid.context().is_none());
 }
 
+#[test]
+fn test_new_async() {
+    let causaloid = test_utils_async::get_test_async_causaloid();
+    assert!(causaloid.is_singleton());
+    assert!(!causaloid.is_active());
+
+    let res = test_utils_async::block_on(causaloid.verify_single_cause_async(&0.89));
+    assert!(res.unwrap());
+    assert!(causaloid.is_active());
+    assert!(causaloid.explain().is_ok());
+
+    let res = test_utils_async::block_on(causaloid.verify_single_cause_async(&0.23));
+    assert!(!res.unwrap());
+    assert!(!causaloid.is_active());
+
+    let res = test_utils_async::block_on(causaloid.verify_single_cause_async(&f64::NAN));
+    assert!(res.is_err());
+
+    // An async causaloid cannot be verified synchronously.
+    assert!(causaloid.verify_single_cause(&0.89).is_err());
+}
+
+#[test]
+fn test_verify_single_cause_async_sync_causaloid() {
+    let causaloid = test_utils::get_test_causaloid();
+
+    let res = test_utils_async::block_on(causaloid.verify_single_cause_async(&0.89));
+    assert!(res.unwrap());
+    assert!(causaloid.is_active());
+}
+
+#[test]
+fn test_verify_single_cause_async_is_send() {
+    fn assert_send<F: Send>(future: F) -> F {
+        future
+    }
+
+    let causaloid = test_utils_async::get_test_async_causaloid();
+
+    // The future can be moved to and awaited on another thread.
+    let future = assert_send(causaloid.verify_single_cause_async(&0.89));
+    let res = std::thread::scope(|s| {
+        s.spawn(move || test_utils_async::block_on(future))
+            .join()
+            .unwrap()
+    });
+    assert!(res.unwrap());
+    assert!(causaloid.is_active());
+}
+
 #[test]
 fn test_new_with_context() {
     let id: IdentificationValue = 1;
@@ -89,6 +139,43 @@ fn test_new_with_context() {
     assert!(causaloid.context().is_some());
 }
 
+#[test]
+fn test_new_with_context_set() {
+    let id: IdentificationValue = 1;
+    let description = "tests whether data exceeds the number of telemetry nodes";
+
+    let config = get_context();
+    let mut telemetry = get_context();
+    let root = Contextoid::new(2, ContextoidType::Root(Root::new(2)));
+    telemetry.add_node(root);
+
+    let context_set = BaseContextSet::new()
+        .with_context("config", &config)
+        .with_context("telemetry", &telemetry);
+
+    fn context_set_causal_fn(
+        obs: NumericalValue,
+        contexts: &BaseContextSet,
+    ) -> Result<bool, CausalityError> {
+        let telemetry = contexts
+            .get("telemetry")
+            .ok_or_else(|| CausalityError("Telemetry context not found".into()))?;
+
+        Ok(obs >= telemetry.node_count() as NumericalValue)
+    }
+
+    let causaloid: BaseCausaloid =
+        Causaloid::new_with_context_set(id, context_set_causal_fn, &context_set, description);
+
+    assert!(causaloid.is_singleton());
+    assert!(causaloid.context().is_none());
+    assert_eq!(causaloid.context_set().unwrap().len(), 2);
+
+    assert!(!causaloid.verify_single_cause(&1.0).unwrap());
+    assert!(causaloid.verify_single_cause(&2.0).unwrap());
+    assert!(causaloid.is_active());
+}
+
 #[test]
 fn test_from_causal_collection() {
     let id: IdentificationValue = 1;
@@ -215,6 +302,32 @@ fn test_verify_single_cause() {
     assert!(causaloid.is_active());
 }
 
+#[test]
+fn test_try_verify_single_cause() {
+    let causaloid = test_utils::get_test_causaloid();
+    assert!(causaloid.try_verify_single_cause(&0.78).unwrap());
+
+    let err = causaloid.try_verify_single_cause(&f64::NAN).unwrap_err();
+    assert_eq!(err.kind(), ReasoningErrorKind::Evaluation);
+    assert_eq!(err.causaloid_id(), Some(1));
+    assert_eq!(err.message(), "Observation is NULL/NAN");
+    assert_eq!(
+        err.chain(),
+        vec![
+            "ReasoningError[E200] Evaluation: Observation is NULL/NAN (causaloid: 1)",
+            "CausalityError: Observation is NULL/NAN",
+        ]
+    );
+}
+
+#[test]
+fn test_try_verify_all_causes() {
+    let causaloid = test_utils::get_test_causaloid();
+    let err = causaloid.try_verify_all_causes(&[0.78], None).unwrap_err();
+    assert_eq!(err.kind(), ReasoningErrorKind::Evaluation);
+    assert_eq!(err.causaloid_id(), Some(1));
+}
+
 #[test]
 fn test_to_string() {
     let causaloid = test_utils::get_test_causaloid();
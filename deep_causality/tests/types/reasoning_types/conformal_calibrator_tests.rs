@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+#[test]
+fn test_new() {
+    let calibrator = ConformalCalibrator::new(0.2, None).unwrap();
+    assert_eq!(calibrator.alpha(), 0.2);
+    assert!((calibrator.coverage() - 0.8).abs() < 1e-12);
+    assert_eq!(calibrator.capacity(), None);
+    assert!(calibrator.is_empty());
+    assert_eq!(calibrator.min_calibration_size(), 4);
+
+    assert!(ConformalCalibrator::new(0.0, None).is_err());
+    assert!(ConformalCalibrator::new(1.0, None).is_err());
+    assert!(ConformalCalibrator::new(f64::NAN, None).is_err());
+    assert!(ConformalCalibrator::new(0.1, Some(0)).is_err());
+}
+
+#[test]
+fn test_predict_interval() {
+    let mut calibrator = ConformalCalibrator::new(0.2, None).unwrap();
+
+    // Residuals 0.1, 0.2, 0.3
+    calibrator.calibrate(1.0, 1.1).unwrap();
+    calibrator.calibrate(2.0, 1.8).unwrap();
+    calibrator.calibrate(3.0, 3.3).unwrap();
+    // Too few residuals for 80% coverage.
+    assert!(calibrator.quantile().is_none());
+    assert!(calibrator.predict_interval(5.0).is_none());
+
+    // Residual 0.4; rank ceil(5 * 0.8) = 4 yields the largest residual.
+    calibrator.calibrate(4.0, 4.4).unwrap();
+    assert_eq!(calibrator.len(), 4);
+    let q = calibrator.quantile().unwrap();
+    assert!((q - 0.4).abs() < 1e-12);
+
+    let interval = calibrator.predict_interval(10.0).unwrap();
+    assert!((*interval.lower() - 9.6).abs() < 1e-12);
+    assert!((*interval.upper() - 10.4).abs() < 1e-12);
+    assert!((interval.width() - 0.8).abs() < 1e-12);
+    assert!(interval.contains(10.3));
+    assert!(!interval.contains(10.5));
+}
+
+#[test]
+fn test_coverage() {
+    let mut calibrator = ConformalCalibrator::new(0.1, None).unwrap();
+
+    // Deterministic residuals spread evenly over [0, 1).
+    let residual = |i: usize| ((i * 37) % 100) as f64 / 100.0;
+    for i in 0..200 {
+        calibrator.calibrate(0.0, residual(i)).unwrap();
+    }
+
+    let interval = calibrator.predict_interval(0.0).unwrap();
+    let covered = (200..1200)
+        .filter(|i| interval.contains(residual(*i)))
+        .count();
+    assert!(covered as f64 / 1000.0 >= 0.9);
+}
+
+#[test]
+fn test_capacity() {
+    let mut calibrator = ConformalCalibrator::new(0.5, Some(2)).unwrap();
+
+    calibrator.calibrate(0.0, 5.0).unwrap();
+    calibrator.calibrate(0.0, 1.0).unwrap();
+    calibrator.calibrate(0.0, 2.0).unwrap();
+    assert_eq!(calibrator.len(), 2);
+
+    // The large residual has been evicted.
+    assert_eq!(calibrator.quantile(), Some(2.0));
+
+    calibrator.clear();
+    assert!(calibrator.is_empty());
+}
+
+#[test]
+fn test_calibrate_error() {
+    let mut calibrator = ConformalCalibrator::new(0.1, None).unwrap();
+    assert!(calibrator.calibrate(f64::NAN, 1.0).is_err());
+    assert!(calibrator.calibrate(1.0, f64::INFINITY).is_err());
+    assert!(calibrator.is_empty());
+}
+
+#[test]
+fn test_display() {
+    let mut calibrator = ConformalCalibrator::new(0.5, None).unwrap();
+    calibrator.calibrate(1.0, 1.5).unwrap();
+    assert_eq!(
+        format!("{}", calibrator),
+        "ConformalCalibrator: alpha: 0.5, residuals: 1, quantile: Some(0.5)"
+    );
+
+    let interval = PredictionInterval::new(1.0, 2.0);
+    assert_eq!(format!("{}", interval), "PredictionInterval: [1, 2]");
+}
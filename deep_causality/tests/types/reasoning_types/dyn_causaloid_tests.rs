@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+
+// Custom causable that is not a Causaloid to test heterogeneous graphs.
+struct BelowCause {
+    id: IdentificationValue,
+    threshold: NumericalValue,
+}
+
+impl Identifiable for BelowCause {
+    fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl Causable for BelowCause {
+    fn explain(&self) -> Result<String, CausalityError> {
+        Ok(format!("below cause {}", self.id))
+    }
+
+    fn is_active(&self) -> bool {
+        true
+    }
+
+    fn is_singleton(&self) -> bool {
+        true
+    }
+
+    fn verify_single_cause(&self, obs: &NumericalValue) -> Result<bool, CausalityError> {
+        Ok(*obs < self.threshold)
+    }
+
+    fn verify_all_causes(
+        &self,
+        _data: &[NumericalValue],
+        _data_index: Option<&HashMap<IdentificationValue, IdentificationValue>>,
+    ) -> Result<bool, CausalityError> {
+        Err(CausalityError("BelowCause is a singleton".into()))
+    }
+}
+
+#[test]
+fn test_from_causaloid() {
+    let causaloid = get_test_causaloid();
+    let dyn_causaloid: DynCausaloid = causaloid.into();
+
+    assert_eq!(dyn_causaloid.id(), 1);
+    assert!(dyn_causaloid.is_singleton());
+    assert!(!dyn_causaloid.is_active());
+
+    assert!(dyn_causaloid.verify_single_cause(&0.89).unwrap());
+    assert!(dyn_causaloid.is_active());
+    assert!(dyn_causaloid.explain().is_ok());
+}
+
+#[test]
+fn test_new() {
+    let dyn_causaloid = DynCausaloid::new(BelowCause {
+        id: 7,
+        threshold: 0.3,
+    });
+
+    assert_eq!(dyn_causaloid.id(), 7);
+    assert_eq!(dyn_causaloid.inner().id(), 7);
+    assert!(dyn_causaloid.verify_single_cause(&0.1).unwrap());
+    assert!(!dyn_causaloid.verify_single_cause(&0.5).unwrap());
+    assert!(dyn_causaloid.verify_all_causes(&[0.1], None).is_err());
+    assert_eq!(dyn_causaloid.explain().unwrap(), "below cause 7");
+}
+
+#[test]
+fn test_clone_and_eq() {
+    let a: DynCausaloid = get_test_causaloid().into();
+    let b = a.clone();
+    let c = DynCausaloid::new(BelowCause {
+        id: 7,
+        threshold: 0.3,
+    });
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+
+    // Clones share the wrapped causaloid.
+    a.verify_single_cause(&0.89).unwrap();
+    assert!(b.is_active());
+}
+
+#[test]
+fn test_debug() {
+    let dyn_causaloid = DynCausaloid::new(BelowCause {
+        id: 7,
+        threshold: 0.3,
+    });
+
+    let expected = "DynCausaloid: id: 7, is singleton: true, is active: true";
+    assert_eq!(format!("{:?}", dyn_causaloid), expected);
+}
+
+#[test]
+fn test_heterogeneous_graph() {
+    let mut g: DynCausalGraph = CausaloidGraph::new();
+
+    let root_index = g.add_root_causaloid(get_test_causaloid().into());
+    let below_index = g.add_causaloid(DynCausaloid::new(BelowCause {
+        id: 0,
+        threshold: 0.3,
+    }));
+    g.add_edge(root_index, below_index).unwrap();
+
+    assert_eq!(g.size(), 2);
+
+    // Data are matched to causaloids by id.
+    let data = [0.1, 0.89];
+    assert!(g.reason_all_causes(&data, None).unwrap());
+    assert!(g.all_active());
+
+    let data = [0.5, 0.89];
+    assert!(!g.reason_all_causes(&data, None).unwrap());
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::{test_utils, test_utils_graph};
+
+#[test]
+fn test_new() {
+    let arena = EvalArena::new();
+    assert!(arena.evaluated().is_empty());
+    assert!(arena.failures().is_empty());
+    assert!(!arena.result());
+
+    let arena = EvalArena::with_capacity(8);
+    assert!(arena.capacity() >= 8);
+}
+
+#[test]
+fn test_reason_all_causes_with_arena() {
+    let (g, data) = test_utils_graph::get_small_multi_cause_graph_and_data();
+    let mut arena = EvalArena::with_capacity(g.size());
+
+    let res = g
+        .reason_all_causes_with_arena(&data, None, ReasoningPolicy::FailFast, &mut arena)
+        .expect("Failed to reason");
+    assert!(res);
+    assert_eq!(arena.evaluated().len(), g.number_nodes());
+
+    let capacity = arena.capacity();
+
+    // The arena is reset before each evaluation and keeps its buffers.
+    let res = g
+        .reason_all_causes_with_arena(&data, None, ReasoningPolicy::FailFast, &mut arena)
+        .expect("Failed to reason");
+    assert!(res);
+    assert_eq!(arena.evaluated().len(), g.number_nodes());
+    assert_eq!(arena.capacity(), capacity);
+
+    let report = g
+        .reason_all_causes_with_policy(&data, None, ReasoningPolicy::FailFast)
+        .expect("Failed to reason");
+    assert_eq!(arena.evaluated(), report.evaluated().as_slice());
+
+    let report = arena.into_report();
+    assert!(*report.result());
+    assert!(report.is_complete());
+}
+
+#[test]
+fn test_reason_subgraph_from_cause_with_arena_failure() {
+    // root -> a (fails), root -> b
+    let mut g = CausaloidGraph::new();
+    let root_index = g.add_root_causaloid(test_utils::get_test_causaloid());
+    let idx_a = g.add_causaloid(test_utils::get_test_error_causaloid());
+    let idx_b = g.add_causaloid(test_utils::get_test_causaloid());
+    g.add_edge(root_index, idx_a).expect("Failed to add edge");
+    g.add_edge(root_index, idx_b).expect("Failed to add edge");
+
+    let data = [0.0, 0.99];
+    let mut arena = EvalArena::new();
+
+    let res = g.reason_subgraph_from_cause_with_arena(
+        root_index,
+        &data,
+        None,
+        ReasoningPolicy::FailFast,
+        &mut arena,
+    );
+    assert!(res.is_err());
+
+    let res = g
+        .reason_subgraph_from_cause_with_arena(
+            root_index,
+            &data,
+            None,
+            ReasoningPolicy::SkipAndLog,
+            &mut arena,
+        )
+        .expect("Failed to reason");
+    assert!(res);
+    assert_eq!(arena.evaluated().len(), 2);
+    assert_eq!(arena.failures().len(), 1);
+    assert_eq!(*arena.failures()[0].index(), idx_a);
+
+    arena.reset();
+    assert!(arena.evaluated().is_empty());
+    assert!(arena.failures().is_empty());
+}
+
+#[test]
+fn test_reason_with_arena_error() {
+    let g: BaseCausalGraph = CausaloidGraph::new();
+    let mut arena = EvalArena::new();
+
+    let res = g.reason_all_causes_with_arena(&[0.99], None, ReasoningPolicy::FailFast, &mut arena);
+    assert!(res.is_err());
+
+    let (g, _) = test_utils_graph::get_small_multi_cause_graph_and_data();
+    let res = g.reason_subgraph_from_cause_with_arena(
+        0,
+        &[],
+        None,
+        ReasoningPolicy::FailFast,
+        &mut arena,
+    );
+    assert!(res.is_err());
+
+    let res = g.reason_subgraph_from_cause_with_arena(
+        99,
+        &[0.99],
+        None,
+        ReasoningPolicy::FailFast,
+        &mut arena,
+    );
+    assert!(res.is_err());
+}
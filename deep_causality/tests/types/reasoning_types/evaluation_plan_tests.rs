@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::HashMap;
+
+use deep_causality::prelude::*;
+
+fn get_causaloid<'l>(id: IdentificationValue) -> BaseCausaloid<'l> {
+    let description = "tests whether data exceeds threshold of 0.55";
+
+    fn causal_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+        if obs.is_nan() {
+            return Err(CausalityError("Observation is NULL/NAN".into()));
+        }
+
+        Ok(obs.ge(&0.55))
+    }
+
+    Causaloid::new(id, causal_fn, description)
+}
+
+// Builds the graph:
+//  root
+//  / \
+//  A B
+//  \ /
+//   C
+fn get_graph<'l>() -> BaseCausalGraph<'l> {
+    let mut g = CausaloidGraph::new();
+
+    let root_index = g.add_root_causaloid(get_causaloid(0));
+    let idx_a = g.add_causaloid(get_causaloid(1));
+    let idx_b = g.add_causaloid(get_causaloid(2));
+    let idx_c = g.add_causaloid(get_causaloid(3));
+
+    g.add_edge(root_index, idx_a).expect("Failed to add edge");
+    g.add_edge(root_index, idx_b).expect("Failed to add edge");
+    g.add_edge(idx_a, idx_c).expect("Failed to add edge");
+    g.add_edge(idx_b, idx_c).expect("Failed to add edge");
+
+    g
+}
+
+#[test]
+fn test_compile_plan() {
+    let g = get_graph();
+    let plan = g.compile_plan().unwrap();
+
+    assert_eq!(plan.len(), 4);
+    assert!(!plan.is_empty());
+    assert_eq!(*plan.fingerprint(), g.fingerprint());
+    assert_eq!(plan.order()[0], 0);
+    assert_eq!(plan.order()[3], 3);
+    assert_eq!(plan.input_offsets(), &vec![0, 1, 2, 3]);
+    assert_eq!(plan.singletons(), &vec![true; 4]);
+}
+
+#[test]
+fn test_compile_plan_skips_unreachable() {
+    let mut g = get_graph();
+    g.add_causaloid(get_causaloid(4));
+
+    let plan = g.compile_plan().unwrap();
+    assert_eq!(plan.len(), 4);
+    assert!(!plan.order().contains(&4));
+}
+
+#[test]
+fn test_compile_plan_error() {
+    let g: BaseCausalGraph = CausaloidGraph::new();
+    assert!(g.compile_plan().is_err());
+
+    let mut g = get_graph();
+    g.add_edge(3, 0).expect("Failed to add edge");
+    assert!(g.compile_plan().is_err());
+}
+
+#[test]
+fn test_evaluate_plan() {
+    let g = get_graph();
+    let plan = g.compile_plan().unwrap();
+
+    let data = [0.99; 4];
+    let res = g.evaluate_plan(&plan, &data, None).unwrap();
+    assert!(res);
+    assert_eq!(res, g.reason_all_causes(&data, None).unwrap());
+
+    let data = [0.99, 0.99, 0.1, 0.99];
+    let res = g.evaluate_plan(&plan, &data, None).unwrap();
+    assert!(!res);
+    assert_eq!(res, g.reason_all_causes(&data, None).unwrap());
+}
+
+#[test]
+fn test_evaluate_plan_vs_reason_all_causes() {
+    // Chain built in order: root -> a -> b
+    let mut g: BaseCausalGraph = CausaloidGraph::new();
+    let root_index = g.add_root_causaloid(get_causaloid(0));
+    let idx_a = g.add_causaloid(get_causaloid(1));
+    let idx_b = g.add_causaloid(get_causaloid(2));
+    g.add_edge(root_index, idx_a).expect("Failed to add edge");
+    g.add_edge(idx_a, idx_b).expect("Failed to add edge");
+    let plan = g.compile_plan().unwrap();
+
+    // Both methods evaluate all causaloids and agree.
+    for data in [[0.99, 0.99, 0.99], [0.99, 0.1, 0.99], [0.99, 0.99, 0.1]] {
+        assert_eq!(
+            g.evaluate_plan(&plan, &data, None).unwrap(),
+            g.reason_all_causes(&data, None).unwrap()
+        );
+    }
+
+    // After removing a causaloid, the index of c equals the number of causaloids,
+    // which reason_all_causes uses as stop index: root -> c -> b
+    let mut g: BaseCausalGraph = CausaloidGraph::new();
+    let root_index = g.add_root_causaloid(get_causaloid(0));
+    let idx_x = g.add_causaloid(get_causaloid(1));
+    let idx_b = g.add_causaloid(get_causaloid(2));
+    let idx_c = g.add_causaloid(get_causaloid(3));
+    g.remove_causaloid(idx_x)
+        .expect("Failed to remove causaloid");
+    g.add_edge(root_index, idx_c).expect("Failed to add edge");
+    g.add_edge(idx_c, idx_b).expect("Failed to add edge");
+    assert_eq!(g.get_last_index().unwrap(), idx_c);
+    let plan = g.compile_plan().unwrap();
+
+    // reason_all_causes stops at c and never evaluates b,
+    // whereas the plan evaluates all reachable causaloids.
+    let data = [0.99, 0.99, 0.1, 0.99];
+    assert!(g.reason_all_causes(&data, None).unwrap());
+    assert!(!g.evaluate_plan(&plan, &data, None).unwrap());
+}
+
+#[test]
+fn test_evaluate_plan_with_data_index() {
+    let g = get_graph();
+    let plan = g.compile_plan().unwrap();
+
+    // Data stored in reverse order.
+    let data = [0.1, 0.99, 0.99, 0.99];
+    let data_index = HashMap::from([(0, 3), (1, 2), (2, 1), (3, 0)]);
+
+    let res = g.evaluate_plan(&plan, &data, Some(&data_index)).unwrap();
+    assert!(!res);
+
+    let data = [0.99; 4];
+    let res = g.evaluate_plan(&plan, &data, Some(&data_index)).unwrap();
+    assert!(res);
+}
+
+#[test]
+fn test_evaluate_plan_error() {
+    let g = get_graph();
+    let plan = g.compile_plan().unwrap();
+
+    let data = [0.99; 2];
+    assert!(g.evaluate_plan(&plan, &data, None).is_err());
+
+    let data = [0.99, f64::NAN, 0.99, 0.99];
+    assert!(g.evaluate_plan(&plan, &data, None).is_err());
+}
+
+#[test]
+fn test_evaluate_stale_plan_error() {
+    let mut g = get_graph();
+    let plan = g.compile_plan().unwrap();
+    let data = [0.99; 5];
+    assert!(g.evaluate_plan(&plan, &data, None).unwrap());
+
+    // A plan compiled before the graph changed is rejected.
+    let idx_d = g.add_causaloid(get_causaloid(4));
+    g.add_edge(3, idx_d).expect("Failed to add edge");
+    assert!(g.evaluate_plan(&plan, &data, None).is_err());
+
+    let plan = g.compile_plan().unwrap();
+    assert!(g.evaluate_plan(&plan, &data, None).unwrap());
+
+    // A plan of another graph is rejected as well.
+    let other = get_graph();
+    assert!(other.evaluate_plan(&plan, &data, None).is_err());
+}
+
+#[test]
+fn test_evaluation_plan_cache() {
+    let mut cache = EvaluationPlanCache::new();
+    assert!(cache.is_empty());
+
+    let g = get_graph();
+    let plan = cache.get_or_compile(&g).unwrap().clone();
+    assert_eq!(cache.len(), 1);
+    assert!(cache.contains(g.fingerprint()));
+    assert_eq!(cache.get(g.fingerprint()), Some(&plan));
+
+    // Same graph re-uses the cached plan.
+    let other = get_graph();
+    cache.get_or_compile(&other).unwrap();
+    assert_eq!(cache.len(), 1);
+
+    // A changed graph compiles a new plan.
+    let mut changed = get_graph();
+    changed.add_causaloid(get_causaloid(4));
+    cache.get_or_compile(&changed).unwrap();
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_evaluate_all() {
+    let g = get_graph();
+
+    // Evaluation continues past B evaluating to false.
+    let data = [0.99, 0.99, 0.1, 0.99];
+    let effects = g.evaluate_all(&data, None).unwrap();
+
+    assert_eq!(effects.len(), 4);
+    assert!(effects[&0]);
+    assert!(effects[&1]);
+    assert!(!effects[&2]);
+    assert!(effects[&3]);
+}
+
+#[test]
+fn test_evaluate_all_with_data_index() {
+    let g = get_graph();
+
+    let data = [0.1, 0.99, 0.99, 0.99];
+    let data_index = HashMap::from([(0, 3), (1, 2), (2, 1), (3, 0)]);
+
+    let effects = g.evaluate_all(&data, Some(&data_index)).unwrap();
+    assert!(effects[&0]);
+    assert!(!effects[&3]);
+}
+
+#[test]
+fn test_evaluate_all_error() {
+    let g: BaseCausalGraph = CausaloidGraph::new();
+    assert!(g.evaluate_all(&[0.99], None).is_err());
+
+    let g = get_graph();
+    let data = [0.99, f64::NAN, 0.99, 0.99];
+    assert!(g.evaluate_all(&data, None).is_err());
+}
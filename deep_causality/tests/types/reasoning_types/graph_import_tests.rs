@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+fn above_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+    if obs.is_nan() {
+        return Err(CausalityError("Observation is NULL/NAN".into()));
+    }
+    Ok(obs.ge(&0.55))
+}
+
+fn below_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+    Ok(obs.lt(&0.55))
+}
+
+fn get_registry() -> CausalFnRegistry {
+    let mut registry = CausalFnRegistry::new();
+    registry.register("root", above_fn);
+    registry.register("a", above_fn);
+    registry.register("b", below_fn);
+    registry.register("c", above_fn);
+    registry
+}
+
+#[test]
+fn test_registry() {
+    let registry = get_registry();
+    assert_eq!(registry.len(), 4);
+    assert!(!registry.is_empty());
+    assert!(registry.contains("a"));
+    assert!(!registry.contains("d"));
+
+    let (name, causal_fn) = registry.get("b").unwrap();
+    assert_eq!(name, "b");
+    assert!(causal_fn(0.1).unwrap());
+    assert!(registry.get("d").is_none());
+}
+
+#[test]
+fn test_edge_list() {
+    let mut edge_list = EdgeList::new();
+    assert!(edge_list.is_empty());
+
+    edge_list.add_edge("root", "a");
+    edge_list.add_edge("root", "b");
+    edge_list.add_edge("a", "c");
+    edge_list.add_edge("b", "c");
+
+    assert_eq!(edge_list.len(), 4);
+    assert_eq!(edge_list.nodes(), vec!["root", "a", "b", "c"]);
+    assert_eq!(edge_list.edges()[2], ("a".to_string(), "c".to_string()));
+}
+
+#[test]
+fn test_from_csv() {
+    let csv = "source,target\n\
+        root,a\n\
+        # comment\n\
+        \n\
+        root, b\n\
+        \"a\",c\n";
+
+    let edge_list = EdgeList::from_csv(csv).unwrap();
+    assert_eq!(edge_list.len(), 3);
+    assert_eq!(edge_list.nodes(), vec!["root", "a", "b", "c"]);
+
+    assert!(EdgeList::from_csv("root,a\nroot").is_err());
+    assert!(EdgeList::from_csv("root,a,b").is_err());
+    assert!(EdgeList::from_csv("root,").is_err());
+}
+
+#[test]
+fn test_from_dot() {
+    let dot = r#"digraph G {
+        // model authored in graphviz
+        root [label="Root"];
+        root -> a -> c;
+        "root" -> "b" [label="inhibits"];
+        b -> c
+    }"#;
+
+    let edge_list = EdgeList::from_dot(dot).unwrap();
+    assert_eq!(edge_list.len(), 4);
+    assert_eq!(edge_list.nodes(), vec!["root", "a", "c", "b"]);
+
+    assert!(EdgeList::from_dot("a -> ;").is_err());
+}
+
+#[test]
+fn test_from_dot_single_line() {
+    let edge_list = EdgeList::from_dot("digraph G { a -> b; b -> c }").unwrap();
+    assert_eq!(edge_list.nodes(), vec!["a", "b", "c"]);
+    assert_eq!(
+        edge_list.edges(),
+        &[
+            ("a".to_string(), "b".to_string()),
+            ("b".to_string(), "c".to_string())
+        ]
+    );
+
+    // Statements without separator and arrows without whitespace
+    let edge_list = EdgeList::from_dot("strict digraph {a->b c->d}").unwrap();
+    assert_eq!(edge_list.nodes(), vec!["a", "b", "c", "d"]);
+    assert_eq!(edge_list.len(), 2);
+}
+
+#[test]
+fn test_from_dot_quoted_names() {
+    let dot = r#"digraph { "a;b" -> "c[0]" [label="x;y]"]; "c[0]" -> "d -> e" }"#;
+
+    let edge_list = EdgeList::from_dot(dot).unwrap();
+    assert_eq!(edge_list.nodes(), vec!["a;b", "c[0]", "d -> e"]);
+    assert_eq!(edge_list.len(), 2);
+
+    let edge_list = EdgeList::from_dot(r#"digraph { "say \"hi\"" -> b }"#).unwrap();
+    assert_eq!(edge_list.nodes(), vec![r#"say "hi""#, "b"]);
+}
+
+#[test]
+fn test_from_dot_comments() {
+    let dot = r#"# generated
+    digraph G {
+        /* a -> x;
+           ignored */
+        a -> b; // b -> y
+        b /* inline */ -> c
+    }"#;
+
+    let edge_list = EdgeList::from_dot(dot).unwrap();
+    assert_eq!(edge_list.nodes(), vec!["a", "b", "c"]);
+    assert_eq!(edge_list.len(), 2);
+}
+
+#[test]
+fn test_from_dot_err() {
+    // Missing source or target
+    assert!(EdgeList::from_dot("digraph { -> b }").is_err());
+    assert!(EdgeList::from_dot("digraph { a -> }").is_err());
+    assert!(EdgeList::from_dot("a -> -> b").is_err());
+    // Empty name
+    assert!(EdgeList::from_dot(r#"a -> """#).is_err());
+    // Unterminated quoted name, attributes, or comment
+    assert!(EdgeList::from_dot(r#"digraph { "a -> b }"#).is_err());
+    assert!(EdgeList::from_dot("digraph { a -> b [label=x }").is_err());
+    assert!(EdgeList::from_dot("digraph { a -> b /* }").is_err());
+}
+
+#[test]
+fn test_from_edge_list() {
+    let registry = get_registry();
+    let edge_list = EdgeList::from_csv("root,a\nroot,b\na,c\nb,c").unwrap();
+
+    let g: BaseCausalGraph = CausaloidGraph::from_edge_list(&edge_list, &registry).unwrap();
+    assert_eq!(g.size(), 4);
+    assert_eq!(g.number_edges(), 4);
+    assert_eq!(g.get_root_index(), Some(0));
+    assert_eq!(g.get_causaloid(2).unwrap().description(), "b");
+    assert!(g.contains_edge(2, 3));
+
+    // Data at index i apply to node i: root, a, b, c
+    let data = [0.9, 0.9, 0.1, 0.9];
+    assert!(g.reason_all_causes(&data, None).unwrap());
+
+    let data = [0.9, 0.9, 0.9, 0.9];
+    assert!(!g.reason_all_causes(&data, None).unwrap());
+}
+
+#[test]
+fn test_from_edge_list_error() {
+    let registry = get_registry();
+
+    let res: Result<BaseCausalGraph, BuildError> =
+        CausaloidGraph::from_edge_list(&EdgeList::new(), &registry);
+    assert!(res.is_err());
+
+    let edge_list = EdgeList::from_csv("root,a\na,d").unwrap();
+    let res: Result<BaseCausalGraph, BuildError> =
+        CausaloidGraph::from_edge_list(&edge_list, &registry);
+    assert_eq!(
+        res.err().unwrap().to_string(),
+        "BuildError: No causal function registered for node d"
+    );
+
+    // Two roots
+    let edge_list = EdgeList::from_csv("root,c\nb,c").unwrap();
+    let res: Result<BaseCausalGraph, BuildError> =
+        CausaloidGraph::from_edge_list(&edge_list, &registry);
+    assert!(res.is_err());
+
+    // No root
+    let edge_list = EdgeList::from_csv("a,b\nb,a").unwrap();
+    let res: Result<BaseCausalGraph, BuildError> =
+        CausaloidGraph::from_edge_list(&edge_list, &registry);
+    assert!(res.is_err());
+}
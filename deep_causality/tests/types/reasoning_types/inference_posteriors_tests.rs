@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+
+#[test]
+fn test_new() {
+    let posteriors = InferencePosteriors::default();
+    assert_eq!(posteriors.prior(), BetaDistribution::uniform());
+    assert!(posteriors.ids().is_empty());
+
+    let prior = BetaDistribution::new(2.0, 2.0).unwrap();
+    let posteriors = InferencePosteriors::new(prior);
+    assert_eq!(posteriors.posterior(7), prior);
+    assert!(!posteriors.contains(7));
+}
+
+#[test]
+fn test_observe() {
+    let mut posteriors = InferencePosteriors::default();
+
+    let inferable = get_test_inferable(1, false);
+    let inverse = get_test_inferable(2, true);
+    assert!(inferable.is_inferable());
+    assert!(!inverse.is_inferable());
+
+    posteriors.observe(&inferable);
+    let posterior = posteriors.observe(&inferable);
+    assert_eq!(posterior, BetaDistribution::new(3.0, 1.0).unwrap());
+    assert_eq!(posteriors.posterior(1).mean(), 0.75);
+
+    posteriors.observe(&inverse);
+    assert_eq!(posteriors.posterior(2).mean(), 1.0 / 3.0);
+
+    assert_eq!(posteriors.ids(), vec![1, 2]);
+}
+
+#[test]
+fn test_update_and_reset() {
+    let mut posteriors = InferencePosteriors::default();
+
+    posteriors.update(1, true);
+    posteriors.update(1, false);
+    posteriors.update(2, true);
+    assert_eq!(
+        posteriors.posterior(1),
+        BetaDistribution::new(2.0, 2.0).unwrap()
+    );
+
+    posteriors.reset(1);
+    assert!(!posteriors.contains(1));
+    assert_eq!(posteriors.posterior(1), posteriors.prior());
+
+    posteriors.clear();
+    assert!(posteriors.ids().is_empty());
+}
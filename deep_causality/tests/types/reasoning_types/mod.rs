@@ -1,17 +1,57 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
+#[cfg(test)]
+mod assumption_monitor_tests;
 #[cfg(test)]
 mod assumption_tests;
 #[cfg(test)]
+mod beta_distribution_tests;
+#[cfg(test)]
+mod causal_executor_tests;
+#[cfg(test)]
+mod causality_graph_attribution_tests;
+#[cfg(test)]
+mod causality_graph_diagnosing_tests;
+#[cfg(test)]
 mod causality_graph_explaining_tests;
 #[cfg(test)]
+mod causality_graph_incremental_tests;
+#[cfg(test)]
 mod causality_graph_reasoning_tests;
 #[cfg(test)]
+mod causality_graph_simulating_tests;
+#[cfg(test)]
 mod causality_graph_tests;
 #[cfg(test)]
+mod causality_graph_validation_tests;
+#[cfg(test)]
 mod causaloid_tests;
 #[cfg(test)]
+mod conformal_calibrator_tests;
+#[cfg(test)]
+mod dyn_causaloid_tests;
+#[cfg(test)]
+mod eval_arena_tests;
+#[cfg(test)]
+mod evaluation_plan_tests;
+#[cfg(test)]
+mod graph_import_tests;
+#[cfg(test)]
+mod inference_posteriors_tests;
+#[cfg(test)]
 mod inference_tests;
 #[cfg(test)]
+mod observation_statistics_tests;
+#[cfg(test)]
+mod observation_store_tests;
+#[cfg(test)]
 mod observation_tests;
+#[cfg(test)]
+mod online_learner_tests;
+#[cfg(test)]
+mod page_hinkley_tests;
+#[cfg(test)]
+mod reasoning_policy_tests;
+#[cfg(test)]
+mod reasoning_report_diff_tests;
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::test_utils::*;
+
+#[test]
+fn test_new() {
+    let stats = ObservationStatistics::new(10.0, 1.0);
+    assert_eq!(stats.target_threshold(), 10.0);
+    assert_eq!(stats.target_effect(), 1.0);
+    assert_eq!(stats.decay(), 1.0);
+    assert!(stats.is_empty());
+    assert_eq!(stats.percent_observation(), 0.0);
+    assert_eq!(stats.percent_non_observation(), 0.0);
+    assert_eq!(stats.weighted_percent_observation(), 0.0);
+}
+
+#[test]
+fn test_new_with_decay_err() {
+    assert!(ObservationStatistics::new_with_decay(10.0, 1.0, 0.0).is_err());
+    assert!(ObservationStatistics::new_with_decay(10.0, 1.0, 1.5).is_err());
+    assert!(ObservationStatistics::new_with_decay(10.0, 1.0, NumericalValue::NAN).is_err());
+
+    let err = ObservationStatistics::new_with_decay(10.0, 1.0, 2.0).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "BuildError: Decay 2 is out of range (0, 1]"
+    );
+}
+
+#[test]
+fn test_update() {
+    let mut stats = ObservationStatistics::new(10.0, 1.0);
+    stats.update_all(&get_test_obs_vec());
+
+    assert_eq!(stats.len(), 5);
+    assert_eq!(stats.number_observation(), 3.0);
+    assert_eq!(stats.number_non_observation(), 2.0);
+    assert_eq!(stats.percent_observation(), 0.6);
+    assert_eq!(stats.weighted_percent_observation(), 0.6);
+    assert!((stats.percent_non_observation() - 0.4).abs() < 1e-9);
+}
+
+#[test]
+fn test_recency_weighting() {
+    let mut stats = ObservationStatistics::new_with_decay(10.0, 1.0, 0.5).unwrap();
+
+    stats.update(&Observation::new(0, 12.0, 1.0));
+    stats.update(&Observation::new(1, 12.0, 0.0));
+
+    // Weights: first observation 0.5, second 1.0
+    assert_eq!(stats.percent_observation(), 0.5);
+    assert!((stats.weighted_percent_observation() - 0.5 / 1.5).abs() < 1e-9);
+
+    stats.update(&Observation::new(2, 12.0, 1.0));
+    // Weights: 0.25, 0.5, 1.0
+    assert!((stats.weighted_percent_observation() - 1.25 / 1.75).abs() < 1e-9);
+}
+
+#[test]
+fn test_groups() {
+    let mut stats = ObservationStatistics::new(10.0, 1.0);
+    stats.update_group(2, &Observation::new(0, 12.0, 1.0));
+    stats.update_group(2, &Observation::new(1, 8.0, 1.0));
+    stats.update_group(1, &Observation::new(2, 12.0, 1.0));
+
+    assert_eq!(stats.len(), 3);
+    assert_eq!(stats.groups(), vec![1, 2]);
+    assert_eq!(stats.group_len(2), Some(2));
+    assert_eq!(stats.group_percent_observation(2), Some(0.5));
+    assert_eq!(stats.group_percent_observation(1), Some(1.0));
+    assert_eq!(stats.group_percent_observation(3), None);
+    assert_eq!(stats.group_len(3), None);
+}
+
+#[test]
+fn test_reset() {
+    let mut stats = ObservationStatistics::new_with_decay(10.0, 1.0, 0.9).unwrap();
+    stats.update_group(1, &get_test_observation());
+    stats.reset();
+
+    assert!(stats.is_empty());
+    assert!(stats.groups().is_empty());
+    assert_eq!(stats.decay(), 0.9);
+}
+
+#[test]
+fn test_to_string() {
+    let mut stats = ObservationStatistics::new(10.0, 1.0);
+    stats.update(&get_test_observation());
+
+    assert_eq!(
+        stats.to_string(),
+        "ObservationStatistics: observations: 1, observed: 1, percent observed: 1, weighted percent observed: 1, groups: 0"
+    );
+}
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::io::{Cursor, ErrorKind};
+
+use deep_causality::prelude::*;
+
+fn get_test_store() -> ObservationStore {
+    let mut store = ObservationStore::new();
+    store.append(10, Observation::new(0, 10.0, 1.0));
+    store.append(20, Observation::new(1, 10.0, 1.0));
+    store.append(30, Observation::new(2, 10.0, 0.0));
+    store.append(40, Observation::new(3, 12.0, 1.0));
+    store.append(50, Observation::new(4, 14.0, 0.0));
+    store
+}
+
+#[test]
+fn test_new() {
+    let store = ObservationStore::new();
+    assert!(store.is_empty());
+    assert_eq!(store.len(), 0);
+    assert!(store.time_span().is_none());
+
+    let store = ObservationStore::with_capacity(10);
+    assert!(store.is_empty());
+}
+
+#[test]
+fn test_append() {
+    let mut store = get_test_store();
+    assert_eq!(store.len(), 5);
+    assert_eq!(store.time_span(), Some((10, 50)));
+
+    // Out of order observations are inserted in time order.
+    store.append(25, Observation::new(5, 11.0, 1.0));
+    store.append(20, Observation::new(6, 11.0, 1.0));
+    let times: Vec<u64> = store.entries().iter().map(|(t, _)| *t).collect();
+    assert_eq!(times, vec![10, 20, 20, 25, 30, 40, 50]);
+    assert_eq!(store.entries()[2].1.id(), 6);
+}
+
+#[test]
+fn test_range() {
+    let store = get_test_store();
+
+    let range = store.range(20..40);
+    assert_eq!(range.len(), 2);
+    assert_eq!(range[0].0, 20);
+    assert_eq!(range[1].0, 30);
+
+    assert!(store.range(60..70).is_empty());
+    let (start, end) = (40, 20);
+    assert!(store.range(start..end).is_empty());
+    assert_eq!(store.range(0..u64::MAX).len(), 5);
+}
+
+#[test]
+fn test_filter() {
+    let store = get_test_store();
+
+    let filtered = store.filter_by_observation(11.0, 14.0);
+    assert_eq!(filtered.len(), 2);
+    assert_eq!(filtered[0].id(), 3);
+
+    let filtered = store.filter(|time, o| time > 10 && o.observed_effect() == 1.0);
+    assert_eq!(filtered.len(), 2);
+}
+
+#[test]
+fn test_remove_before() {
+    let mut store = get_test_store();
+    assert_eq!(store.remove_before(30), 2);
+    assert_eq!(store.time_span(), Some((30, 50)));
+
+    store.clear();
+    assert!(store.is_empty());
+}
+
+#[test]
+fn test_observable_reasoning() {
+    let store = get_test_store();
+    assert_eq!(store.get_all_items().len(), 5);
+    assert_eq!(store.number_observation(10.0, 1.0), 3.0);
+    assert_eq!(store.percent_observation(10.0, 1.0), 0.6);
+
+    let recent = store.range(30..60);
+    assert_eq!(recent.len(), 3);
+}
+
+#[test]
+fn test_csv_round_trip() {
+    let mut store = get_test_store();
+    store.append(60, Observation::new(5, 0.1 + 0.2, -1.5));
+
+    let mut buffer = Vec::new();
+    store.write_csv(&mut buffer).unwrap();
+
+    let csv = String::from_utf8(buffer.clone()).unwrap();
+    assert!(csv.starts_with("time,id,observation,observed_effect\n10,0,10,1\n"));
+
+    let restored = ObservationStore::read_csv(Cursor::new(buffer)).unwrap();
+    assert_eq!(restored, store);
+}
+
+#[test]
+fn test_csv_invalid() {
+    let res = ObservationStore::read_csv(Cursor::new("a,b,c\n"));
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let csv = "time,id,observation,observed_effect\n10,0,10,1\n20,x,10,1\n";
+    let err = ObservationStore::read_csv(Cursor::new(csv)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(
+        err.to_string(),
+        "Invalid observation CSV at line 3: invalid id: x"
+    );
+
+    let csv = "time,id,observation,observed_effect\n10,0,10\n";
+    assert!(ObservationStore::read_csv(Cursor::new(csv)).is_err());
+}
+
+#[test]
+fn test_csv_file() {
+    let store = get_test_store();
+    let path = std::env::temp_dir().join(format!(
+        "deep_causality_observation_store_{}.csv",
+        std::process::id()
+    ));
+
+    store.save_csv(&path).unwrap();
+    let restored = ObservationStore::load_csv(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(restored, store);
+    assert!(ObservationStore::load_csv(&path).is_err());
+}
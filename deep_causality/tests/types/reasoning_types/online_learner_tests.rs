@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+struct Threshold {
+    id: IdentificationValue,
+    value: NumericalValue,
+}
+
+impl Identifiable for Threshold {
+    fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl OnlineAdjustable for Threshold {
+    fn parameter(&self) -> NumericalValue {
+        self.value
+    }
+
+    fn set_parameter(&mut self, value: NumericalValue) {
+        self.value = value;
+    }
+
+    fn parameter_bounds(&self) -> (NumericalValue, NumericalValue) {
+        (0.0, 1.0)
+    }
+}
+
+#[test]
+fn test_adjust_online() {
+    let mut threshold = Threshold { id: 1, value: 0.5 };
+
+    let change = threshold.adjust_online(0.2, 0.5, 1.0).unwrap();
+    assert_eq!(*change.id(), 1);
+    assert_eq!(*change.previous(), 0.5);
+    assert_eq!(*change.current(), 0.6);
+    assert!((change.step() - 0.1).abs() < 1e-12);
+    assert_eq!(threshold.value, 0.6);
+}
+
+#[test]
+fn test_adjust_online_bounded() {
+    let mut threshold = Threshold { id: 1, value: 0.5 };
+
+    // Step is clamped to max_step.
+    let change = threshold.adjust_online(-1.0, 1.0, 0.1).unwrap();
+    assert!((*change.current() - 0.4).abs() < 1e-12);
+
+    // Parameter is clamped to its bounds.
+    let change = threshold.adjust_online(10.0, 1.0, 5.0).unwrap();
+    assert_eq!(*change.current(), 1.0);
+}
+
+#[test]
+fn test_adjust_online_error() {
+    let mut threshold = Threshold { id: 1, value: 0.5 };
+
+    assert!(threshold.adjust_online(f64::NAN, 0.5, 1.0).is_err());
+    assert!(threshold.adjust_online(0.1, 0.0, 1.0).is_err());
+    assert!(threshold.adjust_online(0.1, 1.5, 1.0).is_err());
+    assert!(threshold.adjust_online(0.1, 0.5, 0.0).is_err());
+    assert_eq!(threshold.value, 0.5);
+}
+
+#[test]
+fn test_online_learner() {
+    let mut learner = OnlineLearner::new(0.5, 1.0).unwrap();
+    assert_eq!(learner.learning_rate(), 0.5);
+    assert_eq!(learner.max_step(), 1.0);
+
+    let mut a = Threshold { id: 1, value: 0.5 };
+    let mut b = Threshold { id: 2, value: 0.5 };
+
+    // Exponentially weighted recalibration towards a target of 0.9.
+    for _ in 0..3 {
+        let error = 0.9 - a.parameter();
+        learner.update(&mut a, error).unwrap();
+    }
+    learner.update(&mut b, -0.2).unwrap();
+
+    assert!((a.value - 0.85).abs() < 1e-12);
+    assert_eq!(learner.changes().len(), 4);
+    assert_eq!(learner.changes_of(1).len(), 3);
+    assert_eq!(*learner.changes_of(2)[0].current(), 0.4);
+
+    // Failed updates are not logged.
+    assert!(learner.update(&mut a, f64::INFINITY).is_err());
+    assert_eq!(learner.changes().len(), 4);
+
+    learner.clear_changes();
+    assert!(learner.changes().is_empty());
+}
+
+#[test]
+fn test_online_learner_new_error() {
+    assert!(OnlineLearner::new(0.0, 1.0).is_err());
+    assert!(OnlineLearner::new(0.5, -1.0).is_err());
+}
+
+#[test]
+fn test_parameter_change_display() {
+    let change = ParameterChange::new(1, 0.5, 0.6, 0.2);
+    assert_eq!(
+        format!("{}", change),
+        "ParameterChange: id: 1, previous: 0.5, current: 0.6, error: 0.2"
+    );
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+// Deterministic noise in [-0.1, 0.1].
+fn noise(i: usize) -> f64 {
+    ((i * 37) % 21) as f64 / 100.0 - 0.1
+}
+
+#[test]
+fn test_new() {
+    let ph = PageHinkley::new(0.05, 5.0, 10).unwrap();
+    assert_eq!(ph.delta(), 0.05);
+    assert_eq!(ph.threshold(), 5.0);
+    assert_eq!(ph.min_samples(), 10);
+    assert_eq!(ph.number_observed(), 0);
+    assert_eq!(ph.statistic(), 0.0);
+
+    assert!(PageHinkley::new(-0.1, 5.0, 10).is_err());
+    assert!(PageHinkley::new(0.1, 0.0, 10).is_err());
+    assert!(PageHinkley::new(f64::NAN, 5.0, 10).is_err());
+}
+
+#[test]
+fn test_no_drift() {
+    let mut ph = PageHinkley::new(0.05, 5.0, 10).unwrap();
+
+    for i in 0..1000 {
+        assert!(!ph.update(1.0 + noise(i)).unwrap());
+    }
+
+    assert_eq!(ph.number_observed(), 1000);
+    assert!((ph.mean() - 1.0).abs() < 0.01);
+    assert_eq!(ph.number_detected(), 0);
+}
+
+#[test]
+fn test_drift_increase() {
+    let mut ph = PageHinkley::new(0.05, 5.0, 10).unwrap();
+
+    for i in 0..100 {
+        assert!(!ph.update(1.0 + noise(i)).unwrap());
+    }
+
+    let detected_at = (100..200).find(|i| ph.update(2.0 + noise(*i)).unwrap());
+    assert!(detected_at.is_some());
+    assert!(detected_at.unwrap() < 120);
+    assert_eq!(ph.number_detected(), 1);
+
+    // Restarted after the detection.
+    assert_eq!(ph.number_observed(), 0);
+}
+
+#[test]
+fn test_drift_decrease() {
+    let mut ph = PageHinkley::new(0.05, 5.0, 10).unwrap();
+
+    for i in 0..100 {
+        ph.update(1.0 + noise(i)).unwrap();
+    }
+
+    let detected = (100..200).any(|i| ph.update(noise(i)).unwrap());
+    assert!(detected);
+}
+
+#[test]
+fn test_min_samples() {
+    let mut ph = PageHinkley::new(0.0, 0.5, 5).unwrap();
+
+    assert!(!ph.update(0.0).unwrap());
+    assert!(!ph.update(10.0).unwrap());
+    assert!(ph.statistic() > 0.5);
+}
+
+#[test]
+fn test_update_error() {
+    let mut ph = PageHinkley::new(0.05, 5.0, 10).unwrap();
+    assert!(ph.update(f64::NAN).is_err());
+    assert_eq!(ph.number_observed(), 0);
+}
+
+#[test]
+fn test_display() {
+    let ph = PageHinkley::new(0.05, 5.0, 10).unwrap();
+    assert_eq!(
+        format!("{}", ph),
+        "PageHinkley: observed: 0, mean: 0, statistic: 0, detected: 0"
+    );
+}
@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+use crate::utils::{test_utils, test_utils_graph};
+
+// Builds the graph:
+// root -> a (fails) -> c
+// root -> b
+fn get_graph_with_failing_node<'l>() -> (BaseCausalGraph<'l>, [usize; 4]) {
+    let mut g = CausaloidGraph::new();
+
+    let root_index = g.add_root_causaloid(test_utils::get_test_causaloid());
+    let idx_a = g.add_causaloid(test_utils::get_test_error_causaloid());
+    let idx_b = g.add_causaloid(test_utils::get_test_causaloid());
+    let idx_c = g.add_causaloid(test_utils::get_test_causaloid());
+
+    g.add_edge(root_index, idx_a).expect("Failed to add edge");
+    g.add_edge(root_index, idx_b).expect("Failed to add edge");
+    g.add_edge(idx_a, idx_c).expect("Failed to add edge");
+
+    (g, [root_index, idx_a, idx_b, idx_c])
+}
+
+fn evaluated_indices(report: &ReasoningReport) -> Vec<usize> {
+    let mut indices: Vec<usize> = report.evaluated().iter().map(|(i, _)| *i).collect();
+    indices.sort();
+    indices
+}
+
+#[test]
+fn test_reasoning_policy_default() {
+    assert_eq!(ReasoningPolicy::default(), ReasoningPolicy::FailFast);
+    assert_eq!(
+        format!("{}", ReasoningPolicy::SubstituteDefault(true)),
+        "SubstituteDefault(true)"
+    );
+}
+
+#[test]
+fn test_reason_with_policy_fail_fast() {
+    let (g, _) = get_graph_with_failing_node();
+    let data = [0.0, 0.99];
+
+    let err = g
+        .reason_all_causes_with_policy(&data, None, ReasoningPolicy::FailFast)
+        .unwrap_err();
+    assert_eq!(err.kind(), ReasoningErrorKind::Evaluation);
+    assert_eq!(err.causaloid_id(), Some(1));
+    assert!(!err.is_retriable());
+    assert_eq!(
+        err.to_string(),
+        "ReasoningError[E200] Evaluation: Test error (causaloid: 1)"
+    );
+}
+
+#[test]
+fn test_reason_with_policy_fail_fast_causaloid_id() {
+    fn causal_fn(_obs: NumericalValue) -> Result<bool, CausalityError> {
+        Err(CausalityError("Sensor offline".into()))
+    }
+
+    let mut g: BaseCausalGraph = CausaloidGraph::new();
+    let root_index = g.add_root_causaloid(test_utils::get_test_causaloid());
+    let idx_a = g.add_causaloid(Causaloid::new(42, causal_fn, "fails"));
+    g.add_edge(root_index, idx_a).expect("Failed to add edge");
+
+    let data = vec![0.99; 43];
+    let err = g
+        .reason_all_causes_with_policy(&data, None, ReasoningPolicy::FailFast)
+        .unwrap_err();
+    assert_eq!(err.kind(), ReasoningErrorKind::Evaluation);
+    assert_eq!(err.causaloid_id(), Some(42));
+    assert_eq!(err.message(), "Sensor offline");
+}
+
+#[test]
+fn test_reason_with_policy_continues_after_false() {
+    fn causal_fn(obs: NumericalValue) -> Result<bool, CausalityError> {
+        Ok(obs >= 0.55)
+    }
+    let description = "tests whether data exceeds threshold of 0.55";
+
+    // root -> a (false) -> c
+    // root -> b
+    let mut g: BaseCausalGraph = CausaloidGraph::new();
+    let root_index = g.add_root_causaloid(Causaloid::new(0, causal_fn, description));
+    let idx_a = g.add_causaloid(Causaloid::new(1, causal_fn, description));
+    let idx_b = g.add_causaloid(Causaloid::new(2, causal_fn, description));
+    let idx_c = g.add_causaloid(Causaloid::new(3, causal_fn, description));
+    g.add_edge(root_index, idx_a).expect("Failed to add edge");
+    g.add_edge(root_index, idx_b).expect("Failed to add edge");
+    g.add_edge(idx_a, idx_c).expect("Failed to add edge");
+
+    let data = [0.99, 0.1, 0.99, 0.99];
+
+    // The regular methods return false on the first false causaloid.
+    assert!(!g.reason_all_causes(&data, None).unwrap());
+
+    // Reasoning with a policy skips the descendants of the false causaloid
+    // but still evaluates the other branch.
+    let report = g
+        .reason_all_causes_with_policy(&data, None, ReasoningPolicy::FailFast)
+        .expect("Failed to reason");
+    assert!(report.is_complete());
+    assert_eq!(evaluated_indices(&report), vec![root_index, idx_a, idx_b]);
+    assert_eq!(report.effect_of(idx_a), Some(false));
+    assert_eq!(report.effect_of(idx_b), Some(true));
+    assert_eq!(report.effect_of(idx_c), None);
+}
+
+#[test]
+fn test_reason_with_policy_skip_and_log() {
+    let (g, [root_index, idx_a, idx_b, _]) = get_graph_with_failing_node();
+    let data = [0.0, 0.99];
+
+    let report = g
+        .reason_all_causes_with_policy(&data, None, ReasoningPolicy::SkipAndLog)
+        .expect("Failed to reason");
+
+    assert!(*report.result());
+    assert!(!report.is_complete());
+    // c is only reachable through the failing a and is therefore skipped.
+    assert_eq!(evaluated_indices(&report), vec![root_index, idx_b]);
+    assert_eq!(report.failed_indices(), vec![idx_a]);
+
+    let failure = report.failures().first().unwrap();
+    assert_eq!(*failure.index(), idx_a);
+    assert_eq!(failure.causaloid_id(), Some(1));
+    assert_eq!(failure.error().kind(), ReasoningErrorKind::Evaluation);
+    assert_eq!(failure.error().message(), "Test error");
+}
+
+#[test]
+fn test_reason_with_policy_substitute_default_true() {
+    let (g, [root_index, idx_a, idx_b, idx_c]) = get_graph_with_failing_node();
+    let data = [0.0, 0.99];
+
+    let report = g
+        .reason_all_causes_with_policy(&data, None, ReasoningPolicy::SubstituteDefault(true))
+        .expect("Failed to reason");
+
+    assert!(*report.result());
+    assert!(!report.is_complete());
+    assert_eq!(
+        evaluated_indices(&report),
+        vec![root_index, idx_a, idx_b, idx_c]
+    );
+    assert!(report.evaluated().contains(&(idx_a, true)));
+    assert_eq!(report.failed_indices(), vec![idx_a]);
+}
+
+#[test]
+fn test_reason_with_policy_substitute_default_false() {
+    let (g, [root_index, idx_a, idx_b, _]) = get_graph_with_failing_node();
+    let data = [0.0, 0.99];
+
+    let report = g
+        .reason_all_causes_with_policy(&data, None, ReasoningPolicy::SubstituteDefault(false))
+        .expect("Failed to reason");
+
+    assert!(!*report.result());
+    assert_eq!(evaluated_indices(&report), vec![root_index, idx_a, idx_b]);
+    assert!(report.evaluated().contains(&(idx_a, false)));
+}
+
+#[test]
+fn test_reason_with_policy_no_failure() {
+    let (g, data) = test_utils_graph::get_small_multi_cause_graph_and_data();
+
+    let report = g
+        .reason_all_causes_with_policy(&data, None, ReasoningPolicy::SkipAndLog)
+        .expect("Failed to reason");
+
+    assert!(report.is_complete());
+    assert_eq!(
+        *report.result(),
+        g.reason_all_causes(&data, None).expect("Failed to reason")
+    );
+    assert_eq!(report.evaluated().len(), g.number_nodes());
+}
+
+#[test]
+fn test_reason_with_policy_missing_data() {
+    let (g, [root_index, _, _, _]) = get_graph_with_failing_node();
+    // No data at index 1 i.e. the id of all test causaloids.
+    let data = [0.99];
+
+    let res = g.reason_all_causes_with_policy(&data, None, ReasoningPolicy::FailFast);
+    assert!(res.is_err());
+
+    let report = g
+        .reason_all_causes_with_policy(&data, None, ReasoningPolicy::SkipAndLog)
+        .expect("Failed to reason");
+
+    assert!(!*report.result());
+    assert!(report.evaluated().is_empty());
+    assert_eq!(report.failed_indices(), vec![root_index]);
+    assert_eq!(
+        report.failures()[0].error().kind(),
+        ReasoningErrorKind::InvalidData
+    );
+}
+
+#[test]
+fn test_reason_subgraph_with_policy() {
+    let (g, [_, _, idx_b, _]) = get_graph_with_failing_node();
+    let data = [0.0, 0.99];
+
+    let report = g
+        .reason_subgraph_from_cause_with_policy(idx_b, &data, None, ReasoningPolicy::FailFast)
+        .expect("Failed to reason");
+
+    assert!(*report.result());
+    assert!(report.is_complete());
+    assert_eq!(evaluated_indices(&report), vec![idx_b]);
+    assert_eq!(
+        format!("{}", report),
+        "ReasoningReport: result: true, evaluated: 1, failed: 0"
+    );
+}
+
+#[test]
+fn test_reason_with_policy_error() {
+    let g: BaseCausalGraph = CausaloidGraph::new();
+    let data = [0.99];
+
+    let res = g.reason_all_causes_with_policy(&data, None, ReasoningPolicy::SkipAndLog);
+    assert!(res.is_err());
+
+    let res = g.reason_subgraph_from_cause_with_policy(0, &data, None, ReasoningPolicy::SkipAndLog);
+    assert!(res.is_err());
+
+    let (g, _) = get_graph_with_failing_node();
+    let res = g.reason_all_causes_with_policy(&[], None, ReasoningPolicy::SkipAndLog);
+    assert!(res.is_err());
+
+    let res =
+        g.reason_subgraph_from_cause_with_policy(42, &data, None, ReasoningPolicy::SkipAndLog);
+    assert!(res.is_err());
+}
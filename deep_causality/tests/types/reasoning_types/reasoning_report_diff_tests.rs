@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+fn get_factual_report() -> ReasoningReport {
+    ReasoningReport::new(true, vec![(0, true), (1, true), (2, true)], Vec::new())
+}
+
+#[test]
+fn test_diff_equal() {
+    let factual = get_factual_report();
+    let diff = factual.diff(&get_factual_report());
+
+    assert!(diff.is_empty());
+    assert!(!diff.result_changed());
+    assert!(diff.deltas().is_empty());
+    assert!(diff.first_divergence().is_none());
+    assert_eq!(diff.explain(), "Result unchanged: true");
+}
+
+#[test]
+fn test_diff_effect_changed() {
+    let factual = get_factual_report();
+    let counterfactual =
+        ReasoningReport::new(false, vec![(0, true), (2, false), (1, false)], Vec::new());
+
+    let diff = factual.diff(&counterfactual);
+    assert!(!diff.is_empty());
+    assert!(diff.result_changed());
+    assert_eq!(
+        diff.deltas(),
+        &vec![
+            EffectDelta::new(1, Some(true), Some(false)),
+            EffectDelta::new(2, Some(true), Some(false)),
+        ]
+    );
+    // Follows the evaluation order of the factual run.
+    assert_eq!(
+        diff.first_divergence(),
+        &Some(EffectDelta::new(1, Some(true), Some(false)))
+    );
+
+    let expected = "Result changed from true to false\n\
+        Causaloid at index 1 evaluated to true in the first run but evaluated to false in the second run\n\
+        Causaloid at index 2 evaluated to true in the first run but evaluated to false in the second run";
+    assert_eq!(diff.explain(), expected);
+}
+
+#[test]
+fn test_diff_not_evaluated_and_failed() {
+    let factual = get_factual_report();
+    let error = ReasoningError::new(ReasoningErrorKind::InvalidData, "NaN");
+    let counterfactual = ReasoningReport::new(
+        true,
+        vec![(0, true), (2, true)],
+        vec![NodeFailure::new(1, error)],
+    );
+
+    let diff = counterfactual.diff(&factual);
+    assert!(!diff.result_changed());
+    assert_eq!(diff.deltas().len(), 1);
+
+    let expected = "Result unchanged: true\n\
+        Causaloid at index 1 failed with ReasoningError[E100] InvalidData: NaN in the first run but evaluated to true in the second run";
+    assert_eq!(diff.explain(), expected);
+
+    let factual = ReasoningReport::new(false, vec![(0, false)], Vec::new());
+    let diff = factual.diff(&get_factual_report());
+    assert_eq!(
+        diff.first_divergence(),
+        &Some(EffectDelta::new(0, Some(false), Some(true)))
+    );
+    assert!(diff
+        .explain()
+        .contains("Causaloid at index 2 was not evaluated in the first run"));
+}
+
+#[test]
+fn test_diff_display() {
+    let factual = get_factual_report();
+    let counterfactual = ReasoningReport::new(false, vec![(0, false)], Vec::new());
+
+    let diff = factual.diff(&counterfactual);
+    assert_eq!(
+        format!("{}", diff),
+        "ReasoningReportDiff: result: true -> false, deltas: 3"
+    );
+}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::escape_dot_label;
+
+#[test]
+fn test_escape_dot_label() {
+    assert_eq!(escape_dot_label("plain label"), "plain label");
+    assert_eq!(escape_dot_label("say \"hi\""), "say \\\"hi\\\"");
+    assert_eq!(escape_dot_label("a\\b"), "a\\\\b");
+    assert_eq!(escape_dot_label("line\r\nbreak"), "line\\nbreak");
+}
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::hash::Hasher;
+
+use deep_causality::prelude::{fingerprint, FingerprintHasher};
+
+#[test]
+fn test_fingerprint_hasher_empty() {
+    let hasher = FingerprintHasher::default();
+    assert_eq!(hasher.finish(), 0xcbf2_9ce4_8422_2325);
+}
+
+#[test]
+fn test_fingerprint_hasher_fnv1a_vectors() {
+    let mut hasher = FingerprintHasher::default();
+    hasher.write(b"a");
+    assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+
+    let mut hasher = FingerprintHasher::default();
+    hasher.write(b"foobar");
+    assert_eq!(hasher.finish(), 0x8594_4171_f739_67e8);
+}
+
+#[test]
+fn test_fingerprint_hasher_integers_little_endian() {
+    let mut a = FingerprintHasher::default();
+    a.write_u64(42);
+
+    let mut b = FingerprintHasher::default();
+    b.write(&42u64.to_le_bytes());
+    assert_eq!(a.finish(), b.finish());
+
+    // usize is widened to u64 on all platforms.
+    let mut c = FingerprintHasher::default();
+    c.write_usize(42);
+    assert_eq!(a.finish(), c.finish());
+}
+
+#[test]
+fn test_fingerprint_hasher_field_encoding() {
+    // Lengths are 64 bit little endian on all platforms.
+    let mut a = FingerprintHasher::default();
+    a.write_len(3);
+    let mut b = FingerprintHasher::default();
+    b.write_u64(3);
+    assert_eq!(a.finish(), b.finish());
+
+    // Text is prefixed by its length.
+    let mut hasher = FingerprintHasher::default();
+    hasher.write_text("abc");
+    assert_eq!(hasher.finish(), 0xc11a_b6d2_519b_c2b2);
+
+    // Options are prefixed by a tag byte.
+    let mut hasher = FingerprintHasher::default();
+    hasher.write_option_u64(None);
+    assert_eq!(hasher.finish(), 0xaf63_bd4c_8601_b7df);
+
+    let mut hasher = FingerprintHasher::default();
+    hasher.write_option_u64(Some(7));
+    assert_eq!(hasher.finish(), 0x339f_65d3_8505_e98b);
+
+    let mut hasher = FingerprintHasher::default();
+    hasher.write_option_text(None);
+    assert_eq!(hasher.finish(), 0xaf63_bd4c_8601_b7df);
+
+    let mut hasher = FingerprintHasher::default();
+    hasher.write_option_text(Some("abc"));
+    assert_eq!(hasher.finish(), 0x26e8_4f23_f6be_2601);
+}
+
+#[test]
+fn test_fingerprint() {
+    assert_eq!(fingerprint(b"model"), 0x9de5_4393_3e6e_703a);
+    assert_ne!(fingerprint(b"model"), fingerprint(b"other model"));
+
+    let mut hasher = FingerprintHasher::default();
+    hasher.write(b"model");
+    assert_eq!(fingerprint(b"model"), hasher.finish());
+}
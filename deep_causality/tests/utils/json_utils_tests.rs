@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::{number_json, quote_json};
+
+#[test]
+fn test_quote_json() {
+    assert_eq!(quote_json("plain"), "\"plain\"");
+    assert_eq!(quote_json("say \"hi\""), "\"say \\\"hi\\\"\"");
+    assert_eq!(quote_json("a\\b"), "\"a\\\\b\"");
+    assert_eq!(quote_json("line\r\n\tbreak"), "\"line\\r\\n\\tbreak\"");
+    assert_eq!(quote_json("\u{1}"), "\"\\u0001\"");
+}
+
+#[test]
+fn test_number_json() {
+    assert_eq!(number_json(0.5), "0.5");
+    assert_eq!(number_json(-2.0), "-2");
+    assert_eq!(number_json(f64::NAN), "null");
+    assert_eq!(number_json(f64::INFINITY), "null");
+}
@@ -1,8 +1,17 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 #[cfg(test)]
+mod dot_utils_tests;
+#[cfg(test)]
+mod fingerprint_utils_tests;
+#[cfg(test)]
+mod json_utils_tests;
+#[cfg(test)]
 mod math_utils_tests;
+#[cfg(test)]
+mod resample_utils_tests;
 pub mod test_utils;
+pub mod test_utils_async;
 pub mod test_utils_graph;
 #[cfg(test)]
 mod time_utils_tests;
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use deep_causality::prelude::*;
+
+fn get_samples() -> Vec<(u64, f64)> {
+    vec![(10, 1.0), (15, 2.0), (30, 4.0), (35, 6.0)]
+}
+
+#[test]
+fn test_grid_times() {
+    assert_eq!(grid_times(10, 5, 3), vec![10, 15, 20]);
+    assert!(grid_times(10, 5, 0).is_empty());
+}
+
+#[test]
+fn test_resample_forward_fill() {
+    let values = resample(&get_samples(), 5, 10, 4, ResampleMethod::ForwardFill);
+    assert_eq!(values, vec![None, Some(2.0), Some(2.0), Some(6.0)]);
+}
+
+#[test]
+fn test_resample_linear() {
+    let values = resample(&get_samples(), 10, 10, 4, ResampleMethod::Linear);
+    assert_eq!(values, vec![Some(1.0), Some(8.0 / 3.0), Some(4.0), None]);
+}
+
+#[test]
+fn test_resample_aggregation() {
+    let samples = get_samples();
+
+    let mean = resample(&samples, 10, 10, 3, ResampleMethod::Mean);
+    assert_eq!(mean, vec![Some(1.5), None, Some(5.0)]);
+
+    let min = resample(&samples, 10, 10, 3, ResampleMethod::Min);
+    assert_eq!(min, vec![Some(1.0), None, Some(4.0)]);
+
+    let max = resample(&samples, 10, 10, 3, ResampleMethod::Max);
+    assert_eq!(max, vec![Some(2.0), None, Some(6.0)]);
+
+    let last = resample(&samples, 10, 10, 3, ResampleMethod::Last);
+    assert_eq!(last, vec![Some(2.0), None, Some(6.0)]);
+}
+
+#[test]
+fn test_resample_zero_step() {
+    assert!(resample(&get_samples(), 10, 0, 3, ResampleMethod::ForwardFill).is_empty());
+    assert!(resample(&[], 10, 5, 3, ResampleMethod::Mean)
+        .iter()
+        .all(|v| v.is_none()));
+}
+
+#[test]
+fn test_align_series() {
+    let fast = get_samples();
+    let slow = vec![(20, 100.0), (40, 200.0)];
+
+    let rows = align_series(&[&fast, &slow], 10, 10, 4, ResampleMethod::ForwardFill);
+    assert_eq!(
+        rows,
+        vec![
+            (20, vec![2.0, 100.0]),
+            (30, vec![4.0, 100.0]),
+            (40, vec![6.0, 200.0])
+        ]
+    );
+
+    let rows = align_series(&[&fast, &slow], 10, 0, 4, ResampleMethod::ForwardFill);
+    assert!(rows.is_empty());
+}
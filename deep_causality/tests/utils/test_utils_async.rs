@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+use deep_causality::prelude::*;
+
+// Minimal executor to test async causal functions without an async runtime.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        fn noop(_: *const ()) {}
+
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+pub fn get_test_async_causaloid<'l>() -> BaseCausaloid<'l> {
+    let id: IdentificationValue = 1;
+    let description = "tests asynchronously whether data exceeds threshold of 0.55";
+
+    fn async_causal_fn(obs: NumericalValue) -> CausalFuture {
+        Box::pin(async move {
+            if obs.is_nan() {
+                return Err(CausalityError("Observation is NULL/NAN".into()));
+            }
+
+            Ok(obs.ge(&0.55))
+        })
+    }
+
+    Causaloid::new_async(id, async_causal_fn, description)
+}
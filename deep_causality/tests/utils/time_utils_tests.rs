@@ -1,7 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 
-use deep_causality::prelude::time_execution;
+use std::collections::BTreeMap;
+
+use deep_causality::prelude::{
+    align_times, downsample_times, time_execution, upsample_time, Time, TimeScale,
+};
 
 #[test]
 fn test_time() {
@@ -11,3 +15,50 @@ fn test_time() {
 fn run() {
     println!("Hello Run")
 }
+
+#[test]
+fn test_downsample_times() {
+    let times = vec![
+        Time::new(1, TimeScale::Millisecond, 200u64),
+        Time::new(2, TimeScale::Millisecond, 1_200),
+        Time::new(3, TimeScale::Microsecond, 900_000),
+        Time::new(4, TimeScale::Second, 3),
+    ];
+
+    let buckets = downsample_times(&times, TimeScale::Second).unwrap();
+    let expected = BTreeMap::from([(0, vec![0, 2]), (1, vec![1]), (3, vec![3])]);
+    assert_eq!(buckets, expected);
+
+    assert!(downsample_times(&times, TimeScale::Millisecond).is_none());
+}
+
+#[test]
+fn test_upsample_time() {
+    let time = Time::new(1, TimeScale::Second, 2u64);
+    assert_eq!(
+        upsample_time(&time, TimeScale::Millisecond),
+        Some((2_000, 3_000))
+    );
+    assert_eq!(upsample_time(&time, TimeScale::Minute), None);
+}
+
+#[test]
+fn test_align_times() {
+    let telemetry = vec![
+        Time::new(1, TimeScale::Millisecond, 100u64),
+        Time::new(2, TimeScale::Millisecond, 3_700_000),
+        Time::new(3, TimeScale::Millisecond, 7_300_000),
+        Time::new(4, TimeScale::Millisecond, 90_000_000),
+    ];
+    // Hour 0 and hour 2, plus minute 61 which lies inside hour 1.
+    let events = vec![
+        Time::new(10, TimeScale::Hour, 0u64),
+        Time::new(11, TimeScale::Minute, 61),
+        Time::new(12, TimeScale::Hour, 2),
+    ];
+
+    assert_eq!(
+        align_times(&telemetry, &events),
+        vec![Some(0), Some(1), Some(2), None]
+    );
+}
@@ -8,6 +8,8 @@ pub use crate::errors::UltraGraphError;
 // Protocols
 pub use crate::protocols::graph_algorithms::GraphAlgorithms;
 pub use crate::protocols::graph_like::GraphLike;
+pub use crate::protocols::graph_metrics::GraphMetrics;
+pub use crate::protocols::graph_partitions::GraphPartitions;
 pub use crate::protocols::graph_root::GraphRoot;
 pub use crate::protocols::graph_storage::GraphStorage;
 // Storage implementation
@@ -11,4 +11,25 @@ pub trait GraphAlgorithms<T>: GraphLike<T> {
 
     /// Returns all nodes with an outgoing edge starting from a.
     fn outgoing_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError>;
+
+    /// Returns a new graph with the same nodes and an edge from a to b
+    /// for every node b reachable from node a.
+    ///
+    /// Existing edges keep their weight; added edges have a weight of zero.
+    /// Self-loops are not added, even for nodes on a cycle.
+    fn transitive_closure(&self) -> Self
+    where
+        Self: Sized,
+        T: Clone;
+
+    /// Returns a new graph with the same nodes and reachability, but with the
+    /// minimal set of edges, that is without every edge a -> b for which another path
+    /// from a to b exists.
+    ///
+    /// Returns an error if the graph contains a cycle because the transitive reduction
+    /// is only unique for directed acyclic graphs.
+    fn transitive_reduction(&self) -> Result<Self, UltraGraphError>
+    where
+        Self: Sized,
+        T: Clone;
 }
@@ -12,6 +12,8 @@ pub trait GraphLike<T> {
 
     fn remove_node(&mut self, index: usize) -> Result<(), UltraGraphError>;
 
+    fn update_node(&mut self, index: usize, value: T) -> Result<(), UltraGraphError>;
+
     fn add_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError>;
 
     fn add_edge_with_weight(
@@ -23,5 +25,7 @@ pub trait GraphLike<T> {
 
     fn contains_edge(&self, a: usize, b: usize) -> bool;
 
+    fn get_edge_weight(&self, a: usize, b: usize) -> Option<u64>;
+
     fn remove_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError>;
 }
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+use std::collections::BTreeMap;
+
+use crate::errors::UltraGraphError;
+use crate::prelude::GraphLike;
+
+/// Structural metrics of a directed graph.
+///
+/// All maps are keyed by node index and ordered by it so that results are deterministic.
+/// Edge weights are ignored; every edge counts as one hop.
+pub trait GraphMetrics<T>: GraphLike<T> {
+    /// Returns the number of edges ending in the node.
+    fn in_degree(&self, index: usize) -> Result<usize, UltraGraphError>;
+
+    /// Returns the number of edges starting from the node.
+    fn out_degree(&self, index: usize) -> Result<usize, UltraGraphError>;
+
+    /// Returns how many nodes have a given in-degree, keyed by in-degree.
+    fn in_degree_distribution(&self) -> BTreeMap<usize, usize>;
+
+    /// Returns how many nodes have a given out-degree, keyed by out-degree.
+    fn out_degree_distribution(&self) -> BTreeMap<usize, usize>;
+
+    /// Returns the betweenness centrality of each node, that is the number of
+    /// shortest paths between other nodes that pass through the node.
+    /// If normalized, values are divided by (n-1)(n-2), the number of ordered node pairs
+    /// excluding the node itself.
+    fn betweenness_centrality(&self, normalized: bool) -> BTreeMap<usize, f64>;
+
+    /// Returns the closeness centrality of each node following outgoing edges.
+    ///
+    /// Uses the Wasserman-Faust variant that scales by the share of reachable nodes
+    /// so that nodes in different components remain comparable.
+    /// Nodes without reachable nodes have a closeness of zero.
+    fn closeness_centrality(&self) -> BTreeMap<usize, f64>;
+
+    /// Returns the PageRank of each node.
+    ///
+    /// Iterates until the sum of absolute changes falls below the tolerance
+    /// or max_iterations is reached. The rank of nodes without outgoing edges
+    /// is distributed evenly across all nodes. Ranks sum up to one.
+    ///
+    /// Returns an error if the damping factor is not in the interval [0, 1].
+    fn page_rank(
+        &self,
+        damping: f64,
+        max_iterations: usize,
+        tolerance: f64,
+    ) -> Result<BTreeMap<usize, f64>, UltraGraphError>;
+}
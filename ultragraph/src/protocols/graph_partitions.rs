@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::GraphLike;
+
+/// Node partitions for bipartite and multipartite graphs.
+///
+/// A partition is identified by a usize, i.e. 0 for causes and 1 for evidence.
+/// Partition membership is stored in the graph, not in the node payload.
+/// A graph is multipartite if every node belongs to a partition
+/// and no edge connects two nodes of the same partition.
+pub trait GraphPartitions<T>: GraphLike<T> {
+    /// Adds a node to the partition and returns its index.
+    fn add_node_with_partition(&mut self, value: T, partition: usize) -> usize;
+
+    /// Assigns an existing node to the partition, replacing any previous partition.
+    fn set_partition(&mut self, index: usize, partition: usize) -> Result<(), UltraGraphError>;
+
+    /// Returns the partition of the node, if the node exists and has a partition.
+    fn get_partition(&self, index: usize) -> Option<usize>;
+
+    /// Returns the indices of all nodes of the partition in ascending order.
+    fn get_partition_nodes(&self, partition: usize) -> Vec<usize>;
+
+    /// Returns all partitions that contain at least one node in ascending order.
+    fn get_all_partitions(&self) -> Vec<usize>;
+
+    /// Adds an edge between two nodes of different partitions.
+    ///
+    /// Returns an error if either node does not exist, has no partition,
+    /// or both nodes belong to the same partition.
+    fn add_partition_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError>;
+
+    /// Returns true if every node has a partition and
+    /// no edge connects two nodes of the same partition.
+    fn is_multipartite(&self) -> bool;
+
+    /// Returns a new graph with only the nodes of the partition and an edge from a to b
+    /// for every path a -> x -> b of length two that passes through a node x of another partition.
+    ///
+    /// Node indices, payloads, and partitions are preserved. Edges of the projection have a weight of zero.
+    /// The root index is kept only if the root node belongs to the partition.
+    ///
+    /// Returns an error if the partition contains no node.
+    fn project_partition(&self, partition: usize) -> Result<Self, UltraGraphError>
+    where
+        Self: Sized,
+        T: Clone;
+}
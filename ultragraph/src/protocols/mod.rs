@@ -5,5 +5,7 @@
 
 pub mod graph_algorithms;
 pub mod graph_like;
+pub mod graph_metrics;
+pub mod graph_partitions;
 pub mod graph_root;
 pub mod graph_storage;
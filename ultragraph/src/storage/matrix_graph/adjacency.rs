@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::prelude::UltraMatrixGraph;
+use crate::storage::matrix_graph::NodeIndex;
+
+// Dense adjacency lists over the nodes sorted by index.
+// Positions in `nodes` are used as compact node ids in `outgoing`.
+pub(super) struct Adjacency {
+    pub(super) nodes: Vec<usize>,
+    pub(super) outgoing: Vec<Vec<usize>>,
+}
+
+impl<T> UltraMatrixGraph<T> {
+    pub(super) fn adjacency(&self) -> Adjacency {
+        let mut nodes: Vec<usize> = self.index_map.keys().copied().collect();
+        nodes.sort_unstable();
+
+        let position: BTreeMap<usize, usize> =
+            nodes.iter().enumerate().map(|(p, i)| (*i, p)).collect();
+
+        let outgoing = nodes
+            .iter()
+            .map(|i| {
+                let mut targets: Vec<usize> = self
+                    .graph
+                    .neighbors(NodeIndex::new(*i))
+                    .map(|n| position[&n.index()])
+                    .collect();
+                targets.sort_unstable();
+                targets
+            })
+            .collect();
+
+        Adjacency { nodes, outgoing }
+    }
+}
+
+impl Adjacency {
+    pub(super) fn in_degrees(&self) -> Vec<usize> {
+        let mut in_degrees = vec![0; self.nodes.len()];
+        for targets in &self.outgoing {
+            for t in targets {
+                in_degrees[*t] += 1;
+            }
+        }
+        in_degrees
+    }
+
+    // Breadth first search from the source. Returns the distance to each node,
+    // the number of shortest paths to each node, the predecessors on shortest paths,
+    // and the nodes in order of non-decreasing distance.
+    #[allow(clippy::type_complexity)]
+    pub(super) fn shortest_paths(
+        &self,
+        source: usize,
+    ) -> (Vec<Option<usize>>, Vec<f64>, Vec<Vec<usize>>, Vec<usize>) {
+        let n = self.nodes.len();
+        let mut distance: Vec<Option<usize>> = vec![None; n];
+        let mut paths = vec![0.0; n];
+        let mut predecessors = vec![Vec::new(); n];
+        let mut order = Vec::with_capacity(n);
+
+        distance[source] = Some(0);
+        paths[source] = 1.0;
+
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            let dv = distance[v].expect("visited nodes have a distance");
+            for &w in &self.outgoing[v] {
+                if distance[w].is_none() {
+                    distance[w] = Some(dv + 1);
+                    queue.push_back(w);
+                }
+                if distance[w] == Some(dv + 1) {
+                    paths[w] += paths[v];
+                    predecessors[w].push(v);
+                }
+            }
+        }
+
+        (distance, paths, predecessors, order)
+    }
+
+    // Returns for each node whether it is reachable from the source via at least one edge.
+    pub(super) fn reachable(&self, source: usize) -> Vec<bool> {
+        let mut reachable = vec![false; self.nodes.len()];
+        let mut stack: Vec<usize> = self.outgoing[source].clone();
+
+        while let Some(v) = stack.pop() {
+            if !reachable[v] {
+                reachable[v] = true;
+                stack.extend(&self.outgoing[v]);
+            }
+        }
+
+        reachable
+    }
+
+    // Kahn's algorithm: the graph is acyclic if all nodes can be sorted topologically.
+    pub(super) fn is_acyclic(&self) -> bool {
+        let mut in_degrees = self.in_degrees();
+        let mut ready: Vec<usize> = (0..self.nodes.len())
+            .filter(|v| in_degrees[*v] == 0)
+            .collect();
+
+        let mut sorted = 0;
+        while let Some(v) = ready.pop() {
+            sorted += 1;
+            for &w in &self.outgoing[v] {
+                in_degrees[w] -= 1;
+                if in_degrees[w] == 0 {
+                    ready.push(w);
+                }
+            }
+        }
+
+        sorted == self.nodes.len()
+    }
+
+    pub(super) fn into_map<V>(self, values: Vec<V>) -> BTreeMap<usize, V> {
+        self.nodes.into_iter().zip(values).collect()
+    }
+}
@@ -53,4 +53,58 @@ impl<T> GraphAlgorithms<T> for UltraMatrixGraph<T> {
 
         Ok(result.into_iter())
     }
+
+    fn transitive_closure(&self) -> Self
+    where
+        T: Clone,
+    {
+        let adjacency = self.adjacency();
+        let mut closure = self.clone();
+
+        for (a, node) in adjacency.nodes.iter().enumerate() {
+            for (b, reachable) in adjacency.reachable(a).into_iter().enumerate() {
+                if reachable && a != b {
+                    let (k, l) = (NodeIndex::new(*node), NodeIndex::new(adjacency.nodes[b]));
+                    if !closure.graph.has_edge(k, l) {
+                        closure.graph.add_edge(k, l, 0);
+                    }
+                }
+            }
+        }
+
+        closure
+    }
+
+    fn transitive_reduction(&self) -> Result<Self, UltraGraphError>
+    where
+        T: Clone,
+    {
+        let adjacency = self.adjacency();
+        if !adjacency.is_acyclic() {
+            return Err(UltraGraphError(
+                "transitive reduction requires an acyclic graph".into(),
+            ));
+        }
+
+        let reachable: Vec<Vec<bool>> = (0..adjacency.nodes.len())
+            .map(|v| adjacency.reachable(v))
+            .collect();
+
+        let mut reduction = self.clone();
+
+        for (a, targets) in adjacency.outgoing.iter().enumerate() {
+            for &b in targets {
+                // The edge a -> b is redundant if b is reachable through another child of a.
+                let redundant = targets.iter().any(|&c| c != b && reachable[c][b]);
+                if redundant {
+                    reduction.graph.remove_edge(
+                        NodeIndex::new(adjacency.nodes[a]),
+                        NodeIndex::new(adjacency.nodes[b]),
+                    );
+                }
+            }
+        }
+
+        Ok(reduction)
+    }
 }
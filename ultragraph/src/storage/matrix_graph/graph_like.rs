@@ -36,6 +36,17 @@ impl<T> GraphLike<T> for UltraMatrixGraph<T> {
         self.graph.remove_node(*k);
         self.node_map.remove(k);
         self.index_map.remove(&k.index());
+        self.partition_map.remove(&index);
+        Ok(())
+    }
+
+    fn update_node(&mut self, index: usize, value: T) -> Result<(), UltraGraphError> {
+        if !self.contains_node(index) {
+            return Err(UltraGraphError(format!("index {} not found", index)));
+        };
+
+        let k = self.index_map.get(&index).unwrap();
+        self.node_map.insert(*k, value);
         Ok(())
     }
 
@@ -98,6 +109,16 @@ impl<T> GraphLike<T> for UltraMatrixGraph<T> {
         self.graph.has_edge(*k, *l)
     }
 
+    fn get_edge_weight(&self, a: usize, b: usize) -> Option<u64> {
+        if !self.contains_edge(a, b) {
+            return None;
+        };
+
+        let k = self.index_map.get(&a).expect("index not found");
+        let l = self.index_map.get(&b).expect("index not found");
+        Some(*self.graph.edge_weight(*k, *l))
+    }
+
     fn remove_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
         if !self.contains_node(a) {
             return Err(UltraGraphError("index a not found".into()));
@@ -118,8 +139,6 @@ impl<T> GraphLike<T> for UltraMatrixGraph<T> {
         let l = self.index_map.get(&b).expect("index not found");
 
         self.graph.remove_edge(*k, *l);
-        self.index_map.remove(&a);
-        self.index_map.remove(&b);
 
         Ok(())
     }
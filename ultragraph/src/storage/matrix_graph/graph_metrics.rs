@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeMap;
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphLike, GraphMetrics, UltraMatrixGraph};
+use crate::storage::matrix_graph::NodeIndex;
+
+fn distribution(degrees: Vec<usize>) -> BTreeMap<usize, usize> {
+    let mut distribution = BTreeMap::new();
+    for d in degrees {
+        *distribution.entry(d).or_insert(0) += 1;
+    }
+    distribution
+}
+
+impl<T> GraphMetrics<T> for UltraMatrixGraph<T> {
+    fn in_degree(&self, index: usize) -> Result<usize, UltraGraphError> {
+        if !self.contains_node(index) {
+            return Err(UltraGraphError(format!("index {} not found", index)));
+        };
+
+        let degree = self
+            .index_map
+            .values()
+            .filter(|k| self.graph.has_edge(**k, NodeIndex::new(index)))
+            .count();
+
+        Ok(degree)
+    }
+
+    fn out_degree(&self, index: usize) -> Result<usize, UltraGraphError> {
+        if !self.contains_node(index) {
+            return Err(UltraGraphError(format!("index {} not found", index)));
+        };
+
+        Ok(self.graph.neighbors(NodeIndex::new(index)).count())
+    }
+
+    fn in_degree_distribution(&self) -> BTreeMap<usize, usize> {
+        distribution(self.adjacency().in_degrees())
+    }
+
+    fn out_degree_distribution(&self) -> BTreeMap<usize, usize> {
+        distribution(self.adjacency().outgoing.iter().map(Vec::len).collect())
+    }
+
+    fn betweenness_centrality(&self, normalized: bool) -> BTreeMap<usize, f64> {
+        // Brandes, U. (2001). A faster algorithm for betweenness centrality.
+        let adjacency = self.adjacency();
+        let n = adjacency.nodes.len();
+        let mut centrality = vec![0.0; n];
+
+        for s in 0..n {
+            let (_, paths, predecessors, order) = adjacency.shortest_paths(s);
+
+            let mut dependency = vec![0.0; n];
+            for &w in order.iter().rev() {
+                for &v in &predecessors[w] {
+                    dependency[v] += paths[v] / paths[w] * (1.0 + dependency[w]);
+                }
+                if w != s {
+                    centrality[w] += dependency[w];
+                }
+            }
+        }
+
+        if normalized && n > 2 {
+            let scale = ((n - 1) * (n - 2)) as f64;
+            centrality.iter_mut().for_each(|c| *c /= scale);
+        }
+
+        adjacency.into_map(centrality)
+    }
+
+    fn closeness_centrality(&self) -> BTreeMap<usize, f64> {
+        let adjacency = self.adjacency();
+        let n = adjacency.nodes.len();
+
+        let closeness = (0..n)
+            .map(|s| {
+                let (distance, _, _, _) = adjacency.shortest_paths(s);
+                let reachable: Vec<usize> = distance.iter().flatten().copied().collect();
+
+                // The source itself is reachable with distance zero.
+                let r = reachable.len() - 1;
+                let total: usize = reachable.iter().sum();
+                if total == 0 {
+                    return 0.0;
+                }
+
+                (r as f64 / (n - 1) as f64) * (r as f64 / total as f64)
+            })
+            .collect();
+
+        adjacency.into_map(closeness)
+    }
+
+    fn page_rank(
+        &self,
+        damping: f64,
+        max_iterations: usize,
+        tolerance: f64,
+    ) -> Result<BTreeMap<usize, f64>, UltraGraphError> {
+        if !(0.0..=1.0).contains(&damping) {
+            return Err(UltraGraphError(format!(
+                "damping factor {} is out of range [0, 1]",
+                damping
+            )));
+        }
+
+        let adjacency = self.adjacency();
+        let n = adjacency.nodes.len();
+        if n == 0 {
+            return Ok(BTreeMap::new());
+        }
+
+        let nf = n as f64;
+        let mut rank = vec![1.0 / nf; n];
+
+        for _ in 0..max_iterations {
+            let dangling: f64 = (0..n)
+                .filter(|v| adjacency.outgoing[*v].is_empty())
+                .map(|v| rank[v])
+                .sum();
+
+            let base = (1.0 - damping) / nf + damping * dangling / nf;
+            let mut next = vec![base; n];
+            for (v, targets) in adjacency.outgoing.iter().enumerate() {
+                if targets.is_empty() {
+                    continue;
+                }
+                let share = damping * rank[v] / targets.len() as f64;
+                for &w in targets {
+                    next[w] += share;
+                }
+            }
+
+            let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+            rank = next;
+            if change < tolerance {
+                break;
+            }
+        }
+
+        Ok(adjacency.into_map(rank))
+    }
+}
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::errors::UltraGraphError;
+use crate::prelude::{GraphLike, GraphPartitions, GraphStorage, UltraMatrixGraph};
+use crate::storage::matrix_graph::NodeIndex;
+
+impl<T> GraphPartitions<T> for UltraMatrixGraph<T> {
+    fn add_node_with_partition(&mut self, value: T, partition: usize) -> usize {
+        let index = self.add_node(value);
+        self.partition_map.insert(index, partition);
+        index
+    }
+
+    fn set_partition(&mut self, index: usize, partition: usize) -> Result<(), UltraGraphError> {
+        if !self.contains_node(index) {
+            return Err(UltraGraphError(format!("index {} not found", index)));
+        };
+
+        self.partition_map.insert(index, partition);
+        Ok(())
+    }
+
+    fn get_partition(&self, index: usize) -> Option<usize> {
+        self.partition_map.get(&index).copied()
+    }
+
+    fn get_partition_nodes(&self, partition: usize) -> Vec<usize> {
+        let mut nodes: Vec<usize> = self
+            .partition_map
+            .iter()
+            .filter(|(_, p)| **p == partition)
+            .map(|(i, _)| *i)
+            .collect();
+        nodes.sort_unstable();
+        nodes
+    }
+
+    fn get_all_partitions(&self) -> Vec<usize> {
+        let mut partitions: Vec<usize> = self.partition_map.values().copied().collect();
+        partitions.sort_unstable();
+        partitions.dedup();
+        partitions
+    }
+
+    fn add_partition_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
+        if !self.contains_node(a) {
+            return Err(UltraGraphError(format!("index a {} not found", a)));
+        };
+
+        if !self.contains_node(b) {
+            return Err(UltraGraphError(format!("index b {} not found", b)));
+        };
+
+        let pa = self
+            .get_partition(a)
+            .ok_or_else(|| UltraGraphError(format!("index a {} has no partition", a)))?;
+
+        let pb = self
+            .get_partition(b)
+            .ok_or_else(|| UltraGraphError(format!("index b {} has no partition", b)))?;
+
+        if pa == pb {
+            return Err(UltraGraphError(format!(
+                "Edge between {} and {} within the same partition {}",
+                a, b, pa
+            )));
+        }
+
+        self.add_edge(a, b)
+    }
+
+    fn is_multipartite(&self) -> bool {
+        if self.partition_map.len() != self.index_map.len() {
+            return false;
+        }
+
+        self.get_all_edges()
+            .iter()
+            .all(|(a, b)| self.get_partition(*a) != self.get_partition(*b))
+    }
+
+    fn project_partition(&self, partition: usize) -> Result<Self, UltraGraphError>
+    where
+        T: Clone,
+    {
+        let nodes = self.get_partition_nodes(partition);
+        if nodes.is_empty() {
+            return Err(UltraGraphError(format!(
+                "partition {} contains no node",
+                partition
+            )));
+        }
+
+        let mut edges = Vec::new();
+        for &a in &nodes {
+            for x in self.graph.neighbors(NodeIndex::new(a)) {
+                if self.get_partition(x.index()) == Some(partition) {
+                    continue;
+                }
+                for b in self.graph.neighbors(x) {
+                    let b = b.index();
+                    if b != a && self.get_partition(b) == Some(partition) {
+                        edges.push((a, b));
+                    }
+                }
+            }
+        }
+
+        let mut projection = self.clone();
+        for (a, b) in self.get_all_edges() {
+            projection
+                .graph
+                .remove_edge(NodeIndex::new(a), NodeIndex::new(b));
+        }
+
+        let mut others: Vec<usize> = self.index_map.keys().copied().collect();
+        others.retain(|i| self.get_partition(*i) != Some(partition));
+        for index in others {
+            projection.remove_node(index)?;
+        }
+
+        if let Some(root) = projection.root_index {
+            if !projection.contains_node(root.index()) {
+                projection.root_index = None;
+            }
+        }
+
+        for (a, b) in edges {
+            if !projection.contains_edge(a, b) {
+                projection.add_edge(a, b)?;
+            }
+        }
+
+        Ok(projection)
+    }
+}
@@ -48,6 +48,7 @@ impl<T> GraphStorage<T> for UltraMatrixGraph<T> {
         self.graph.clear();
         self.node_map.clear();
         self.index_map.clear();
+        self.partition_map.clear();
         self.root_index = None;
     }
 }
@@ -6,9 +6,12 @@ use petgraph::graph::NodeIndex as GraphNodeIndex;
 use petgraph::matrix_graph::MatrixGraph;
 use petgraph::Directed;
 
+mod adjacency;
 mod default;
 mod graph_algorithms;
 mod graph_like;
+mod graph_metrics;
+mod graph_partitions;
 mod graph_root;
 mod graph_storage;
 
@@ -53,12 +56,17 @@ type NodeMap<T> = AHashMap<NodeIndex, T>;
 // set_root_index(). If root index is not set, then get_root_index() will return None.
 type RootIndex = Option<NodeIndex>;
 
+// PartitionMap maps the usize index used in the public API to the partition of the node.
+// Nodes added without a partition have no entry.
+type PartitionMap = AHashMap<usize, usize>;
+
 #[derive(Clone)]
 pub struct UltraMatrixGraph<T> {
     root_index: RootIndex,
     graph: HyperGraph<bool>,
     node_map: NodeMap<T>,
     index_map: IndexMap,
+    partition_map: PartitionMap,
 }
 
 impl<T> UltraMatrixGraph<T> {
@@ -68,6 +76,7 @@ impl<T> UltraMatrixGraph<T> {
             graph: MatrixGraph::default(),
             node_map: AHashMap::new(),
             index_map: AHashMap::new(),
+            partition_map: AHashMap::new(),
         }
     }
 
@@ -77,6 +86,7 @@ impl<T> UltraMatrixGraph<T> {
             graph: MatrixGraph::with_capacity(capacity),
             node_map: AHashMap::with_capacity(capacity),
             index_map: AHashMap::with_capacity(capacity),
+            partition_map: AHashMap::new(),
         }
     }
 }
@@ -16,4 +16,20 @@ where
     fn outgoing_edges(&self, a: usize) -> Result<IntoIter<usize>, UltraGraphError> {
         self.storage.outgoing_edges(a)
     }
+
+    fn transitive_closure(&self) -> Self
+    where
+        T: Clone,
+    {
+        UltraGraphContainer::new(self.storage.transitive_closure())
+    }
+
+    fn transitive_reduction(&self) -> Result<Self, UltraGraphError>
+    where
+        T: Clone,
+    {
+        Ok(UltraGraphContainer::new(
+            self.storage.transitive_reduction()?,
+        ))
+    }
 }
@@ -22,6 +22,10 @@ where
         self.storage.remove_node(index)
     }
 
+    fn update_node(&mut self, index: usize, value: T) -> Result<(), UltraGraphError> {
+        self.storage.update_node(index, value)
+    }
+
     fn add_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
         self.storage.add_edge(a, b)
     }
@@ -39,6 +43,10 @@ where
         self.storage.contains_edge(a, b)
     }
 
+    fn get_edge_weight(&self, a: usize, b: usize) -> Option<u64> {
+        self.storage.get_edge_weight(a, b)
+    }
+
     fn remove_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
         self.storage.remove_edge(a, b)
     }
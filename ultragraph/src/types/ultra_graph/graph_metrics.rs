@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use std::collections::BTreeMap;
+
+use crate::prelude::{GraphMetrics, GraphStorage, UltraGraphContainer, UltraGraphError};
+
+impl<S, T> GraphMetrics<T> for UltraGraphContainer<S, T>
+where
+    S: GraphStorage<T>,
+{
+    fn in_degree(&self, index: usize) -> Result<usize, UltraGraphError> {
+        self.storage.in_degree(index)
+    }
+
+    fn out_degree(&self, index: usize) -> Result<usize, UltraGraphError> {
+        self.storage.out_degree(index)
+    }
+
+    fn in_degree_distribution(&self) -> BTreeMap<usize, usize> {
+        self.storage.in_degree_distribution()
+    }
+
+    fn out_degree_distribution(&self) -> BTreeMap<usize, usize> {
+        self.storage.out_degree_distribution()
+    }
+
+    fn betweenness_centrality(&self, normalized: bool) -> BTreeMap<usize, f64> {
+        self.storage.betweenness_centrality(normalized)
+    }
+
+    fn closeness_centrality(&self) -> BTreeMap<usize, f64> {
+        self.storage.closeness_centrality()
+    }
+
+    fn page_rank(
+        &self,
+        damping: f64,
+        max_iterations: usize,
+        tolerance: f64,
+    ) -> Result<BTreeMap<usize, f64>, UltraGraphError> {
+        self.storage.page_rank(damping, max_iterations, tolerance)
+    }
+}
@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use crate::prelude::{GraphPartitions, GraphStorage, UltraGraphContainer, UltraGraphError};
+
+impl<S, T> GraphPartitions<T> for UltraGraphContainer<S, T>
+where
+    S: GraphStorage<T>,
+{
+    fn add_node_with_partition(&mut self, value: T, partition: usize) -> usize {
+        self.storage.add_node_with_partition(value, partition)
+    }
+
+    fn set_partition(&mut self, index: usize, partition: usize) -> Result<(), UltraGraphError> {
+        self.storage.set_partition(index, partition)
+    }
+
+    fn get_partition(&self, index: usize) -> Option<usize> {
+        self.storage.get_partition(index)
+    }
+
+    fn get_partition_nodes(&self, partition: usize) -> Vec<usize> {
+        self.storage.get_partition_nodes(partition)
+    }
+
+    fn get_all_partitions(&self) -> Vec<usize> {
+        self.storage.get_all_partitions()
+    }
+
+    fn add_partition_edge(&mut self, a: usize, b: usize) -> Result<(), UltraGraphError> {
+        self.storage.add_partition_edge(a, b)
+    }
+
+    fn is_multipartite(&self) -> bool {
+        self.storage.is_multipartite()
+    }
+
+    fn project_partition(&self, partition: usize) -> Result<Self, UltraGraphError>
+    where
+        T: Clone,
+    {
+        Ok(UltraGraphContainer::new(
+            self.storage.project_partition(partition)?,
+        ))
+    }
+}
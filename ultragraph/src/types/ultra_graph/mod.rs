@@ -10,6 +10,8 @@ use crate::prelude::GraphStorage;
 // Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
 mod graph_algorithms;
 mod graph_like;
+mod graph_metrics;
+mod graph_partitions;
 mod graph_root;
 pub mod graph_storage;
 
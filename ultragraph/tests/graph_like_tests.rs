@@ -402,6 +402,42 @@ fn test_contains_edge() {
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn test_update_node() {
+    let mut g = get_ultra_graph();
+
+    let root_index = g.add_root_node(Data { x: 1 });
+    let node_a_index = g.add_node(Data { x: 2 });
+    g.add_edge(root_index, node_a_index).unwrap();
+
+    g.update_node(node_a_index, Data { x: 42 }).unwrap();
+    assert_eq!(g.get_node(node_a_index).unwrap().x, 42);
+    assert!(g.contains_edge(root_index, node_a_index));
+
+    let res = g.update_node(99, Data { x: 3 });
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_get_edge_weight() {
+    let mut g = get_ultra_graph();
+
+    let root_index = g.add_root_node(Data { x: 1 });
+    let node_a_index = g.add_node(Data { x: 2 });
+    let node_b_index = g.add_node(Data { x: 3 });
+
+    assert_eq!(g.get_edge_weight(root_index, node_a_index), None);
+
+    g.add_edge(root_index, node_a_index).unwrap();
+    g.add_edge_with_weight(root_index, node_b_index, 42)
+        .unwrap();
+
+    assert_eq!(g.get_edge_weight(root_index, node_a_index), Some(0));
+    assert_eq!(g.get_edge_weight(root_index, node_b_index), Some(42));
+    assert_eq!(g.get_edge_weight(node_a_index, root_index), None);
+    assert_eq!(g.get_edge_weight(root_index, 99), None);
+}
+
 #[test]
 fn test_remove_edge() {
     let mut g = get_ultra_graph();
@@ -451,6 +487,11 @@ fn test_remove_edge() {
     let expected = false;
     let actual = g.contains_edge(root_index, node_a_index);
     assert_eq!(expected, actual);
+
+    // Removing an edge must not remove its nodes.
+    assert!(g.contains_node(root_index));
+    assert!(g.contains_node(node_a_index));
+    assert!(g.get_node(node_a_index).is_some());
 }
 
 #[test]
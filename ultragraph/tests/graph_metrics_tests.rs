@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use ultragraph::prelude::*;
+
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Data {
+    pub x: u8,
+}
+
+fn get_ultra_graph() -> UltraGraph<Data> {
+    ultragraph::with_capacity::<Data>(10)
+}
+
+// Builds the graph:
+//  0 -> 1 -> 3
+//  0 -> 2 -> 3
+//  3 -> 4
+fn get_diamond_graph() -> UltraGraph<Data> {
+    let mut g = get_ultra_graph();
+    for x in 0..5 {
+        g.add_node(Data { x });
+    }
+    g.add_edge(0, 1).expect("Failed to add edge");
+    g.add_edge(0, 2).expect("Failed to add edge");
+    g.add_edge(1, 3).expect("Failed to add edge");
+    g.add_edge(2, 3).expect("Failed to add edge");
+    g.add_edge(3, 4).expect("Failed to add edge");
+    g
+}
+
+fn assert_close(expected: f64, actual: f64) {
+    assert!(
+        (expected - actual).abs() < 1e-6,
+        "expected {} but got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_degree() {
+    let g = get_diamond_graph();
+
+    assert_eq!(g.in_degree(0).unwrap(), 0);
+    assert_eq!(g.out_degree(0).unwrap(), 2);
+    assert_eq!(g.in_degree(3).unwrap(), 2);
+    assert_eq!(g.out_degree(3).unwrap(), 1);
+    assert_eq!(g.out_degree(4).unwrap(), 0);
+}
+
+#[test]
+fn test_degree_err() {
+    let g = get_diamond_graph();
+
+    let err = g.in_degree(42).unwrap_err();
+    assert_eq!(err.to_string(), "UltraGraphError: index 42 not found");
+    assert!(g.out_degree(42).is_err());
+}
+
+#[test]
+fn test_degree_distribution() {
+    let g = get_diamond_graph();
+
+    let in_dist = g.in_degree_distribution();
+    assert_eq!(
+        in_dist.into_iter().collect::<Vec<_>>(),
+        vec![(0, 1), (1, 3), (2, 1)]
+    );
+
+    let out_dist = g.out_degree_distribution();
+    assert_eq!(
+        out_dist.into_iter().collect::<Vec<_>>(),
+        vec![(0, 1), (1, 3), (2, 1)]
+    );
+}
+
+#[test]
+fn test_betweenness_centrality() {
+    let g = get_diamond_graph();
+
+    let bc = g.betweenness_centrality(false);
+    assert_eq!(bc.len(), 5);
+    assert_close(0.0, bc[&0]);
+    // Half of the shortest paths 0->3 and 0->4 pass through 1 and 2 each.
+    assert_close(1.0, bc[&1]);
+    assert_close(1.0, bc[&2]);
+    // 0->4, 1->4, 2->4 pass through 3.
+    assert_close(3.0, bc[&3]);
+    assert_close(0.0, bc[&4]);
+
+    let bc = g.betweenness_centrality(true);
+    assert_close(3.0 / 12.0, bc[&3]);
+}
+
+#[test]
+fn test_closeness_centrality() {
+    let g = get_diamond_graph();
+
+    let cc = g.closeness_centrality();
+    // Node 0 reaches all four nodes with distances 1 + 1 + 2 + 3.
+    assert_close(4.0 / 7.0, cc[&0]);
+    // Node 3 reaches one of four nodes at distance 1.
+    assert_close(0.25, cc[&3]);
+    assert_close(0.0, cc[&4]);
+}
+
+#[test]
+fn test_page_rank() {
+    let g = get_diamond_graph();
+
+    let pr = g.page_rank(0.85, 100, 1e-10).unwrap();
+    assert_eq!(pr.len(), 5);
+    assert_close(1.0, pr.values().sum());
+
+    // Node 4 collects the rank of the whole chain, node 0 has no incoming edges.
+    assert!(pr[&4] > pr[&3]);
+    assert!(pr[&3] > pr[&1]);
+    assert!(pr[&0] < pr[&1]);
+    assert_close(pr[&1], pr[&2]);
+}
+
+#[test]
+fn test_page_rank_no_damping() {
+    let g = get_diamond_graph();
+
+    let pr = g.page_rank(0.0, 100, 1e-10).unwrap();
+    for rank in pr.values() {
+        assert_close(0.2, *rank);
+    }
+}
+
+#[test]
+fn test_page_rank_err() {
+    let g = get_diamond_graph();
+
+    let err = g.page_rank(1.5, 100, 1e-10).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "UltraGraphError: damping factor 1.5 is out of range [0, 1]"
+    );
+}
+
+#[test]
+fn test_metrics_empty_graph() {
+    let g = get_ultra_graph();
+
+    assert!(g.in_degree_distribution().is_empty());
+    assert!(g.betweenness_centrality(true).is_empty());
+    assert!(g.closeness_centrality().is_empty());
+    assert!(g.page_rank(0.85, 100, 1e-10).unwrap().is_empty());
+}
+
+#[test]
+fn test_metrics_after_remove_node() {
+    let mut g = get_diamond_graph();
+    g.remove_node(1).expect("Failed to remove node");
+
+    let bc = g.betweenness_centrality(false);
+    assert_eq!(bc.keys().copied().collect::<Vec<_>>(), vec![0, 2, 3, 4]);
+    assert_close(2.0, bc[&2]);
+    assert_eq!(g.in_degree(3).unwrap(), 1);
+}
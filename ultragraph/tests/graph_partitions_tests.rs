@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use ultragraph::prelude::*;
+
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Data {
+    pub x: u8,
+}
+
+const CAUSE: usize = 0;
+const EVIDENCE: usize = 1;
+
+fn get_ultra_graph() -> UltraGraph<Data> {
+    ultragraph::with_capacity::<Data>(10)
+}
+
+fn get_sorted_edges(g: &UltraGraph<Data>) -> Vec<(usize, usize)> {
+    let mut edges = g.get_all_edges();
+    edges.sort_unstable();
+    edges
+}
+
+// Builds the bipartite graph:
+//  cause 0 -> evidence 2 -> cause 1
+//  cause 1 -> evidence 3 -> cause 0
+//  cause 4 -> evidence 3
+fn get_bipartite_graph() -> UltraGraph<Data> {
+    let mut g = get_ultra_graph();
+    g.add_node_with_partition(Data { x: 0 }, CAUSE);
+    g.add_node_with_partition(Data { x: 1 }, CAUSE);
+    g.add_node_with_partition(Data { x: 2 }, EVIDENCE);
+    g.add_node_with_partition(Data { x: 3 }, EVIDENCE);
+    g.add_node_with_partition(Data { x: 4 }, CAUSE);
+
+    g.add_partition_edge(0, 2).expect("Failed to add edge");
+    g.add_partition_edge(2, 1).expect("Failed to add edge");
+    g.add_partition_edge(1, 3).expect("Failed to add edge");
+    g.add_partition_edge(3, 0).expect("Failed to add edge");
+    g.add_partition_edge(4, 3).expect("Failed to add edge");
+    g
+}
+
+#[test]
+fn test_partitions() {
+    let mut g = get_bipartite_graph();
+
+    assert_eq!(g.get_partition(0), Some(CAUSE));
+    assert_eq!(g.get_partition(2), Some(EVIDENCE));
+    assert_eq!(g.get_partition(42), None);
+    assert_eq!(g.get_partition_nodes(CAUSE), vec![0, 1, 4]);
+    assert_eq!(g.get_partition_nodes(EVIDENCE), vec![2, 3]);
+    assert_eq!(g.get_all_partitions(), vec![CAUSE, EVIDENCE]);
+
+    g.set_partition(4, 2).expect("Failed to set partition");
+    assert_eq!(g.get_partition(4), Some(2));
+    assert_eq!(g.get_all_partitions(), vec![0, 1, 2]);
+
+    assert!(g.set_partition(42, CAUSE).is_err());
+}
+
+#[test]
+fn test_add_partition_edge_err() {
+    let mut g = get_bipartite_graph();
+
+    let err = g.add_partition_edge(0, 1).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "UltraGraphError: Edge between 0 and 1 within the same partition 0"
+    );
+
+    let index = g.add_node(Data { x: 5 });
+    let err = g.add_partition_edge(0, index).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "UltraGraphError: index b 5 has no partition"
+    );
+
+    assert!(g.add_partition_edge(42, 0).is_err());
+    assert!(g.add_partition_edge(0, 42).is_err());
+
+    // Existing edges are rejected as with add_edge.
+    assert!(g.add_partition_edge(0, 2).is_err());
+}
+
+#[test]
+fn test_is_multipartite() {
+    let mut g = get_bipartite_graph();
+    assert!(g.is_multipartite());
+
+    // An unchecked edge within a partition breaks the property.
+    g.add_edge(0, 1).expect("Failed to add edge");
+    assert!(!g.is_multipartite());
+    g.remove_edge(0, 1).expect("Failed to remove edge");
+    assert!(g.is_multipartite());
+
+    // So does a node without partition.
+    let index = g.add_node(Data { x: 5 });
+    assert!(!g.is_multipartite());
+    g.remove_node(index).expect("Failed to remove node");
+    assert!(g.is_multipartite());
+}
+
+#[test]
+fn test_project_partition() {
+    let g = get_bipartite_graph();
+
+    let causes = g.project_partition(CAUSE).unwrap();
+    assert_eq!(causes.number_nodes(), 3);
+    assert_eq!(causes.get_node(4), Some(&Data { x: 4 }));
+    assert_eq!(causes.get_partition(4), Some(CAUSE));
+    assert!(!causes.contains_node(2));
+    assert_eq!(get_sorted_edges(&causes), vec![(0, 1), (1, 0), (4, 0)]);
+
+    let evidence = g.project_partition(EVIDENCE).unwrap();
+    assert_eq!(evidence.get_partition_nodes(EVIDENCE), vec![2, 3]);
+    assert_eq!(get_sorted_edges(&evidence), vec![(2, 3), (3, 2)]);
+
+    // The original graph is unchanged.
+    assert_eq!(g.number_nodes(), 5);
+    assert_eq!(g.number_edges(), 5);
+}
+
+#[test]
+fn test_project_partition_root() {
+    let mut g = get_ultra_graph();
+    let root = g.add_root_node(Data { x: 0 });
+    g.set_partition(root, CAUSE)
+        .expect("Failed to set partition");
+    let e = g.add_node_with_partition(Data { x: 1 }, EVIDENCE);
+    g.add_partition_edge(root, e).expect("Failed to add edge");
+
+    let causes = g.project_partition(CAUSE).unwrap();
+    assert_eq!(causes.get_root_index(), Some(root));
+
+    let evidence = g.project_partition(EVIDENCE).unwrap();
+    assert_eq!(evidence.get_root_index(), None);
+    assert!(!evidence.contains_root_node());
+}
+
+#[test]
+fn test_project_partition_err() {
+    let g = get_bipartite_graph();
+
+    let res = g.project_partition(7);
+    assert!(res.is_err());
+    assert_eq!(
+        res.err().unwrap().to_string(),
+        "UltraGraphError: partition 7 contains no node"
+    );
+}
+
+#[test]
+fn test_partitions_clear() {
+    let mut g = get_bipartite_graph();
+    g.clear();
+
+    assert!(g.get_all_partitions().is_empty());
+    assert_eq!(g.get_partition(0), None);
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) "2023" . The DeepCausality Authors. All Rights Reserved.
+
+use ultragraph::prelude::*;
+
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Data {
+    pub x: u8,
+}
+
+fn get_ultra_graph() -> UltraGraph<Data> {
+    ultragraph::with_capacity::<Data>(10)
+}
+
+fn get_sorted_edges(g: &UltraGraph<Data>) -> Vec<(usize, usize)> {
+    let mut edges = g.get_all_edges();
+    edges.sort_unstable();
+    edges
+}
+
+// Builds the graph:
+//  0 -> 1 -> 2 -> 3
+//  0 -> 2
+//  0 -> 3
+fn get_redundant_chain() -> UltraGraph<Data> {
+    let mut g = get_ultra_graph();
+    let root_index = g.add_root_node(Data { x: 0 });
+    for x in 1..4 {
+        g.add_node(Data { x });
+    }
+    g.add_edge(root_index, 1).expect("Failed to add edge");
+    g.add_edge(1, 2).expect("Failed to add edge");
+    g.add_edge(2, 3).expect("Failed to add edge");
+    g.add_edge(0, 2).expect("Failed to add edge");
+    g.add_edge_with_weight(0, 3, 7).expect("Failed to add edge");
+    g
+}
+
+#[test]
+fn test_transitive_reduction() {
+    let g = get_redundant_chain();
+
+    let reduced = g.transitive_reduction().unwrap();
+    assert_eq!(get_sorted_edges(&reduced), vec![(0, 1), (1, 2), (2, 3)]);
+
+    // Nodes, node indices, and root are preserved.
+    assert_eq!(reduced.number_nodes(), 4);
+    assert_eq!(reduced.get_root_index(), Some(0));
+    assert_eq!(reduced.get_node(3), Some(&Data { x: 3 }));
+
+    // The original graph is unchanged.
+    assert_eq!(g.number_edges(), 5);
+}
+
+#[test]
+fn test_transitive_reduction_diamond() {
+    // Both paths 0 -> 1 -> 3 and 0 -> 2 -> 3 are kept.
+    let mut g = get_ultra_graph();
+    for x in 0..4 {
+        g.add_node(Data { x });
+    }
+    g.add_edge(0, 1).expect("Failed to add edge");
+    g.add_edge(0, 2).expect("Failed to add edge");
+    g.add_edge(1, 3).expect("Failed to add edge");
+    g.add_edge(2, 3).expect("Failed to add edge");
+    g.add_edge(0, 3).expect("Failed to add edge");
+
+    let reduced = g.transitive_reduction().unwrap();
+    assert_eq!(
+        get_sorted_edges(&reduced),
+        vec![(0, 1), (0, 2), (1, 3), (2, 3)]
+    );
+}
+
+#[test]
+fn test_transitive_reduction_cycle_err() {
+    let mut g = get_ultra_graph();
+    for x in 0..3 {
+        g.add_node(Data { x });
+    }
+    g.add_edge(0, 1).expect("Failed to add edge");
+    g.add_edge(1, 2).expect("Failed to add edge");
+    g.add_edge(2, 0).expect("Failed to add edge");
+
+    let res = g.transitive_reduction();
+    assert!(res.is_err());
+    assert_eq!(
+        res.err().unwrap().to_string(),
+        "UltraGraphError: transitive reduction requires an acyclic graph"
+    );
+}
+
+#[test]
+fn test_transitive_closure() {
+    let mut g = get_ultra_graph();
+    for x in 0..4 {
+        g.add_node(Data { x });
+    }
+    g.add_edge(0, 1).expect("Failed to add edge");
+    g.add_edge(1, 2).expect("Failed to add edge");
+    g.add_edge(2, 3).expect("Failed to add edge");
+
+    let closure = g.transitive_closure();
+    assert_eq!(
+        get_sorted_edges(&closure),
+        vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]
+    );
+    assert_eq!(g.number_edges(), 3);
+
+    // The reduction of the closure is the original chain.
+    let reduced = closure.transitive_reduction().unwrap();
+    assert_eq!(get_sorted_edges(&reduced), get_sorted_edges(&g));
+}
+
+#[test]
+fn test_transitive_closure_cycle() {
+    let mut g = get_ultra_graph();
+    for x in 0..3 {
+        g.add_node(Data { x });
+    }
+    g.add_edge(0, 1).expect("Failed to add edge");
+    g.add_edge(1, 0).expect("Failed to add edge");
+    g.add_edge(1, 2).expect("Failed to add edge");
+
+    let closure = g.transitive_closure();
+    assert_eq!(
+        get_sorted_edges(&closure),
+        vec![(0, 1), (0, 2), (1, 0), (1, 2)]
+    );
+}
+
+#[test]
+fn test_transitive_closure_after_remove_node() {
+    let mut g = get_redundant_chain();
+    g.remove_node(1).expect("Failed to remove node");
+
+    let closure = g.transitive_closure();
+    assert_eq!(get_sorted_edges(&closure), vec![(0, 2), (0, 3), (2, 3)]);
+    assert!(!closure.contains_node(1));
+}
+
+#[test]
+fn test_transitive_empty_graph() {
+    let g = get_ultra_graph();
+
+    assert!(g.transitive_closure().is_empty());
+    assert!(g.transitive_reduction().unwrap().is_empty());
+}
@@ -8,10 +8,16 @@ mod error_tests;
 #[cfg(test)]
 mod graph_like_tests;
 #[cfg(test)]
+mod graph_metrics_tests;
+#[cfg(test)]
 mod graph_outgoing_edges_tests;
 #[cfg(test)]
+mod graph_partitions_tests;
+#[cfg(test)]
 mod graph_root_tests;
 #[cfg(test)]
 mod graph_shortest_path_tests;
 #[cfg(test)]
 mod graph_storage_tests;
+#[cfg(test)]
+mod graph_transitive_tests;